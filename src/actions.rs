@@ -0,0 +1,527 @@
+use std::collections::HashMap;
+use std::fs;
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{AppState, Game};
+use crate::storage::{config_file_path, file_version, write_file};
+
+/// Bumped whenever the layout of the bindings file changes
+const BINDINGS_FILE_VERSION: u32 = 1;
+const BINDINGS_FILE_NAME: &str = "bindings.ron";
+
+/// Below this, stick movements are ignored so that a resting stick does not drift
+const STICK_DEADZONE: f32 = 0.3;
+
+pub struct ActionsPlugin;
+
+impl Plugin for ActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ActionBindings::load())
+            .init_resource::<Actions>()
+            .add_system(
+                update_actions
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(InputSystem),
+            );
+    }
+}
+
+/// What the player wants to do, whatever the keyboard key or gamepad input used for it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Thrust,
+    Fire,
+    Confirm,
+    Back,
+    Pause,
+    RotateClockwise,
+    RotateCounterClockwise,
+    Hold,
+    HardDrop,
+    Undo,
+    Redo,
+    Restart,
+    LevelSelect,
+}
+
+impl Action {
+    pub const ALL: [Action; 17] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Thrust,
+        Action::Fire,
+        Action::Confirm,
+        Action::Back,
+        Action::Pause,
+        Action::RotateClockwise,
+        Action::RotateCounterClockwise,
+        Action::Hold,
+        Action::HardDrop,
+        Action::Undo,
+        Action::Redo,
+        Action::Restart,
+        Action::LevelSelect,
+    ];
+
+    /// Actions the player can rebind for a game. In the games played by several players at once,
+    /// they are the actions of player one, the other players keeping fixed keys.
+    pub fn of_game(game: Game) -> &'static [Action] {
+        match game {
+            Game::Asteroid => &[
+                Action::MoveLeft,
+                Action::MoveRight,
+                Action::Thrust,
+                Action::Fire,
+            ],
+            Game::Breakout | Game::SpaceInvaders => {
+                &[Action::MoveLeft, Action::MoveRight, Action::Fire]
+            }
+            Game::PacMan => &[
+                Action::MoveUp,
+                Action::MoveDown,
+                Action::MoveLeft,
+                Action::MoveRight,
+            ],
+            Game::Sokoban => &[
+                Action::MoveUp,
+                Action::MoveDown,
+                Action::MoveLeft,
+                Action::MoveRight,
+                Action::Undo,
+                Action::Redo,
+                Action::Restart,
+                Action::LevelSelect,
+            ],
+            Game::Tetris => &[
+                Action::MoveLeft,
+                Action::MoveRight,
+                Action::MoveDown,
+                Action::RotateClockwise,
+                Action::RotateCounterClockwise,
+                Action::Hold,
+                Action::HardDrop,
+            ],
+            Game::Bomberman => &[
+                Action::MoveUp,
+                Action::MoveDown,
+                Action::MoveLeft,
+                Action::MoveRight,
+                Action::Fire,
+            ],
+            Game::Tron => &[
+                Action::MoveUp,
+                Action::MoveDown,
+                Action::MoveLeft,
+                Action::MoveRight,
+            ],
+        }
+    }
+
+    /// Name of the action as used by the game
+    pub fn name(&self, game: Game) -> &'static str {
+        match (self, game) {
+            (Action::MoveLeft, Game::Asteroid) => "Rotate Left",
+            (Action::MoveRight, Game::Asteroid) => "Rotate Right",
+            (Action::MoveDown, Game::Tetris) => "Soft Drop",
+            (Action::Fire, Game::Breakout) => "Launch",
+            (Action::Fire, Game::Bomberman) => "Bomb",
+            (Action::MoveUp, _) => "Move Up",
+            (Action::MoveDown, _) => "Move Down",
+            (Action::MoveLeft, _) => "Move Left",
+            (Action::MoveRight, _) => "Move Right",
+            (Action::Thrust, _) => "Thrust",
+            (Action::Fire, _) => "Shoot",
+            (Action::Confirm, _) => "Confirm",
+            (Action::Back, _) => "Back",
+            (Action::Pause, _) => "Pause",
+            (Action::RotateClockwise, _) => "Rotate",
+            (Action::RotateCounterClockwise, _) => "Rotate Back",
+            (Action::Hold, _) => "Hold",
+            (Action::HardDrop, _) => "Hard Drop",
+            (Action::Undo, _) => "Undo",
+            (Action::Redo, _) => "Redo",
+            (Action::Restart, _) => "Restart",
+            (Action::LevelSelect, _) => "Level Select",
+        }
+    }
+
+    pub fn default_binding(&self) -> Binding {
+        Binding {
+            keys: self.default_keys(),
+            gamepad_buttons: self.default_gamepad_buttons(),
+        }
+    }
+
+    fn default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            Action::MoveUp => vec![KeyCode::W, KeyCode::Up],
+            Action::MoveDown => vec![KeyCode::S, KeyCode::Down],
+            Action::MoveLeft => vec![KeyCode::A, KeyCode::Left],
+            Action::MoveRight => vec![KeyCode::D, KeyCode::Right],
+            Action::Thrust => vec![KeyCode::W, KeyCode::Up],
+            Action::Fire => vec![KeyCode::Space],
+            Action::Confirm => vec![KeyCode::Return, KeyCode::Space],
+            Action::Back => vec![KeyCode::Escape],
+            Action::Pause => vec![KeyCode::Escape],
+            Action::RotateClockwise => vec![KeyCode::Up, KeyCode::W, KeyCode::X],
+            Action::RotateCounterClockwise => vec![KeyCode::Z, KeyCode::LControl],
+            Action::Hold => vec![KeyCode::C, KeyCode::LShift],
+            Action::HardDrop => vec![KeyCode::Space],
+            Action::Undo => vec![KeyCode::Z, KeyCode::U, KeyCode::Back],
+            Action::Redo => vec![KeyCode::Y],
+            Action::Restart => vec![KeyCode::R],
+            Action::LevelSelect => vec![KeyCode::L],
+        }
+    }
+
+    fn default_gamepad_buttons(&self) -> Vec<GamepadButtonType> {
+        match self {
+            Action::MoveUp => vec![GamepadButtonType::DPadUp],
+            Action::MoveDown => vec![GamepadButtonType::DPadDown],
+            Action::MoveLeft => vec![GamepadButtonType::DPadLeft],
+            Action::MoveRight => vec![GamepadButtonType::DPadRight],
+            Action::Thrust => vec![GamepadButtonType::DPadUp, GamepadButtonType::RightTrigger2],
+            Action::Fire => vec![GamepadButtonType::South, GamepadButtonType::RightTrigger],
+            Action::Confirm => vec![GamepadButtonType::South, GamepadButtonType::Start],
+            Action::Back => vec![GamepadButtonType::East, GamepadButtonType::Select],
+            Action::Pause => vec![GamepadButtonType::Start],
+            Action::RotateClockwise => vec![GamepadButtonType::East, GamepadButtonType::DPadUp],
+            Action::RotateCounterClockwise => vec![GamepadButtonType::West],
+            Action::Hold => vec![
+                GamepadButtonType::LeftTrigger,
+                GamepadButtonType::RightTrigger,
+            ],
+            Action::HardDrop => vec![GamepadButtonType::South],
+            Action::Undo => vec![GamepadButtonType::West],
+            Action::Redo => vec![GamepadButtonType::North],
+            Action::Restart => vec![GamepadButtonType::Select],
+            Action::LevelSelect => vec![GamepadButtonType::LeftTrigger],
+        }
+    }
+
+    /// Stick axis and direction triggering the action, if any
+    fn gamepad_axis(&self) -> Option<(GamepadAxisType, f32)> {
+        match self {
+            Action::MoveUp | Action::Thrust => Some((GamepadAxisType::LeftStickY, 1.0)),
+            Action::MoveDown => Some((GamepadAxisType::LeftStickY, -1.0)),
+            Action::MoveLeft => Some((GamepadAxisType::LeftStickX, -1.0)),
+            Action::MoveRight => Some((GamepadAxisType::LeftStickX, 1.0)),
+            _ => None,
+        }
+    }
+}
+
+/// Keyboard keys and gamepad buttons triggering an action
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Binding {
+    pub keys: Vec<KeyCode>,
+    pub gamepad_buttons: Vec<GamepadButtonType>,
+}
+
+impl Binding {
+    pub fn contains(&self, input: BindingInput) -> bool {
+        match input {
+            BindingInput::Key(key) => self.keys.contains(&key),
+            BindingInput::GamepadButton(button) => self.gamepad_buttons.contains(&button),
+        }
+    }
+
+    pub fn keys_label(&self) -> String {
+        inputs_label(&self.keys)
+    }
+
+    pub fn gamepad_buttons_label(&self) -> String {
+        inputs_label(&self.gamepad_buttons)
+    }
+}
+
+fn inputs_label<T: std::fmt::Debug>(inputs: &[T]) -> String {
+    if inputs.is_empty() {
+        return "-".to_string();
+    }
+    inputs
+        .iter()
+        .map(|input| format!("{:?}", input))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Key or button pressed to rebind an action
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BindingInput {
+    Key(KeyCode),
+    GamepadButton(GamepadButtonType),
+}
+
+impl BindingInput {
+    pub fn label(&self) -> String {
+        match self {
+            BindingInput::Key(key) => format!("{:?}", key),
+            BindingInput::GamepadButton(button) => format!("{:?}", button),
+        }
+    }
+}
+
+/// Bindings changed by the player for each game, the other actions keeping their defaults
+#[derive(Resource, Default)]
+pub struct ActionBindings {
+    games: HashMap<Game, HashMap<Action, Binding>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BindingsFile {
+    version: u32,
+    games: HashMap<Game, HashMap<Action, Binding>>,
+}
+
+impl ActionBindings {
+    pub fn load() -> Self {
+        let Some(path) = config_file_path(BINDINGS_FILE_NAME) else {
+            return ActionBindings::default();
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            return ActionBindings::default();
+        };
+
+        let version = match file_version(&content) {
+            Ok(version) => version,
+            Err(err) => {
+                warn!("Ignoring unreadable bindings file {:?}: {}", path, err);
+                return ActionBindings::default();
+            }
+        };
+        match version {
+            BINDINGS_FILE_VERSION => match ron::from_str::<BindingsFile>(&content) {
+                Ok(file) => ActionBindings { games: file.games },
+                Err(err) => {
+                    warn!("Ignoring unreadable bindings file {:?}: {}", path, err);
+                    ActionBindings::default()
+                }
+            },
+            _ => {
+                warn!(
+                    "Ignoring bindings file {:?} with unsupported version {}",
+                    path, version
+                );
+                ActionBindings::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = config_file_path(BINDINGS_FILE_NAME) else { return; };
+        let file = BindingsFile {
+            version: BINDINGS_FILE_VERSION,
+            games: self.games.clone(),
+        };
+        let content = ron::ser::to_string_pretty(&file, PrettyConfig::default()).unwrap();
+        if let Err(err) = write_file(&path, content) {
+            warn!("Could not save bindings to {:?}: {}", path, err);
+        }
+    }
+
+    /// Binding of the action while playing the game, or outside of the games without one
+    pub fn binding(&self, game: Option<Game>, action: Action) -> Binding {
+        game.and_then(|game| self.games.get(&game))
+            .and_then(|bindings| bindings.get(&action))
+            .cloned()
+            .unwrap_or_else(|| action.default_binding())
+    }
+
+    /// Other action of the game already triggered by the input, pausing included
+    pub fn conflict(&self, game: Game, action: Action, input: BindingInput) -> Option<Action> {
+        Action::of_game(game)
+            .iter()
+            .chain([Action::Pause].iter())
+            .copied()
+            .filter(|other| *other != action)
+            .find(|other| self.binding(Some(game), *other).contains(input))
+    }
+
+    /// Replaces the keys or the gamepad buttons of the action with the input
+    pub fn bind(&mut self, game: Game, action: Action, input: BindingInput) {
+        let mut binding = self.binding(Some(game), action);
+        match input {
+            BindingInput::Key(key) => binding.keys = vec![key],
+            BindingInput::GamepadButton(button) => binding.gamepad_buttons = vec![button],
+        }
+        let game_bindings = self.games.entry(game).or_default();
+        if binding == action.default_binding() {
+            game_bindings.remove(&action);
+        } else {
+            game_bindings.insert(action, binding);
+        }
+    }
+
+    pub fn reset(&mut self, game: Game) {
+        self.games.remove(&game);
+    }
+}
+
+/// Pressed actions of a single device, or of all of them together
+#[derive(Default)]
+pub struct ActionState {
+    input: Input<Action>,
+    /// How far each pressed action is pushed, from 0 to 1, to follow analog sticks
+    values: HashMap<Action, f32>,
+}
+
+impl ActionState {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.input.pressed(action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.input.just_pressed(action)
+    }
+
+    pub fn any_just_pressed(&self, actions: impl IntoIterator<Item = Action>) -> bool {
+        self.input.any_just_pressed(actions)
+    }
+
+    pub fn value(&self, action: Action) -> f32 {
+        self.values.get(&action).copied().unwrap_or(0.0)
+    }
+
+    /// From -1 when only the negative action is pressed to 1 when only the positive one is
+    pub fn axis(&self, negative: Action, positive: Action) -> f32 {
+        self.value(positive) - self.value(negative)
+    }
+
+    fn update(&mut self, values: HashMap<Action, f32>) {
+        self.input.clear();
+        for action in Action::ALL {
+            if values.get(&action).is_some_and(|value| *value > 0.0) {
+                self.input.press(action);
+            } else {
+                self.input.release(action);
+            }
+        }
+        self.values = values;
+    }
+}
+
+/// Actions of the current frame, from the keyboard and every gamepad together, and from each
+/// gamepad on its own for the games played by several players
+#[derive(Resource, Default)]
+pub struct Actions {
+    all: ActionState,
+    gamepads: Vec<(Gamepad, ActionState)>,
+}
+
+impl Actions {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.all.pressed(action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.all.just_pressed(action)
+    }
+
+    pub fn any_just_pressed(&self, actions: impl IntoIterator<Item = Action>) -> bool {
+        self.all.any_just_pressed(actions)
+    }
+
+    pub fn value(&self, action: Action) -> f32 {
+        self.all.value(action)
+    }
+
+    pub fn axis(&self, negative: Action, positive: Action) -> f32 {
+        self.all.axis(negative, positive)
+    }
+
+    /// Actions of the nth connected gamepad
+    pub fn gamepad(&self, index: usize) -> Option<&ActionState> {
+        self.gamepads.get(index).map(|(_, state)| state)
+    }
+}
+
+fn update_actions(
+    app_state: Res<State<AppState>>,
+    keys: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    bindings: Res<ActionBindings>,
+    mut actions: ResMut<Actions>,
+) {
+    let mut connected = gamepads.iter().collect::<Vec<_>>();
+    connected.sort_by_key(|gamepad| gamepad.id);
+
+    let game = app_state.0.game();
+    let bindings = Action::ALL
+        .iter()
+        .map(|action| (*action, bindings.binding(game, *action)))
+        .collect::<HashMap<_, _>>();
+
+    let mut all_values = HashMap::new();
+    for (action, binding) in bindings.iter() {
+        if keys.any_pressed(binding.keys.iter().copied()) {
+            all_values.insert(*action, 1.0);
+        }
+    }
+
+    let mut gamepad_states = Vec::with_capacity(connected.len());
+    for gamepad in connected {
+        let mut values = HashMap::new();
+        for (action, binding) in bindings.iter() {
+            let value =
+                gamepad_action_value(gamepad, *action, binding, &gamepad_buttons, &gamepad_axes);
+            if value > 0.0 {
+                values.insert(*action, value);
+                let all_value = all_values.entry(*action).or_insert(0.0);
+                *all_value = value.max(*all_value);
+            }
+        }
+
+        // Keeps the state of the frame before so that just pressed actions are detected
+        let mut state = actions
+            .gamepads
+            .iter()
+            .position(|(other, _)| *other == gamepad)
+            .map(|index| actions.gamepads.swap_remove(index).1)
+            .unwrap_or_default();
+        state.update(values);
+        gamepad_states.push((gamepad, state));
+    }
+
+    actions.gamepads = gamepad_states;
+    actions.all.update(all_values);
+}
+
+fn gamepad_action_value(
+    gamepad: Gamepad,
+    action: Action,
+    binding: &Binding,
+    gamepad_buttons: &Input<GamepadButton>,
+    gamepad_axes: &Axis<GamepadAxis>,
+) -> f32 {
+    let is_button_pressed = binding
+        .gamepad_buttons
+        .iter()
+        .any(|button_type| gamepad_buttons.pressed(GamepadButton::new(gamepad, *button_type)));
+    if is_button_pressed {
+        return 1.0;
+    }
+
+    let Some((axis_type, direction)) = action.gamepad_axis() else { return 0.0; };
+    let axis_value = gamepad_axes
+        .get(GamepadAxis::new(gamepad, axis_type))
+        .unwrap_or(0.0)
+        * direction;
+    if axis_value <= STICK_DEADZONE {
+        return 0.0;
+    }
+    // rescaled so that the value starts from 0 at the edge of the deadzone
+    ((axis_value - STICK_DEADZONE) / (1.0 - STICK_DEADZONE)).min(1.0)
+}
@@ -0,0 +1,292 @@
+use bevy::audio::AddAudioSource;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::common::{AppState, Game};
+use crate::settings::Settings;
+use crate::synth::{SfxPreset, SynthSound};
+
+pub struct AudioBusPlugin;
+
+impl Plugin for AudioBusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<SynthSound>()
+            .add_event::<PlaySoundEvent>()
+            .init_resource::<AudioBus>()
+            .add_startup_system(render_synth_sounds)
+            .add_system(preload_sounds.run_if(state_changed::<AppState>()))
+            .add_system(update_channel_volumes.run_if(resource_changed::<Settings>()))
+            .add_system(play_sound_event_handler.in_base_set(CoreSet::PostUpdate));
+    }
+}
+
+/// Group of sounds sharing a volume in the settings
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioChannel {
+    Music,
+    Sfx,
+}
+
+impl AudioChannel {
+    pub const ALL: [AudioChannel; 2] = [AudioChannel::Music, AudioChannel::Sfx];
+
+    /// Volume of the channel, the master volume included
+    pub fn volume(self, settings: &Settings) -> f32 {
+        let channel_volume = match self {
+            AudioChannel::Music => settings.music_volume,
+            AudioChannel::Sfx => settings.sfx_volume,
+        };
+        settings.master_volume * channel_volume
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sound {
+    AsteroidLaser,
+    AsteroidExplosion,
+    AsteroidShipExplosion,
+    AsteroidThrust,
+    BreakoutWallHit,
+    BreakoutPaddleHit,
+    BreakoutExplosion,
+    BreakoutPowerUp,
+    BreakoutLaser,
+    TetrisLineClear,
+    PacManPellet,
+    PacManDeath,
+    SpaceInvadersShot,
+    BombermanExplosion,
+}
+
+/// Where the samples of a sound come from
+enum SoundSource {
+    /// With the seconds it lasts
+    File(&'static str, f32),
+    /// Rendered once at startup
+    Synth(SfxPreset),
+}
+
+impl Sound {
+    pub const ALL: [Sound; 14] = [
+        Sound::AsteroidLaser,
+        Sound::AsteroidExplosion,
+        Sound::AsteroidShipExplosion,
+        Sound::AsteroidThrust,
+        Sound::BreakoutWallHit,
+        Sound::BreakoutPaddleHit,
+        Sound::BreakoutExplosion,
+        Sound::BreakoutPowerUp,
+        Sound::BreakoutLaser,
+        Sound::TetrisLineClear,
+        Sound::PacManPellet,
+        Sound::PacManDeath,
+        Sound::SpaceInvadersShot,
+        Sound::BombermanExplosion,
+    ];
+
+    fn source(self) -> SoundSource {
+        match self {
+            Sound::AsteroidLaser => SoundSource::Synth(SfxPreset::LASER),
+            Sound::AsteroidExplosion => SoundSource::Synth(SfxPreset::EXPLOSION),
+            Sound::AsteroidShipExplosion => SoundSource::Synth(SfxPreset::BIG_EXPLOSION),
+            Sound::AsteroidThrust => SoundSource::Synth(SfxPreset::THRUST),
+            Sound::BreakoutWallHit => SoundSource::File("sound/breakout/ball_impact.ogg", 0.12),
+            Sound::BreakoutPaddleHit => {
+                SoundSource::File("sound/breakout/ball_paddle_impact.ogg", 0.15)
+            }
+            Sound::BreakoutExplosion => SoundSource::Synth(SfxPreset::EXPLOSION),
+            Sound::BreakoutPowerUp => SoundSource::Synth(SfxPreset::POWER_UP),
+            Sound::BreakoutLaser => SoundSource::Synth(SfxPreset::LASER),
+            Sound::TetrisLineClear => SoundSource::Synth(SfxPreset::CHIME),
+            Sound::PacManPellet => SoundSource::Synth(SfxPreset::BLIP),
+            Sound::PacManDeath => SoundSource::Synth(SfxPreset::FALL),
+            Sound::SpaceInvadersShot => SoundSource::Synth(SfxPreset::LASER),
+            Sound::BombermanExplosion => SoundSource::Synth(SfxPreset::EXPLOSION),
+        }
+    }
+
+    /// Game whose scene loads the sound when entered
+    fn game(self) -> Game {
+        match self {
+            Sound::AsteroidLaser
+            | Sound::AsteroidExplosion
+            | Sound::AsteroidShipExplosion
+            | Sound::AsteroidThrust => Game::Asteroid,
+            Sound::BreakoutWallHit
+            | Sound::BreakoutPaddleHit
+            | Sound::BreakoutExplosion
+            | Sound::BreakoutPowerUp
+            | Sound::BreakoutLaser => Game::Breakout,
+            Sound::TetrisLineClear => Game::Tetris,
+            Sound::PacManPellet | Sound::PacManDeath => Game::PacMan,
+            Sound::SpaceInvadersShot => Game::SpaceInvaders,
+            Sound::BombermanExplosion => Game::Bomberman,
+        }
+    }
+
+    fn channel(self) -> AudioChannel {
+        match self {
+            Sound::AsteroidLaser
+            | Sound::AsteroidExplosion
+            | Sound::AsteroidShipExplosion
+            | Sound::AsteroidThrust
+            | Sound::BreakoutWallHit
+            | Sound::BreakoutPaddleHit
+            | Sound::BreakoutExplosion
+            | Sound::BreakoutPowerUp
+            | Sound::BreakoutLaser
+            | Sound::TetrisLineClear
+            | Sound::PacManPellet
+            | Sound::PacManDeath
+            | Sound::SpaceInvadersShot
+            | Sound::BombermanExplosion => AudioChannel::Sfx,
+        }
+    }
+
+    /// Seconds the sound lasts, after which it no longer counts as playing
+    fn length(self) -> f32 {
+        match self.source() {
+            SoundSource::File(_, length) => length,
+            SoundSource::Synth(preset) => preset.envelope.length(),
+        }
+    }
+
+    /// Most instances of the sound heard at once, the oldest being cut to start another one
+    fn max_instances(self) -> usize {
+        match self {
+            Sound::AsteroidThrust | Sound::PacManDeath | Sound::SpaceInvadersShot => 1,
+            Sound::AsteroidShipExplosion | Sound::TetrisLineClear | Sound::PacManPellet => 2,
+            Sound::AsteroidLaser
+            | Sound::AsteroidExplosion
+            | Sound::BreakoutWallHit
+            | Sound::BreakoutPaddleHit
+            | Sound::BreakoutExplosion
+            | Sound::BreakoutPowerUp
+            | Sound::BreakoutLaser
+            | Sound::BombermanExplosion => 3,
+        }
+    }
+
+    /// Sent every frame for as long as it should be heard, so new instances wait for a free slot
+    /// instead of cutting the oldest one
+    fn is_sustained(self) -> bool {
+        matches!(self, Sound::AsteroidThrust)
+    }
+}
+
+/// Plays the sound on its channel, the one every scene uses
+pub struct PlaySoundEvent(pub Sound);
+
+struct PlayingSound {
+    sound: Sound,
+    sink: Handle<AudioSink>,
+    started_at: f32,
+}
+
+#[derive(Resource, Default)]
+struct AudioBus {
+    /// Sounds of the current scene, loaded as it is entered so that they play without delay
+    handles: HashMap<Sound, Handle<AudioSource>>,
+    /// Small enough to be kept for every scene
+    synth_handles: HashMap<Sound, Handle<SynthSound>>,
+    playing: Vec<PlayingSound>,
+}
+
+fn render_synth_sounds(mut synth_sounds: ResMut<Assets<SynthSound>>, mut bus: ResMut<AudioBus>) {
+    for sound in Sound::ALL {
+        if let SoundSource::Synth(preset) = sound.source() {
+            bus.synth_handles.insert(sound, synth_sounds.add(preset.render()));
+        }
+    }
+}
+
+fn preload_sounds(
+    app_state: Res<State<AppState>>,
+    asset_server: Res<AssetServer>,
+    mut bus: ResMut<AudioBus>,
+) {
+    let game = app_state.0.game();
+    bus.handles.retain(|sound, _| Some(sound.game()) == game);
+    for sound in Sound::ALL {
+        let SoundSource::File(path, _) = sound.source() else { continue; };
+        if Some(sound.game()) == game {
+            bus.handles
+                .entry(sound)
+                .or_insert_with(|| asset_server.load(path));
+        }
+    }
+}
+
+fn update_channel_volumes(
+    settings: Res<Settings>,
+    bus: Res<AudioBus>,
+    audio_sinks: Res<Assets<AudioSink>>,
+) {
+    for channel in AudioChannel::ALL {
+        let volume = channel.volume(&settings);
+        for playing_sound in bus.playing.iter() {
+            if playing_sound.sound.channel() != channel {
+                continue;
+            }
+            if let Some(sink) = audio_sinks.get(&playing_sound.sink) {
+                sink.set_volume(volume);
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn play_sound_event_handler(
+    mut events: EventReader<PlaySoundEvent>,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    audio: Res<Audio>,
+    synth_audio: Res<Audio<SynthSound>>,
+    audio_sinks: Res<Assets<AudioSink>>,
+    asset_server: Res<AssetServer>,
+    mut bus: ResMut<AudioBus>,
+) {
+    let now = time.elapsed_seconds();
+    bus.playing
+        .retain(|playing_sound| now - playing_sound.started_at < playing_sound.sound.length());
+
+    for PlaySoundEvent(sound) in events.iter() {
+        let sound = *sound;
+        let is_same_sound = |playing_sound: &PlayingSound| playing_sound.sound == sound;
+        if bus.playing.iter().filter(|p| is_same_sound(p)).count() >= sound.max_instances() {
+            if sound.is_sustained() {
+                continue;
+            }
+            // the oldest comes first
+            if let Some(index) = bus.playing.iter().position(is_same_sound) {
+                let oldest = bus.playing.remove(index);
+                if let Some(sink) = audio_sinks.get(&oldest.sink) {
+                    sink.stop();
+                }
+            }
+        }
+
+        let playback_settings =
+            PlaybackSettings::ONCE.with_volume(sound.channel().volume(&settings));
+        let sink = match sound.source() {
+            // not preloaded when sent from another scene, so only heard once loaded
+            SoundSource::File(path, _) => {
+                let source = bus
+                    .handles
+                    .entry(sound)
+                    .or_insert_with(|| asset_server.load(path))
+                    .clone();
+                audio.play_with_settings(source, playback_settings)
+            }
+            SoundSource::Synth(_) => {
+                let Some(source) = bus.synth_handles.get(&sound).cloned() else { continue; };
+                synth_audio.play_with_settings(source, playback_settings)
+            }
+        };
+        bus.playing.push(PlayingSound {
+            sound,
+            sink: audio_sinks.get_handle(sink),
+            started_at: now,
+        });
+    }
+}
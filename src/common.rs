@@ -2,11 +2,12 @@ use std::slice::Iter;
 
 use bevy::math::Vec2;
 use bevy::prelude::{Color, Component, Resource, States};
+use serde::{Deserialize, Serialize};
 
 use crate::common::Game::*;
 use crate::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Game {
     Asteroid,
     Bomberman,
@@ -20,10 +21,29 @@ pub enum Game {
 
 #[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
 pub enum AppState {
-    Menu,
     #[default]
+    Menu,
     Asteroid,
+    Bomberman,
     Breakout,
+    PacMan,
+    Sokoban,
+    SpaceInvaders,
+    Tetris,
+    Tron,
+    HighScores,
+    Controls,
+    Settings,
+}
+
+/// Whether the game being played is frozen behind the pause overlay
+#[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+    /// Settings opened from the pause overlay, without leaving the game
+    Settings,
 }
 
 impl Game {
@@ -53,6 +73,67 @@ impl Game {
             Tron => Color::GREEN,
         }
     }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Asteroid => "Asteroid",
+            Bomberman => "Bomberman",
+            Breakout => "Breakout",
+            PacMan => "Pac-Man",
+            Sokoban => "Sokoban",
+            SpaceInvaders => "Space Invaders",
+            Tetris => "Tetris",
+            Tron => "Tron",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Asteroid => "Blast drifting asteroids apart in an endless wrapping field of space.",
+            Bomberman => "Blow up crates and rivals with chain reacting bombs in a walled arena.",
+            Breakout => "Bounce the ball off your paddle to break every block of the wall.",
+            PacMan => "Eat every pellet of the maze while staying away from the four ghosts.",
+            Sokoban => "Push the boxes onto their goals without getting them stuck in corners.",
+            SpaceInvaders => "Hold back the waves of descending invaders from behind your shields.",
+            Tetris => "Rotate the falling pieces to clear full lines before the well fills up.",
+            Tron => "Steer your light cycle and make your rivals crash into the walls of light.",
+        }
+    }
+
+    pub fn thumbnail(self) -> &'static str {
+        match self {
+            Asteroid => "thumbnails/asteroid.png",
+            Bomberman => "thumbnails/bomberman.png",
+            Breakout => "thumbnails/breakout.png",
+            PacMan => "thumbnails/pacman.png",
+            Sokoban => "thumbnails/sokoban.png",
+            SpaceInvaders => "thumbnails/space_invaders.png",
+            Tetris => "thumbnails/tetris.png",
+            Tron => "thumbnails/tron.png",
+        }
+    }
+
+    pub fn app_state(self) -> AppState {
+        match self {
+            Asteroid => AppState::Asteroid,
+            Bomberman => AppState::Bomberman,
+            Breakout => AppState::Breakout,
+            PacMan => AppState::PacMan,
+            Sokoban => AppState::Sokoban,
+            SpaceInvaders => AppState::SpaceInvaders,
+            Tetris => AppState::Tetris,
+            Tron => AppState::Tron,
+        }
+    }
+}
+
+impl AppState {
+    /// Game played in this state, if any
+    pub fn game(&self) -> Option<Game> {
+        Game::supported_games()
+            .find(|game| game.app_state() == *self)
+            .copied()
+    }
 }
 
 #[derive(Resource, Debug)]
@@ -5,6 +5,7 @@ use bevy::sprite::Anchor;
 
 use crate::common::ViewportSize;
 use crate::constants::{FONT_FILE, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::settings::Settings;
 
 const FPS_MARGIN_LEFT: f32 = 5.0;
 const FPS_MARGIN_TOP: f32 = 0.0;
@@ -16,22 +17,15 @@ pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
-        init_plugin(app);
+        app.add_plugin(FrameTimeDiagnosticsPlugin::default())
+            .add_startup_system(setup)
+            .add_system(update_scale)
+            .add_system(update_fps)
+            .add_system(update_visibility.run_if(resource_changed::<Settings>()))
+            .add_system(update_position.in_base_set(CoreSet::PostUpdate));
     }
 }
 
-#[cfg(debug_assertions)]
-fn init_plugin(app: &mut App) {
-    app.add_plugin(FrameTimeDiagnosticsPlugin::default())
-        .add_startup_system(setup)
-        .add_system(update_scale)
-        .add_system(update_fps)
-        .add_system(update_position.in_base_set(CoreSet::PostUpdate));
-}
-
-#[cfg(not(debug_assertions))]
-fn init_plugin(_app: &mut App) {}
-
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font = asset_server.load(FONT_FILE.to_string());
     let text_style = TextStyle {
@@ -54,6 +48,17 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     ));
 }
 
+/// The counter is shown in every build, as long as the player asked for it in the settings
+fn update_visibility(settings: Res<Settings>, mut query: Query<&mut Visibility, With<FpsText>>) {
+    for mut visibility in query.iter_mut() {
+        *visibility = if settings.show_fps {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
 fn update_scale(viewport_size: Res<ViewportSize>, mut query: Query<&mut Transform, With<FpsText>>) {
     if !viewport_size.is_changed() {
         return;
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{AppState, Game};
+use crate::storage::{data_file_path, file_version, write_file};
+
+/// Bumped whenever the layout of the high scores file changes
+const HIGH_SCORES_FILE_VERSION: u32 = 1;
+const HIGH_SCORES_FILE_NAME: &str = "high_scores.ron";
+
+pub const HIGH_SCORE_TABLE_SIZE: usize = 10;
+pub const INITIALS_LENGTH: usize = 3;
+/// Seconds the game over screen stays up before the initials of a qualifying score are asked for
+const INITIALS_ENTRY_DELAY: f32 = 3.0;
+
+/// Games keeping a score, in the order their tables are browsed
+pub const HIGH_SCORE_GAMES: [Game; 5] = [
+    Game::Asteroid,
+    Game::Breakout,
+    Game::PacMan,
+    Game::SpaceInvaders,
+    Game::Tetris,
+];
+
+pub struct HighScoresPlugin;
+
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HighScores::load())
+            .init_resource::<PendingHighScore>()
+            .init_resource::<InitialsEntryDelay>()
+            .add_event::<SubmitScoreEvent>()
+            .add_system(submit_score_event_handler)
+            .add_system(initials_entry_delay.after(submit_score_event_handler));
+    }
+}
+
+/// Sent by a game once it is over, its score being kept if it makes it into the table
+pub struct SubmitScoreEvent {
+    pub game: Game,
+    pub score: i32,
+    /// Game specific stats, such as the level reached, as label and value pairs
+    pub stats: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HighScoreEntry {
+    pub initials: String,
+    pub score: i32,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub stats: Vec<(String, String)>,
+}
+
+impl HighScoreEntry {
+    /// UTC date of the entry, formatted as YYYY-MM-DD
+    pub fn date(&self) -> String {
+        let (year, month, day) = civil_from_days((self.timestamp / 86400) as i64);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    pub fn stats_summary(&self) -> String {
+        self.stats
+            .iter()
+            .map(|(label, value)| format!("{} {}", label, value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Qualifying score waiting for the player initials before being added to its table
+#[derive(Resource, Default)]
+pub struct PendingHighScore(pub Option<(Game, HighScoreEntry)>);
+
+/// Running while the game over screen of a qualifying score is shown
+#[derive(Resource, Default)]
+struct InitialsEntryDelay(Option<Timer>);
+
+/// Best scores of every game, sorted from the highest
+#[derive(Resource, Default)]
+pub struct HighScores {
+    tables: HashMap<Game, Vec<HighScoreEntry>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HighScoresFile {
+    version: u32,
+    tables: HashMap<Game, Vec<HighScoreEntry>>,
+}
+
+impl HighScores {
+    pub fn load() -> Self {
+        let Some(path) = data_file_path(HIGH_SCORES_FILE_NAME) else {
+            return HighScores::default();
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            return HighScores::default();
+        };
+
+        let version = match file_version(&content) {
+            Ok(version) => version,
+            Err(err) => {
+                warn!("Ignoring unreadable high scores file {:?}: {}", path, err);
+                return HighScores::default();
+            }
+        };
+        match version {
+            HIGH_SCORES_FILE_VERSION => match ron::from_str::<HighScoresFile>(&content) {
+                Ok(file) => HighScores {
+                    tables: file.tables,
+                },
+                Err(err) => {
+                    warn!("Ignoring unreadable high scores file {:?}: {}", path, err);
+                    HighScores::default()
+                }
+            },
+            _ => {
+                warn!(
+                    "Ignoring high scores file {:?} with unsupported version {}",
+                    path, version
+                );
+                HighScores::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = data_file_path(HIGH_SCORES_FILE_NAME) else { return; };
+        let file = HighScoresFile {
+            version: HIGH_SCORES_FILE_VERSION,
+            tables: self.tables.clone(),
+        };
+        let content = ron::ser::to_string_pretty(&file, PrettyConfig::default()).unwrap();
+        if let Err(err) = write_file(&path, content) {
+            warn!("Could not save high scores to {:?}: {}", path, err);
+        }
+    }
+
+    pub fn table(&self, game: Game) -> &[HighScoreEntry] {
+        self.tables.get(&game).map_or(&[], |table| table.as_slice())
+    }
+
+    pub fn best(&self, game: Game) -> Option<i32> {
+        self.table(game).first().map(|entry| entry.score)
+    }
+
+    pub fn qualifies(&self, game: Game, score: i32) -> bool {
+        let table = self.table(game);
+        score > 0
+            && (table.len() < HIGH_SCORE_TABLE_SIZE
+                || score > table[HIGH_SCORE_TABLE_SIZE - 1].score)
+    }
+
+    /// Adds the entry after the ones with the same score, returns its rank starting from 0
+    pub fn insert(&mut self, game: Game, entry: HighScoreEntry) -> usize {
+        let table = self.tables.entry(game).or_default();
+        let rank = table
+            .iter()
+            .position(|other| other.score < entry.score)
+            .unwrap_or(table.len());
+        table.insert(rank, entry);
+        table.truncate(HIGH_SCORE_TABLE_SIZE);
+        rank
+    }
+}
+
+/// Year, month and day of a number of days since the Unix epoch in the proleptic Gregorian calendar
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u32, day as u32)
+}
+
+fn submit_score_event_handler(
+    mut events: EventReader<SubmitScoreEvent>,
+    high_scores: Res<HighScores>,
+    mut pending_high_score: ResMut<PendingHighScore>,
+    mut entry_delay: ResMut<InitialsEntryDelay>,
+) {
+    for event in events.iter() {
+        if !high_scores.qualifies(event.game, event.score) {
+            continue;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        pending_high_score.0 = Some((
+            event.game,
+            HighScoreEntry {
+                initials: String::new(),
+                score: event.score,
+                timestamp,
+                stats: event.stats.clone(),
+            },
+        ));
+        entry_delay.0 = Some(Timer::from_seconds(INITIALS_ENTRY_DELAY, TimerMode::Once));
+    }
+}
+
+/// Asks for the initials once the player had the time to see how their game ended
+fn initials_entry_delay(
+    time: Res<Time>,
+    mut entry_delay: ResMut<InitialsEntryDelay>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(timer) = entry_delay.0.as_mut() else { return; };
+    if timer.tick(time.delta()).finished() {
+        entry_delay.0 = None;
+        next_state.set(AppState::HighScores);
+    }
+}
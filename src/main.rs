@@ -1,40 +1,79 @@
 use bevy::prelude::*;
-use bevy::window::{close_on_esc, PresentMode, Window, WindowPlugin};
+use bevy::window::{close_on_esc, Window, WindowPlugin};
 
+use crate::actions::ActionsPlugin;
+use crate::audio::AudioBusPlugin;
 use crate::common::*;
 use crate::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
 use crate::debug_plugin::DebugPlugin;
+use crate::high_scores::HighScoresPlugin;
+use crate::music::MusicPlugin;
 use crate::rendering_plugin::RenderingPlugin;
-use crate::scenes::{AsteroidScenePlugin, BreakoutScenePlugin, MenuScenePlugin};
+use crate::scenes::{
+    AsteroidScenePlugin, BombermanScenePlugin, BreakoutScenePlugin, ControlsScenePlugin,
+    HighScoresScenePlugin, MenuScenePlugin, PacManScenePlugin, PauseScenePlugin,
+    SettingsScenePlugin, SokobanScenePlugin, SpaceInvadersScenePlugin, TetrisScenePlugin,
+    TronScenePlugin,
+};
+use crate::screen_shake::ScreenShakePlugin;
+use crate::settings::{Settings, SettingsPlugin};
 
+mod actions;
+mod audio;
 mod common;
 mod constants;
 mod debug_plugin;
+mod high_scores;
+mod music;
 mod rendering_plugin;
 mod scenes;
+mod screen_shake;
+mod settings;
+mod storage;
+mod synth;
 mod texture_atlas_loader;
 
 const CLEAR_COLOR: ClearColor = ClearColor(Color::BLACK);
 
 fn main() {
+    let settings = Settings::load();
     App::new()
         .add_state::<AppState>()
+        .add_state::<PauseState>()
         .insert_resource(CLEAR_COLOR)
         .insert_resource(ViewportSize::default())
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Retro Arcade Collection".into(),
                 resolution: (WINDOW_WIDTH, WINDOW_HEIGHT).into(),
-                present_mode: PresentMode::AutoVsync,
+                mode: settings.window_mode(),
+                present_mode: settings.present_mode(),
                 ..default()
             }),
             ..default()
         }))
+        .insert_resource(settings)
+        .add_plugin(ActionsPlugin)
+        .add_plugin(SettingsPlugin)
+        .add_plugin(AudioBusPlugin)
+        .add_plugin(MusicPlugin)
+        .add_plugin(ScreenShakePlugin)
         .add_plugin(DebugPlugin)
         .add_plugin(RenderingPlugin)
+        .add_plugin(HighScoresPlugin)
         .add_plugin(AsteroidScenePlugin)
+        .add_plugin(BombermanScenePlugin)
         .add_plugin(BreakoutScenePlugin)
+        .add_plugin(ControlsScenePlugin)
+        .add_plugin(HighScoresScenePlugin)
         .add_plugin(MenuScenePlugin)
+        .add_plugin(PacManScenePlugin)
+        .add_plugin(PauseScenePlugin)
+        .add_plugin(SettingsScenePlugin)
+        .add_plugin(SokobanScenePlugin)
+        .add_plugin(SpaceInvadersScenePlugin)
+        .add_plugin(TetrisScenePlugin)
+        .add_plugin(TronScenePlugin)
         .add_system(close_on_esc.run_if(is_in_menu))
         .add_startup_system(setup_camera)
         .run();
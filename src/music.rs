@@ -0,0 +1,333 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::audio::AudioChannel;
+use crate::common::{AppState, Game, PauseState};
+use crate::settings::Settings;
+use crate::synth::{mix, note_frequency, render_notes, SynthSound, Waveform};
+
+/// Seconds it takes for a track to fade out while the next one fades in
+const CROSSFADE_DURATION: f32 = 1.5;
+/// Seconds it takes to duck the music, or bring it back
+const DUCK_DURATION: f32 = 0.3;
+/// Volume of the music behind the pause and game over overlays
+const DUCKED_VOLUME: f32 = 0.3;
+const MELODY_VOLUME: f32 = 0.25;
+const BASS_VOLUME: f32 = 0.35;
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DuckMusicEvent>()
+            .add_event::<SetMusicTempoEvent>()
+            .init_resource::<MusicPlayer>()
+            .add_startup_system(render_tracks)
+            .add_system(switch_track.run_if(state_changed::<AppState>()))
+            .add_systems(
+                (music_events_handler, fade_tracks)
+                    .chain()
+                    .in_base_set(CoreSet::PostUpdate),
+            );
+    }
+}
+
+/// Lowers the music while an overlay is shown over the game, until sent again with `false`
+pub struct DuckMusicEvent {
+    pub is_ducked: bool,
+}
+
+/// Plays the current track this many times faster, until the scene is left
+pub struct SetMusicTempoEvent(pub f32);
+
+/// Looping music of a scene, the menu one being shared by every screen outside of the games
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Track {
+    Menu,
+    Game(Game),
+}
+
+/// Notes of a track, one step each: `-` is a rest and `~` holds the previous note a step longer
+struct Score {
+    bpm: f32,
+    melody_waveform: Waveform,
+    melody: &'static str,
+    bass: &'static str,
+}
+
+impl Track {
+    fn of(app_state: &AppState) -> Track {
+        app_state.game().map_or(Track::Menu, Track::Game)
+    }
+
+    fn all() -> impl Iterator<Item = Track> {
+        std::iter::once(Track::Menu).chain(Game::supported_games().copied().map(Track::Game))
+    }
+
+    fn score(self) -> Score {
+        match self {
+            Track::Menu => Score {
+                bpm: 120.0,
+                melody_waveform: Waveform::Square(0.5),
+                melody: "C5 E5 G5 E5 C5 E5 G5 C6 B4 D5 G5 D5 B4 D5 G5 B5 \
+                         A4 C5 E5 C5 A4 C5 E5 A5 F4 A4 C5 F5 G4 B4 D5 G5",
+                bass: "C3 ~ ~ ~ C3 ~ ~ ~ G2 ~ ~ ~ G2 ~ ~ ~ \
+                       A2 ~ ~ ~ A2 ~ ~ ~ F2 ~ ~ ~ G2 ~ ~ ~",
+            },
+            Track::Game(Game::Asteroid) => Score {
+                bpm: 100.0,
+                melody_waveform: Waveform::Square(0.25),
+                melody: "E4 - - - G4 - F#4 - E4 - - - B3 - - - \
+                         E4 - - - G4 - A4 - B4 - A4 - G4 - F#4 -",
+                bass: "E2 E2 E2 E2 E2 E2 E2 E2 C2 C2 C2 C2 D2 D2 D2 D2 \
+                       E2 E2 E2 E2 E2 E2 E2 E2 C2 C2 C2 C2 B1 B1 B1 B1",
+            },
+            Track::Game(Game::Bomberman) => Score {
+                bpm: 140.0,
+                melody_waveform: Waveform::Square(0.5),
+                melody: "G4 - G4 A4 B4 - G4 - C5 - B4 - A4 - - - \
+                         G4 - G4 A4 B4 - D5 - C5 B4 A4 B4 G4 - - -",
+                bass: "G2 - D3 - G2 - D3 - C3 - G2 - D3 - A2 - \
+                       G2 - D3 - G2 - D3 - C3 - D3 - G2 - D3 -",
+            },
+            Track::Game(Game::Breakout) => Score {
+                bpm: 128.0,
+                melody_waveform: Waveform::Square(0.25),
+                melody: "A4 C5 E5 A5 G5 E5 C5 E5 F4 A4 C5 F5 E5 C5 A4 C5 \
+                         G4 B4 D5 G5 F5 D5 B4 D5 E4 G#4 B4 E5 D5 B4 G#4 B4",
+                bass: "A2 ~ A2 ~ A2 ~ A2 ~ F2 ~ F2 ~ F2 ~ F2 ~ \
+                       G2 ~ G2 ~ G2 ~ G2 ~ E2 ~ E2 ~ E2 ~ E2 ~",
+            },
+            Track::Game(Game::PacMan) => Score {
+                bpm: 132.0,
+                melody_waveform: Waveform::Square(0.5),
+                melody: "E5 - C5 - D5 E5 - - G4 - A4 - B4 C5 - - \
+                         E5 D5 C5 - A4 - C5 - D5 - - - - - - -",
+                bass: "C3 G2 C3 G2 C3 G2 C3 G2 F2 C3 F2 C3 F2 C3 F2 C3 \
+                       A2 E2 A2 E2 A2 E2 A2 E2 G2 D3 G2 D3 G2 D3 G2 D3",
+            },
+            Track::Game(Game::Sokoban) => Score {
+                bpm: 90.0,
+                melody_waveform: Waveform::Sine,
+                melody: "C5 ~ E5 ~ D5 ~ G4 ~ A4 ~ C5 ~ B4 ~ ~ ~ \
+                         C5 ~ E5 ~ G5 ~ E5 ~ D5 ~ C5 ~ D5 ~ ~ ~",
+                bass: "C3 ~ ~ ~ G2 ~ ~ ~ A2 ~ ~ ~ E2 ~ ~ ~ \
+                       F2 ~ ~ ~ C3 ~ ~ ~ G2 ~ ~ ~ G2 ~ ~ ~",
+            },
+            Track::Game(Game::SpaceInvaders) => Score {
+                bpm: 100.0,
+                melody_waveform: Waveform::Square(0.125),
+                melody: "- - - - - - - - G4 - - - F4 - - - \
+                         - - - - - - - - D#4 - - - D4 - - -",
+                bass: "C3 - A#2 - A2 - G2 - C3 - A#2 - A2 - G2 - \
+                       C3 - A#2 - A2 - G2 - C3 - A#2 - A2 - G2 -",
+            },
+            Track::Game(Game::Tetris) => Score {
+                bpm: 144.0,
+                melody_waveform: Waveform::Square(0.5),
+                melody: "E5 ~ B4 C5 D5 ~ C5 B4 A4 ~ A4 C5 E5 ~ D5 C5 \
+                         B4 ~ ~ C5 D5 ~ E5 ~ C5 ~ A4 ~ A4 ~ - -",
+                bass: "E2 E3 E2 E3 E2 E3 E2 E3 A2 A3 A2 A3 A2 A3 A2 A3 \
+                       G#2 G#3 G#2 G#3 E2 E3 E2 E3 A2 A3 A2 A3 A2 A3 B2 C3",
+            },
+            Track::Game(Game::Tron) => Score {
+                bpm: 150.0,
+                melody_waveform: Waveform::Square(0.25),
+                melody: "D4 F4 A4 D5 A4 F4 D4 F4 A#3 D4 F4 A#4 F4 D4 A#3 D4 \
+                         C4 E4 G4 C5 G4 E4 C4 E4 A3 C#4 E4 A4 E4 C#4 A3 C#4",
+                bass: "D2 D2 D3 D2 D2 D3 D2 D3 A#1 A#1 A#2 A#1 A#1 A#2 A#1 A#2 \
+                       C2 C2 C3 C2 C2 C3 C2 C3 A1 A1 A2 A1 A1 A2 A1 A2",
+            },
+        }
+    }
+
+    fn render(self) -> SynthSound {
+        let score = self.score();
+        // eighth notes
+        let step_length = 30.0 / score.bpm;
+        let melody = render_notes(
+            score.melody_waveform,
+            &parse_notes(score.melody, step_length),
+            MELODY_VOLUME,
+        );
+        let bass = render_notes(
+            Waveform::Triangle,
+            &parse_notes(score.bass, step_length),
+            BASS_VOLUME,
+        );
+        SynthSound::new(mix(&[melody, bass]))
+    }
+}
+
+/// Frequencies and lengths of the notes written like `C#4`, in steps of the given seconds
+fn parse_notes(notes: &str, step_length: f32) -> Vec<(Option<f32>, f32)> {
+    let mut parsed: Vec<(Option<f32>, f32)> = Vec::new();
+    for token in notes.split_whitespace() {
+        match token {
+            "~" => {
+                if let Some((_, length)) = parsed.last_mut() {
+                    *length += step_length;
+                }
+            }
+            "-" => parsed.push((None, step_length)),
+            _ => parsed.push((parse_pitch(token).map(note_frequency), step_length)),
+        }
+    }
+    parsed
+}
+
+/// Semitones from A4 of a note like `C#4`, `None` when it isn't one
+fn parse_pitch(note: &str) -> Option<i32> {
+    let mut chars = note.chars();
+    let semitone = match chars.next()? {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let rest = chars.as_str();
+    let (semitone, octave) = match rest.strip_prefix('#') {
+        Some(octave) => (semitone + 1, octave),
+        None => (semitone, rest),
+    };
+    let octave: i32 = octave.parse().ok()?;
+    Some((octave - 4) * 12 + semitone - 9)
+}
+
+struct PlayingTrack {
+    track: Track,
+    sink: Handle<AudioSink>,
+    /// Volume of the track in the crossfade, from 0.0 to 1.0
+    fade: f32,
+}
+
+#[derive(Resource)]
+struct MusicPlayer {
+    tracks: HashMap<Track, Handle<SynthSound>>,
+    /// The track of the scene comes first, followed by the ones still fading out
+    playing: Vec<PlayingTrack>,
+    tempo: f32,
+    is_ducked: bool,
+    /// Multiplier of the volume, moving towards `DUCKED_VOLUME` while ducked
+    duck_volume: f32,
+}
+
+impl Default for MusicPlayer {
+    fn default() -> Self {
+        MusicPlayer {
+            tracks: HashMap::default(),
+            playing: Vec::new(),
+            tempo: 1.0,
+            is_ducked: false,
+            duck_volume: 1.0,
+        }
+    }
+}
+
+fn render_tracks(mut sounds: ResMut<Assets<SynthSound>>, mut player: ResMut<MusicPlayer>) {
+    for track in Track::all() {
+        player.tracks.insert(track, sounds.add(track.render()));
+    }
+}
+
+/// Also run when the scene is restarted, which brings the tempo and volume back
+fn switch_track(
+    app_state: Res<State<AppState>>,
+    audio: Res<Audio<SynthSound>>,
+    audio_sinks: Res<Assets<AudioSink>>,
+    mut player: ResMut<MusicPlayer>,
+) {
+    player.tempo = 1.0;
+    player.is_ducked = false;
+
+    let track = Track::of(&app_state.0);
+    if let Some(index) = player.playing.iter().position(|p| p.track == track) {
+        // still fading out when coming back quickly, so it resumes from where it is
+        let playing_track = player.playing.remove(index);
+        player.playing.insert(0, playing_track);
+        return;
+    }
+
+    let Some(source) = player.tracks.get(&track).cloned() else { return; };
+    let sink = audio.play_with_settings(source, PlaybackSettings::LOOP.with_volume(0.0));
+    player.playing.insert(
+        0,
+        PlayingTrack {
+            track,
+            sink: audio_sinks.get_handle(sink),
+            fade: 0.0,
+        },
+    );
+}
+
+fn music_events_handler(
+    mut duck_events: EventReader<DuckMusicEvent>,
+    mut tempo_events: EventReader<SetMusicTempoEvent>,
+    mut player: ResMut<MusicPlayer>,
+) {
+    if let Some(event) = duck_events.iter().last() {
+        player.is_ducked = event.is_ducked;
+    }
+    if let Some(SetMusicTempoEvent(tempo)) = tempo_events.iter().last() {
+        player.tempo = *tempo;
+    }
+}
+
+fn fade_tracks(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    pause_state: Res<State<PauseState>>,
+    audio_sinks: Res<Assets<AudioSink>>,
+    mut player: ResMut<MusicPlayer>,
+) {
+    let delta = time.delta_seconds();
+    let target_duck_volume = if player.is_ducked || pause_state.0 != PauseState::Running {
+        DUCKED_VOLUME
+    } else {
+        1.0
+    };
+    let duck_step = (1.0 - DUCKED_VOLUME) * delta / DUCK_DURATION;
+    player.duck_volume = if player.duck_volume < target_duck_volume {
+        (player.duck_volume + duck_step).min(target_duck_volume)
+    } else {
+        (player.duck_volume - duck_step).max(target_duck_volume)
+    };
+
+    let volume = AudioChannel::Music.volume(&settings) * player.duck_volume;
+    let tempo = player.tempo;
+    let fade_step = delta / CROSSFADE_DURATION;
+    for (index, playing_track) in player.playing.iter_mut().enumerate() {
+        let sink = audio_sinks.get(&playing_track.sink);
+        if index == 0 {
+            // only once heard, so that the fade in isn't spent while the track starts
+            if sink.is_some() {
+                playing_track.fade = (playing_track.fade + fade_step).min(1.0);
+            }
+        } else {
+            playing_track.fade = (playing_track.fade - fade_step).max(0.0);
+        }
+        if let Some(sink) = sink {
+            sink.set_volume(volume * playing_track.fade);
+            if index == 0 {
+                sink.set_speed(tempo);
+            }
+        }
+    }
+
+    let mut index = 0;
+    player.playing.retain(|playing_track| {
+        let is_current = index == 0;
+        index += 1;
+        if is_current || playing_track.fade > 0.0 {
+            return true;
+        }
+        if let Some(sink) = audio_sinks.get(&playing_track.sink) {
+            sink.stop();
+        }
+        false
+    });
+}
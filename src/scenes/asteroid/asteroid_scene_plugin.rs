@@ -1,13 +1,13 @@
 use bevy::prelude::*;
-use bevy::window::close_on_esc;
 
 use crate::common::AppState;
+use crate::scenes::asteroid::components::AsteroidEntity;
 use crate::scenes::asteroid::event_handlers::EventHandlersPlugin;
 use crate::scenes::asteroid::events::{EventsPlugin, StartGameEvent};
 use crate::scenes::asteroid::game_logic::GameLogicPlugin;
 use crate::scenes::asteroid::input::InputPlugin;
 use crate::scenes::asteroid::rendering::RenderingPlugin;
-use crate::scenes::asteroid::resources::{ResourcesPlugin, SpaceShooterSpriteSheet};
+use crate::scenes::asteroid::resources::{GameState, ResourcesPlugin, SpaceShooterSpriteSheet};
 use crate::scenes::asteroid::utils::FrameSet;
 
 pub struct AsteroidScenePlugin;
@@ -23,8 +23,8 @@ impl Plugin for AsteroidScenePlugin {
             .add_plugin(InputPlugin)
             .add_plugin(RenderingPlugin)
             .add_plugin(ResourcesPlugin)
-            .add_system(close_on_esc)
-            .add_system(setup_scene.in_schedule(OnEnter(AppState::Asteroid)));
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::Asteroid)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::Asteroid)));
     }
 }
 
@@ -33,7 +33,26 @@ fn setup_scene(
     asset_server: Res<AssetServer>,
     texture_atlases: ResMut<Assets<TextureAtlas>>,
     mut space_shooter_sprite_sheet: ResMut<SpaceShooterSpriteSheet>,
+    mut game_state: ResMut<GameState>,
 ) {
-    space_shooter_sprite_sheet.load(&asset_server, texture_atlases);
+    if space_shooter_sprite_sheet.atlas_handle().is_none() {
+        space_shooter_sprite_sheet.load(&asset_server, texture_atlases);
+    }
+    game_state.reset();
     start_events.send_default();
 }
+
+/// Despawns the scene and puts back the camera, which followed the ship
+fn despawn_game(
+    mut commands: Commands,
+    entities: Query<Entity, With<AsteroidEntity>>,
+    mut camera_q: Query<&mut Transform, With<Camera>>,
+) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    for mut transform in camera_q.iter_mut() {
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+    }
+}
@@ -0,0 +1,137 @@
+use bevy::math::{Rect, Vec2};
+use bevy::prelude::{Component, Transform};
+
+/// Collision shape of a sprite, in unscaled sprite pixels around its center
+#[derive(Clone, Debug, Component)]
+pub enum Collider {
+    Circle { radius: f32 },
+    Hull { points: Vec<Vec2> },
+}
+
+/// A collider placed in the world
+enum Shape {
+    Circle { center: Vec2, radius: f32 },
+    Polygon(Vec<Vec2>),
+}
+
+impl Collider {
+    /// The circle inscribed in the sprite bounds, shrunk by `ratio` to forgive the transparent edges
+    pub fn circle_from_bounds(bounds: Rect, ratio: f32) -> Self {
+        Collider::Circle {
+            radius: bounds.width().min(bounds.height()) / 2.0 * ratio,
+        }
+    }
+
+    /// The sprite bounds with their corners cut off; `corner_cut` is the fraction of each side
+    /// removed at both ends, 0.0 giving back the rectangle
+    pub fn hull_from_bounds(bounds: Rect, corner_cut: f32) -> Self {
+        let half = bounds.size() / 2.0;
+        let cut = half * 2.0 * corner_cut;
+        let points = if corner_cut <= 0.0 {
+            vec![
+                Vec2::new(-half.x, -half.y),
+                Vec2::new(half.x, -half.y),
+                Vec2::new(half.x, half.y),
+                Vec2::new(-half.x, half.y),
+            ]
+        } else {
+            vec![
+                Vec2::new(-half.x + cut.x, -half.y),
+                Vec2::new(half.x - cut.x, -half.y),
+                Vec2::new(half.x, -half.y + cut.y),
+                Vec2::new(half.x, half.y - cut.y),
+                Vec2::new(half.x - cut.x, half.y),
+                Vec2::new(-half.x + cut.x, half.y),
+                Vec2::new(-half.x, half.y - cut.y),
+                Vec2::new(-half.x, -half.y + cut.y),
+            ]
+        };
+        Collider::Hull { points }
+    }
+
+    fn placed(&self, transform: &Transform) -> Shape {
+        let center = transform.translation.truncate();
+        let scale = transform.scale.x;
+        match self {
+            Collider::Circle { radius } => Shape::Circle {
+                center,
+                radius: radius * scale,
+            },
+            Collider::Hull { points } => Shape::Polygon(
+                points
+                    .iter()
+                    .map(|point| {
+                        center + (transform.rotation * (*point * scale).extend(0.0)).truncate()
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn intersects(
+        &self,
+        transform: &Transform,
+        other: &Collider,
+        other_transform: &Transform,
+    ) -> bool {
+        match (self.placed(transform), other.placed(other_transform)) {
+            (
+                Shape::Circle { center, radius },
+                Shape::Circle {
+                    center: other_center,
+                    radius: other_radius,
+                },
+            ) => center.distance_squared(other_center) < (radius + other_radius).powi(2),
+            (Shape::Circle { center, radius }, Shape::Polygon(polygon))
+            | (Shape::Polygon(polygon), Shape::Circle { center, radius }) => {
+                circle_intersects_polygon(center, radius, &polygon)
+            }
+            (Shape::Polygon(polygon), Shape::Polygon(other_polygon)) => {
+                polygons_intersect(&polygon, &other_polygon)
+            }
+        }
+    }
+}
+
+fn edge_normals(polygon: &[Vec2]) -> impl Iterator<Item = Vec2> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| (*b - *a).perp().normalize_or_zero())
+}
+
+fn project(polygon: &[Vec2], axis: Vec2) -> (f32, f32) {
+    polygon
+        .iter()
+        .map(|point| point.dot(axis))
+        .fold((f32::MAX, f32::MIN), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// Separating axis test between two convex polygons
+fn polygons_intersect(a: &[Vec2], b: &[Vec2]) -> bool {
+    edge_normals(a).chain(edge_normals(b)).all(|axis| {
+        let (min_a, max_a) = project(a, axis);
+        let (min_b, max_b) = project(b, axis);
+        min_a < max_b && min_b < max_a
+    })
+}
+
+/// Separating axis test between a circle and a convex polygon
+fn circle_intersects_polygon(center: Vec2, radius: f32, polygon: &[Vec2]) -> bool {
+    let Some(closest) = polygon.iter().min_by(|a, b| {
+        a.distance_squared(center)
+            .total_cmp(&b.distance_squared(center))
+    }) else {
+        return false;
+    };
+    let closest_axis = (*closest - center).normalize_or_zero();
+    edge_normals(polygon)
+        .chain(std::iter::once(closest_axis))
+        .all(|axis| {
+            let (min, max) = project(polygon, axis);
+            let circle_center = center.dot(axis);
+            min < circle_center + radius && circle_center - radius < max
+        })
+}
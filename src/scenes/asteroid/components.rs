@@ -1,5 +1,5 @@
 use bevy::math::Vec2;
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Entity};
 use bevy::time::Timer;
 
 #[derive(Debug, Component, Default)]
@@ -13,6 +13,7 @@ pub struct Ship {
 #[derive(Copy, Clone, Debug, Component, Default)]
 pub struct Asteroid {
     pub size_class: usize,
+    pub hit_points: i32,
     pub speed: Vec2,
     pub rotation_speed: f32,
     pub rotation: f32,
@@ -22,3 +23,34 @@ pub struct Asteroid {
 pub struct LaserBeam {
     pub dir: Vec2,
 }
+
+/// Wraps around the edges of the play area
+#[derive(Component)]
+pub struct Wrapping;
+
+/// Copy of a wrapping entity drawn across the edge of the play area when the camera is near it
+#[derive(Component)]
+pub struct WrapGhost {
+    pub source: Entity,
+}
+
+/// Marks everything spawned by the scene, despawned when leaving it
+#[derive(Component)]
+pub struct AsteroidEntity;
+
+/// Kept at a fixed offset from the camera, which follows the ship around the world
+#[derive(Component)]
+pub struct HudElement {
+    /// Position relative to the viewport, from -1 to 1 on each axis
+    pub anchor: Vec2,
+    pub offset: Vec2,
+}
+
+#[derive(Component)]
+pub struct LivesText;
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct EndGameUIElement;
@@ -1,11 +1,19 @@
 use std::f32::consts::PI;
 
-pub const _PLAY_AREA_SIZE: f32 = 5000.0;
+/// Side of the square play area, which wraps around on both axes
+pub const PLAY_AREA_SIZE: f32 = 5000.0;
 
 pub const SHIP_MAX_SPEED: f32 = 500.0;
 pub const SHIP_ACCELERATION: f32 = 1000.0;
 pub const SHIP_ROTATION_SPEED: f32 = 2.0 * PI;
 pub const SHIP_SHOOT_COOLDOWN: f32 = 0.2;
+pub const SHIP_SCALE: f32 = 2.0 / 3.0;
+pub const SHIP_LIVES: i32 = 3;
+pub const SHIP_RESPAWN_DELAY: f32 = 2.0;
+pub const SHIP_INVULNERABILITY_DURATION: f32 = 3.0;
+pub const SHIP_BLINK_INTERVAL: f32 = 0.1;
+/// Share of each side of the ship bounds cut off at the corners of its collision hull
+pub const SHIP_HULL_CORNER_CUT: f32 = 0.3;
 
 pub const LASER_BEAM_SPEED: f32 = 2000.0;
 pub const LASER_BEAM_DESPAWN_SCALE: f32 = 1.1;
@@ -21,3 +29,18 @@ pub const ASTEROID_STARTING_SPEED_MIN: f32 = 50.0;
 pub const ASTEROID_STARTING_SPEED_MAX: f32 = 150.0;
 pub const ASTEROID_SPAWN_INTERVAL: f32 = 0.5;
 pub const ASTEROID_DEFAULT_SPAWN_CHANCE: f32 = 0.2;
+/// Asteroids never leave the wrapping play area, so the spawner stops once this many are left
+pub const MAX_ASTEROIDS: usize = 40;
+/// Share of the inscribed circle used for collisions, meteors being far from round
+pub const ASTEROID_COLLIDER_RATIO: f32 = 0.9;
+/// Laser hits it takes to destroy an asteroid, by size class from tiny to big
+pub const ASTEROID_HIT_POINTS: [i32; 4] = [1, 1, 1, 2];
+/// Score for destroying an asteroid, by size class from tiny to big
+pub const ASTEROID_SCORES: [i32; 4] = [150, 100, 50, 20];
+pub const ASTEROID_FRAGMENTS_MIN: i32 = 2;
+pub const ASTEROID_FRAGMENTS_MAX: i32 = 3;
+/// Speed added to the momentum of the parent asteroid in a random direction
+pub const ASTEROID_FRAGMENT_SPREAD_SPEED_MIN: f32 = 40.0;
+pub const ASTEROID_FRAGMENT_SPREAD_SPEED_MAX: f32 = 120.0;
+/// Strength of the screen shake when the ship is destroyed
+pub const SHIP_DESTROYED_SHAKE: f32 = 0.8;
@@ -1,15 +1,37 @@
+use std::f32::consts::TAU;
+
 use bevy::app::App;
 use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::utils::HashSet;
 use rand::{thread_rng, Rng};
 
-use crate::common::{AppState, ViewportSize};
-use crate::scenes::asteroid::components::{Asteroid, LaserBeam, Ship};
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Game, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::high_scores::SubmitScoreEvent;
+use crate::music::DuckMusicEvent;
+use crate::scenes::asteroid::collision::Collider;
+use crate::scenes::asteroid::components::{
+    Asteroid, AsteroidEntity, EndGameUIElement, HudElement, LaserBeam, LivesText, ScoreText, Ship,
+    Wrapping,
+};
 use crate::scenes::asteroid::constants::{
-    ASTEROID_STARTING_COUNT_MAX, ASTEROID_STARTING_COUNT_MIN, SHIP_SHOOT_COOLDOWN,
+    ASTEROID_COLLIDER_RATIO, ASTEROID_FRAGMENTS_MAX, ASTEROID_FRAGMENTS_MIN,
+    ASTEROID_FRAGMENT_SPREAD_SPEED_MAX, ASTEROID_FRAGMENT_SPREAD_SPEED_MIN, ASTEROID_HIT_POINTS,
+    ASTEROID_MAX_ROTATION_SPEED, ASTEROID_SCORES, ASTEROID_STARTING_COUNT_MAX,
+    ASTEROID_STARTING_COUNT_MIN, SHIP_DESTROYED_SHAKE, SHIP_HULL_CORNER_CUT, SHIP_SCALE,
+    SHIP_SHOOT_COOLDOWN,
+};
+use crate::scenes::asteroid::events::{
+    AsteroidDestroyedEvent, FireLaserEvent, GameOverEvent, LaserHitEvent, RestartGameEvent,
+    ShipDestroyedEvent, SpawnAsteroidsEvent, StartGameEvent,
+};
+use crate::scenes::asteroid::resources::{GameState, SpaceShooterSpriteSheet};
+use crate::scenes::asteroid::utils::{
+    get_meteor_sprite, get_random_meteor_sprite, wrap_position, FrameSet,
 };
-use crate::scenes::asteroid::events::{FireLaserEvent, SpawnAsteroidsEvent, StartGameEvent};
-use crate::scenes::asteroid::resources::SpaceShooterSpriteSheet;
-use crate::scenes::asteroid::utils::{get_random_meteor_sprite, FrameSet};
+use crate::screen_shake::ShakeScreenEvent;
 
 pub struct EventHandlersPlugin;
 
@@ -19,7 +41,13 @@ impl Plugin for EventHandlersPlugin {
             (
                 start_game_event_handler,
                 spawn_asteroids_event_handler.after(start_game_event_handler),
+                // the new game starts next frame, once the old entities are gone
+                restart_game_event_handler.after(spawn_asteroids_event_handler),
                 fire_laser_event_handler,
+                laser_hit_event_handler,
+                asteroid_destroyed_event_handler.after(laser_hit_event_handler),
+                ship_destroyed_event_handler,
+                game_over_event_handler.after(ship_destroyed_event_handler),
             )
                 .in_set(OnUpdate(AppState::Asteroid))
                 .in_set(FrameSet::EventHandling),
@@ -32,6 +60,8 @@ fn start_game_event_handler(
     mut start_events: EventReader<StartGameEvent>,
     mut asteroids_spawn_event: EventWriter<SpawnAsteroidsEvent>,
     space_shooter_sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
 ) {
     if start_events.is_empty() {
         return;
@@ -50,7 +80,7 @@ fn start_game_event_handler(
                     .index_of(ship_sprite_name)
                     .unwrap(),
             ),
-            transform: Transform::from_scale(Vec3::new(2.0 / 3.0, 2.0 / 3.0, 0.0)),
+            transform: Transform::from_scale(Vec3::new(SHIP_SCALE, SHIP_SCALE, 0.0)),
             ..default()
         },
         Ship {
@@ -58,6 +88,78 @@ fn start_game_event_handler(
             shoot_cooldown: Timer::from_seconds(SHIP_SHOOT_COOLDOWN, TimerMode::Once),
             ..default()
         },
+        Collider::hull_from_bounds(
+            space_shooter_sprite_sheet
+                .bounds_of(ship_sprite_name)
+                .unwrap(),
+            SHIP_HULL_CORNER_CUT,
+        ),
+        Wrapping,
+        AsteroidEntity,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_sections([
+                TextSection::new(
+                    "Lives: ",
+                    TextStyle {
+                        font: asset_server.load(FONT_FILE.to_string()),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                TextSection::new(
+                    format!("{}", game_state.lives),
+                    TextStyle {
+                        font: asset_server.load(FONT_FILE.to_string()),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+            ]),
+            text_anchor: Anchor::TopLeft,
+            transform: Transform::from_xyz(0.0, 0.0, 5.0),
+            ..default()
+        },
+        HudElement {
+            anchor: Vec2::new(-1.0, 1.0),
+            offset: Vec2::new(10.0, -10.0),
+        },
+        LivesText,
+        AsteroidEntity,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_sections([
+                TextSection::new(
+                    "Score: ",
+                    TextStyle {
+                        font: asset_server.load(FONT_FILE.to_string()),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                TextSection::new(
+                    format!("{}", game_state.score),
+                    TextStyle {
+                        font: asset_server.load(FONT_FILE.to_string()),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+            ]),
+            text_anchor: Anchor::TopRight,
+            transform: Transform::from_xyz(0.0, 0.0, 5.0),
+            ..default()
+        },
+        HudElement {
+            anchor: Vec2::new(1.0, 1.0),
+            offset: Vec2::new(-10.0, -10.0),
+        },
+        ScoreText,
+        AsteroidEntity,
     ));
 
     asteroids_spawn_event.send(
@@ -80,8 +182,6 @@ fn spawn_asteroids_event_handler(
     let Ok(ship_p) = ship_q.get_single()
         .map(|transform| Vec2::new(transform.translation.x, transform.translation.y))
         else { return; };
-    let atlas_handle = space_shooter_sprite_sheet.atlas_handle().unwrap();
-
     for event in spawn_event.iter() {
         let viewport = Rect::new(
             ship_p.x - width_half * event.spawn_area_ratio,
@@ -114,34 +214,62 @@ fn spawn_asteroids_event_handler(
                 thread_rng().gen_range(-event.max_rotation_speed..event.max_rotation_speed);
 
             let (size_class, sprite_name) = get_random_meteor_sprite(event.big_ratio);
-
-            commands.spawn((
-                SpriteSheetBundle {
-                    texture_atlas: atlas_handle.clone(),
-                    sprite: TextureAtlasSprite::new(
-                        space_shooter_sprite_sheet
-                            .index_of(sprite_name.as_str())
-                            .unwrap(),
-                    ),
-                    transform: Transform::from_translation(Vec3::new(pos.x, pos.y, 0.0)),
-                    ..default()
-                },
-                Asteroid {
-                    rotation_speed,
-                    size_class,
-                    speed: dir * speed,
-                    ..default()
-                },
-            ));
+            spawn_asteroid(
+                &mut commands,
+                &space_shooter_sprite_sheet,
+                sprite_name.as_str(),
+                size_class,
+                pos,
+                dir * speed,
+                rotation_speed,
+            );
         }
     }
 }
 
+fn spawn_asteroid(
+    commands: &mut Commands,
+    space_shooter_sprite_sheet: &SpaceShooterSpriteSheet,
+    sprite_name: &str,
+    size_class: usize,
+    pos: Vec2,
+    speed: Vec2,
+    rotation_speed: f32,
+) {
+    let collider = Collider::circle_from_bounds(
+        space_shooter_sprite_sheet.bounds_of(sprite_name).unwrap(),
+        ASTEROID_COLLIDER_RATIO,
+    );
+    let pos = wrap_position(pos);
+
+    commands.spawn((
+        SpriteSheetBundle {
+            texture_atlas: space_shooter_sprite_sheet.atlas_handle().unwrap(),
+            sprite: TextureAtlasSprite::new(
+                space_shooter_sprite_sheet.index_of(sprite_name).unwrap(),
+            ),
+            transform: Transform::from_translation(Vec3::new(pos.x, pos.y, 0.0)),
+            ..default()
+        },
+        Asteroid {
+            rotation_speed,
+            size_class,
+            hit_points: ASTEROID_HIT_POINTS[size_class],
+            speed,
+            ..default()
+        },
+        collider,
+        Wrapping,
+        AsteroidEntity,
+    ));
+}
+
 fn fire_laser_event_handler(
     mut commands: Commands,
     mut fire_events: EventReader<FireLaserEvent>,
     space_shooter_sprite_sheet: Res<SpaceShooterSpriteSheet>,
     ship_q: Query<(&Transform, &Ship)>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
 ) {
     if fire_events.is_empty() {
         return;
@@ -149,6 +277,7 @@ fn fire_laser_event_handler(
     fire_events.clear();
 
     let Ok((ship_transform, ship)) = ship_q.get_single() else { return; };
+    play_sound_events.send(PlaySoundEvent(Sound::AsteroidLaser));
 
     let atlas_handle = space_shooter_sprite_sheet.atlas_handle().unwrap();
 
@@ -172,16 +301,263 @@ fn fire_laser_event_handler(
                     .index_of(laser_sprite_name)
                     .unwrap(),
             ),
-            transform: Transform::from_translation(Vec3::new(
-                ship_transform.translation.x + trans_x,
-                ship_transform.translation.y + trans_y,
-                0.0,
-            ))
+            transform: Transform::from_translation(
+                wrap_position(Vec2::new(
+                    ship_transform.translation.x + trans_x,
+                    ship_transform.translation.y + trans_y,
+                ))
+                .extend(0.0),
+            )
             .with_rotation(Quat::from_rotation_z(ship.rotation)),
             ..default()
         },
         LaserBeam {
             dir: Vec2::new(trans_x, trans_y).normalize(),
         },
+        Collider::hull_from_bounds(laser_sprite_bounds, 0.0),
+        Wrapping,
+        AsteroidEntity,
     ));
 }
+
+fn laser_hit_event_handler(
+    mut commands: Commands,
+    mut laser_hit_events: EventReader<LaserHitEvent>,
+    mut asteroids_q: Query<(&mut Asteroid, &Transform)>,
+    mut asteroid_destroyed_events: EventWriter<AsteroidDestroyedEvent>,
+) {
+    let mut spent_lasers = HashSet::new();
+    for event in laser_hit_events.iter() {
+        if !spent_lasers.insert(event.laser) {
+            continue;
+        }
+        commands.entity(event.laser).despawn();
+
+        let Ok((mut asteroid, transform)) = asteroids_q.get_mut(event.asteroid) else { continue; };
+        if asteroid.hit_points <= 0 {
+            continue;
+        }
+        asteroid.hit_points -= 1;
+        if asteroid.hit_points == 0 {
+            commands.entity(event.asteroid).despawn();
+            asteroid_destroyed_events.send(AsteroidDestroyedEvent {
+                size_class: asteroid.size_class,
+                pos: transform.translation.truncate(),
+                speed: asteroid.speed,
+            });
+        }
+    }
+}
+
+/// Scores the asteroid and breaks it into fragments one size class smaller
+fn asteroid_destroyed_event_handler(
+    mut commands: Commands,
+    mut asteroid_destroyed_events: EventReader<AsteroidDestroyedEvent>,
+    space_shooter_sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    mut game_state: ResMut<GameState>,
+    mut score_text_q: Query<&mut Text, With<ScoreText>>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if asteroid_destroyed_events.is_empty() {
+        return;
+    }
+
+    for event in asteroid_destroyed_events.iter() {
+        play_sound_events.send(PlaySoundEvent(Sound::AsteroidExplosion));
+        game_state.score += ASTEROID_SCORES[event.size_class];
+        game_state.asteroids_destroyed += 1;
+        if event.size_class == 0 {
+            continue;
+        }
+
+        let size_class = event.size_class - 1;
+        let fragment_count =
+            thread_rng().gen_range(ASTEROID_FRAGMENTS_MIN..=ASTEROID_FRAGMENTS_MAX);
+        for _ in 0..fragment_count {
+            let spread_dir = Vec2::from_angle(thread_rng().gen_range(0.0..TAU));
+            let spread_speed = thread_rng()
+                .gen_range(ASTEROID_FRAGMENT_SPREAD_SPEED_MIN..ASTEROID_FRAGMENT_SPREAD_SPEED_MAX);
+            let rotation_speed =
+                thread_rng().gen_range(-ASTEROID_MAX_ROTATION_SPEED..ASTEROID_MAX_ROTATION_SPEED);
+            spawn_asteroid(
+                &mut commands,
+                &space_shooter_sprite_sheet,
+                get_meteor_sprite(size_class).as_str(),
+                size_class,
+                event.pos,
+                event.speed + spread_dir * spread_speed,
+                rotation_speed,
+            );
+        }
+    }
+
+    for mut text in score_text_q.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.score);
+    }
+}
+
+fn ship_destroyed_event_handler(
+    mut ship_destroyed_events: EventReader<ShipDestroyedEvent>,
+    mut game_state: ResMut<GameState>,
+    mut ship_q: Query<&mut Ship>,
+    mut lives_text_q: Query<&mut Text, With<LivesText>>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+    mut shake_events: EventWriter<ShakeScreenEvent>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if ship_destroyed_events.is_empty() {
+        return;
+    }
+    ship_destroyed_events.clear();
+    if !game_state.is_ship_alive {
+        return;
+    }
+
+    game_state.lives -= 1;
+    game_state.is_ship_alive = false;
+    game_state.respawn_timer.reset();
+    for mut ship in ship_q.iter_mut() {
+        ship.speed = Vec2::ZERO;
+    }
+    shake_events.send(ShakeScreenEvent {
+        strength: SHIP_DESTROYED_SHAKE,
+    });
+    play_sound_events.send(PlaySoundEvent(Sound::AsteroidShipExplosion));
+    for mut text in lives_text_q.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.lives);
+    }
+    if game_state.lives <= 0 {
+        game_over_events.send_default();
+    }
+}
+
+fn game_over_event_handler(
+    mut commands: Commands,
+    mut game_over_events: EventReader<GameOverEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut submit_score_events: EventWriter<SubmitScoreEvent>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if game_over_events.is_empty() {
+        return;
+    }
+    game_over_events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
+    game_state.has_game_ended = true;
+    submit_score_events.send(SubmitScoreEvent {
+        game: Game::Asteroid,
+        score: game_state.score,
+        stats: vec![(
+            "Asteroids".to_string(),
+            game_state.asteroids_destroyed.to_string(),
+        )],
+    });
+
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.85),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        HudElement {
+            anchor: Vec2::ZERO,
+            offset: Vec2::ZERO,
+        },
+        EndGameUIElement,
+        AsteroidEntity,
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "Game Over",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 100.0,
+                    color: Color::WHITE,
+                },
+            ),
+            text_anchor: Anchor::Center,
+            transform: Transform::from_xyz(0.0, 0.0, 11.0),
+            ..default()
+        },
+        HudElement {
+            anchor: Vec2::ZERO,
+            offset: Vec2::new(0.0, 40.0),
+        },
+        EndGameUIElement,
+        AsteroidEntity,
+    ));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                format!("Score: {}", game_state.score),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    color: Color::WHITE,
+                },
+            ),
+            text_anchor: Anchor::Center,
+            transform: Transform::from_xyz(0.0, 0.0, 11.0),
+            ..default()
+        },
+        HudElement {
+            anchor: Vec2::ZERO,
+            offset: Vec2::new(0.0, -40.0),
+        },
+        EndGameUIElement,
+        AsteroidEntity,
+    ));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "[Space]: New Game\n[Esc]: Pause",
+                TextStyle {
+                    font,
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            text_anchor: Anchor::BottomCenter,
+            transform: Transform::from_xyz(0.0, 0.0, 11.0),
+            ..default()
+        },
+        HudElement {
+            anchor: Vec2::new(0.0, -1.0),
+            offset: Vec2::new(0.0, 10.0),
+        },
+        EndGameUIElement,
+        AsteroidEntity,
+    ));
+}
+
+fn restart_game_event_handler(
+    mut commands: Commands,
+    mut restart_events: EventReader<RestartGameEvent>,
+    mut game_state: ResMut<GameState>,
+    entities: Query<Entity, With<AsteroidEntity>>,
+    mut start_events: EventWriter<StartGameEvent>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if restart_events.is_empty() {
+        return;
+    }
+    restart_events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
+
+    for entity in entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    game_state.reset();
+    start_events.send_default();
+}
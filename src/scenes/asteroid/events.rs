@@ -1,7 +1,7 @@
 use std::ops::Range;
 
 use bevy::app::App;
-use bevy::prelude::Plugin;
+use bevy::prelude::{Entity, Plugin, Vec2};
 use bevy::utils::default;
 
 use crate::scenes::asteroid::constants::{
@@ -16,7 +16,12 @@ impl Plugin for EventsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<StartGameEvent>()
             .add_event::<FireLaserEvent>()
-            .add_event::<SpawnAsteroidsEvent>();
+            .add_event::<SpawnAsteroidsEvent>()
+            .add_event::<LaserHitEvent>()
+            .add_event::<AsteroidDestroyedEvent>()
+            .add_event::<ShipDestroyedEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<RestartGameEvent>();
     }
 }
 
@@ -26,6 +31,26 @@ pub struct StartGameEvent;
 #[derive(Default)]
 pub struct FireLaserEvent;
 
+pub struct LaserHitEvent {
+    pub laser: Entity,
+    pub asteroid: Entity,
+}
+
+pub struct AsteroidDestroyedEvent {
+    pub size_class: usize,
+    pub pos: Vec2,
+    pub speed: Vec2,
+}
+
+#[derive(Default)]
+pub struct ShipDestroyedEvent;
+
+#[derive(Default)]
+pub struct GameOverEvent;
+
+#[derive(Default)]
+pub struct RestartGameEvent;
+
 pub struct SpawnAsteroidsEvent {
     pub count: i32,
     pub safe_radius: f32,
@@ -2,11 +2,14 @@ use bevy::prelude::*;
 use rand::{thread_rng, Rng};
 
 use crate::common::{AppState, ViewportSize};
-use crate::scenes::asteroid::components::{Asteroid, LaserBeam, Ship};
-use crate::scenes::asteroid::constants::{LASER_BEAM_DESPAWN_SCALE, LASER_BEAM_SPEED};
-use crate::scenes::asteroid::events::SpawnAsteroidsEvent;
+use crate::scenes::asteroid::collision::Collider;
+use crate::scenes::asteroid::components::{Asteroid, LaserBeam, Ship, Wrapping};
+use crate::scenes::asteroid::constants::{
+    LASER_BEAM_DESPAWN_SCALE, LASER_BEAM_SPEED, MAX_ASTEROIDS,
+};
+use crate::scenes::asteroid::events::{LaserHitEvent, ShipDestroyedEvent, SpawnAsteroidsEvent};
 use crate::scenes::asteroid::resources::GameState;
-use crate::scenes::asteroid::utils::FrameSet;
+use crate::scenes::asteroid::utils::{wrap_position, wrapped_offset, FrameSet};
 
 pub struct GameLogicPlugin;
 
@@ -18,6 +21,12 @@ impl Plugin for GameLogicPlugin {
                 move_lasers_system,
                 move_asteroids_system,
                 asteroid_spawner_system,
+                wrap_around_system
+                    .after(move_ship_system)
+                    .after(move_lasers_system)
+                    .after(move_asteroids_system),
+                collision_system.after(wrap_around_system),
+                ship_respawn_system,
             )
                 .in_set(OnUpdate(AppState::Asteroid))
                 .in_set(FrameSet::GameLogic),
@@ -29,8 +38,6 @@ fn move_ship_system(time: Res<Time>, mut ship_q: Query<(&mut Transform, &Ship)>)
     for (mut transform, ship) in ship_q.iter_mut() {
         transform.translation.x += ship.speed.x * time.delta_seconds();
         transform.translation.y += ship.speed.y * time.delta_seconds();
-
-        // TODO: handle warping back when leaving play area
     }
 }
 
@@ -41,26 +48,19 @@ fn move_lasers_system(
     mut laser_q: Query<(&mut Transform, &LaserBeam, Entity)>,
     ship_q: Query<&mut Transform, (With<Ship>, Without<LaserBeam>)>,
 ) {
-    let no_despawn_are = ship_q.get_single().map(|ship_transform| {
-        let despawn_width = viewport_size.width / 2.0 * LASER_BEAM_DESPAWN_SCALE;
-        let despawn_height = viewport_size.height / 2.0 * LASER_BEAM_DESPAWN_SCALE;
-        let bot_left = Vec2::new(
-            ship_transform.translation.x - despawn_width,
-            ship_transform.translation.y - despawn_height,
-        );
-        let top_right = Vec2::new(
-            ship_transform.translation.x + despawn_width,
-            ship_transform.translation.y + despawn_height,
-        );
-        Rect::from_corners(bot_left, top_right)
-    });
+    let ship_pos = ship_q
+        .get_single()
+        .map(|ship_transform| ship_transform.translation.truncate());
+    let despawn_width = viewport_size.width / 2.0 * LASER_BEAM_DESPAWN_SCALE;
+    let despawn_height = viewport_size.height / 2.0 * LASER_BEAM_DESPAWN_SCALE;
 
     for (mut transform, laser, entity) in laser_q.iter_mut() {
         transform.translation.x += laser.dir.x * LASER_BEAM_SPEED * time.delta_seconds();
         transform.translation.y += laser.dir.y * LASER_BEAM_SPEED * time.delta_seconds();
 
-        if let Ok(area) = no_despawn_are {
-            if !area.contains(transform.translation.truncate()) {
+        if let Ok(ship_pos) = ship_pos {
+            let offset = wrapped_offset(ship_pos, transform.translation.truncate());
+            if offset.x.abs() > despawn_width || offset.y.abs() > despawn_height {
                 commands.entity(entity).despawn();
             }
         }
@@ -75,15 +75,28 @@ fn move_asteroids_system(time: Res<Time>, mut asteroids_q: Query<(&mut Transform
     }
 }
 
+/// Brings everything leaving the play area back in from the opposite edge
+fn wrap_around_system(mut wrapping_q: Query<&mut Transform, With<Wrapping>>) {
+    for mut transform in wrapping_q.iter_mut() {
+        let pos = transform.translation.truncate();
+        let wrapped_pos = wrap_position(pos);
+        if wrapped_pos != pos {
+            transform.translation = wrapped_pos.extend(transform.translation.z);
+        }
+    }
+}
+
 fn asteroid_spawner_system(
     time: Res<Time>,
     mut game_state: ResMut<GameState>,
     viewport_size: Res<ViewportSize>,
+    asteroids_q: Query<(), With<Asteroid>>,
     mut spawn_asteroids_events: EventWriter<SpawnAsteroidsEvent>,
 ) {
     game_state.asteroid_spawn_timer.tick(time.delta());
 
     if game_state.asteroid_spawn_timer.just_finished()
+        && asteroids_q.iter().count() < MAX_ASTEROIDS
         && thread_rng().gen_bool(game_state.asteroid_spawn_chance as f64)
     {
         let safe_radius = viewport_size.width / 2.0 + 200.0;
@@ -91,3 +104,66 @@ fn asteroid_spawner_system(
             .send(SpawnAsteroidsEvent::from_count(1).with_safe_radius(safe_radius))
     }
 }
+
+/// Finds the lasers hitting asteroids and the asteroids hitting the ship
+fn collision_system(
+    game_state: Res<GameState>,
+    ship_q: Query<(&Transform, &Collider), With<Ship>>,
+    laser_q: Query<(Entity, &Transform, &Collider), With<LaserBeam>>,
+    asteroids_q: Query<(Entity, &Transform, &Collider), With<Asteroid>>,
+    mut laser_hit_events: EventWriter<LaserHitEvent>,
+    mut ship_destroyed_events: EventWriter<ShipDestroyedEvent>,
+) {
+    for (laser, laser_transform, laser_collider) in laser_q.iter() {
+        let hit = asteroids_q.iter().find(|(_, transform, collider)| {
+            let transform = nearest_image(laser_transform, transform);
+            laser_collider.intersects(laser_transform, collider, &transform)
+        });
+        if let Some((asteroid, _, _)) = hit {
+            laser_hit_events.send(LaserHitEvent { laser, asteroid });
+        }
+    }
+
+    if !game_state.is_ship_alive || game_state.is_ship_invulnerable() {
+        return;
+    }
+    let Ok((ship_transform, ship_collider)) = ship_q.get_single() else { return; };
+    let is_hit = asteroids_q.iter().any(|(_, transform, collider)| {
+        let transform = nearest_image(ship_transform, transform);
+        ship_collider.intersects(ship_transform, collider, &transform)
+    });
+    if is_hit {
+        ship_destroyed_events.send_default();
+    }
+}
+
+/// The other transform moved to its copy closest to the first one, across the edges of the play area
+fn nearest_image(transform: &Transform, other: &Transform) -> Transform {
+    let pos = transform.translation.truncate();
+    let nearest_pos = pos + wrapped_offset(pos, other.translation.truncate());
+    other.with_translation(nearest_pos.extend(other.translation.z))
+}
+
+fn ship_respawn_system(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut ship_q: Query<&mut Ship>,
+) {
+    if game_state.has_game_ended {
+        return;
+    }
+
+    if game_state.is_ship_alive {
+        game_state.invulnerability_timer.tick(time.delta());
+        return;
+    }
+
+    game_state.respawn_timer.tick(time.delta());
+    if game_state.respawn_timer.just_finished() {
+        game_state.is_ship_alive = true;
+        game_state.invulnerability_timer.reset();
+        for mut ship in ship_q.iter_mut() {
+            ship.speed = Vec2::ZERO;
+        }
+    }
+}
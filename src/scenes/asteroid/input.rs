@@ -1,50 +1,68 @@
 use bevy::prelude::*;
 
+use crate::actions::{Action, Actions};
+use crate::audio::{PlaySoundEvent, Sound};
 use crate::common::AppState;
 use crate::scenes::asteroid::components::Ship;
 use crate::scenes::asteroid::constants::{SHIP_ACCELERATION, SHIP_MAX_SPEED, SHIP_ROTATION_SPEED};
-use crate::scenes::asteroid::events::FireLaserEvent;
+use crate::scenes::asteroid::events::{FireLaserEvent, RestartGameEvent};
+use crate::scenes::asteroid::resources::GameState;
 use crate::scenes::asteroid::utils::FrameSet;
 
 pub struct InputPlugin;
 
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(
-            ship_keyboard_input_system
+        app.add_systems(
+            (ship_input_system, end_game_input_system)
                 .in_set(OnUpdate(AppState::Asteroid))
                 .in_set(FrameSet::Input),
         );
     }
 }
 
-fn ship_keyboard_input_system(
-    keys: Res<Input<KeyCode>>,
+fn ship_input_system(
+    actions: Res<Actions>,
     time: Res<Time>,
+    game_state: Res<GameState>,
     mut fire_events: EventWriter<FireLaserEvent>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
     mut ship_q: Query<&mut Ship>,
 ) {
+    if !game_state.is_ship_alive {
+        return;
+    }
     let Ok(mut ship) = ship_q.get_single_mut() else { return; };
     ship.shoot_cooldown.tick(time.delta());
 
-    if keys.any_pressed([KeyCode::A, KeyCode::Left]) {
-        ship.rotation += time.delta_seconds() * SHIP_ROTATION_SPEED;
-    } else if keys.any_pressed([KeyCode::D, KeyCode::Right]) {
-        ship.rotation -= time.delta_seconds() * SHIP_ROTATION_SPEED;
-    }
+    // turning and thrust follow how far the stick is pushed
+    let rotation_dir = actions.axis(Action::MoveRight, Action::MoveLeft);
+    ship.rotation += time.delta_seconds() * SHIP_ROTATION_SPEED * rotation_dir;
 
-    if keys.any_pressed([KeyCode::W, KeyCode::Up]) {
-        let speed_change_x = SHIP_ACCELERATION * time.delta_seconds() * -ship.rotation.sin();
-        let speed_change_y = SHIP_ACCELERATION * time.delta_seconds() * ship.rotation.cos();
+    if actions.pressed(Action::Thrust) {
+        let acceleration = SHIP_ACCELERATION * actions.value(Action::Thrust);
+        let speed_change_x = acceleration * time.delta_seconds() * -ship.rotation.sin();
+        let speed_change_y = acceleration * time.delta_seconds() * ship.rotation.cos();
         let mut new_speed = Vec2::new(ship.speed.x + speed_change_x, ship.speed.y + speed_change_y);
         if new_speed.length_squared() > SHIP_MAX_SPEED * SHIP_MAX_SPEED {
             new_speed = new_speed.normalize() * SHIP_MAX_SPEED;
         }
         ship.speed = new_speed;
+        play_sound_events.send(PlaySoundEvent(Sound::AsteroidThrust));
     }
 
-    if keys.pressed(KeyCode::Space) && ship.shoot_cooldown.finished() {
+    if actions.pressed(Action::Fire) && ship.shoot_cooldown.finished() {
         ship.shoot_cooldown.reset();
         fire_events.send_default();
     }
 }
+
+fn end_game_input_system(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+) {
+    if game_state.has_game_ended && actions.just_pressed(Action::Confirm) {
+        restart_events.send_default();
+    }
+}
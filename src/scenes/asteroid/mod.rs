@@ -1,6 +1,7 @@
 pub use asteroid_scene_plugin::AsteroidScenePlugin;
 
 mod asteroid_scene_plugin;
+mod collision;
 mod components;
 mod constants;
 mod event_handlers;
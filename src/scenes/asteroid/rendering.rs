@@ -1,8 +1,12 @@
 use bevy::prelude::*;
 
-use crate::common::AppState;
-use crate::scenes::asteroid::components::{Asteroid, Ship};
-use crate::scenes::asteroid::utils::FrameSet;
+use crate::common::{AppState, ViewportSize};
+use crate::scenes::asteroid::components::{
+    Asteroid, AsteroidEntity, HudElement, Ship, WrapGhost, Wrapping,
+};
+use crate::scenes::asteroid::constants::SHIP_BLINK_INTERVAL;
+use crate::scenes::asteroid::resources::GameState;
+use crate::scenes::asteroid::utils::{wrapped_offset, FrameSet};
 
 pub struct RenderingPlugin;
 
@@ -11,8 +15,16 @@ impl Plugin for RenderingPlugin {
         app.add_systems(
             (
                 ship_rotation_update_system,
+                ship_visibility_update_system,
                 camera_follow_system,
+                hud_follow_camera_system.after(camera_follow_system),
                 asteroid_rotation_update_system,
+                spawn_wrap_ghosts_system,
+                wrap_ghosts_update_system
+                    .after(ship_rotation_update_system)
+                    .after(ship_visibility_update_system)
+                    .after(camera_follow_system)
+                    .after(asteroid_rotation_update_system),
             )
                 .in_set(OnUpdate(AppState::Asteroid))
                 .in_set(FrameSet::Rendering),
@@ -44,3 +56,98 @@ fn camera_follow_system(
         camera_transform.translation.z,
     );
 }
+
+/// Hides the ship while it waits to respawn, and makes it blink while invulnerable
+fn ship_visibility_update_system(
+    game_state: Res<GameState>,
+    mut ship_q: Query<&mut Visibility, With<Ship>>,
+) {
+    let is_visible = if !game_state.is_ship_alive {
+        false
+    } else if game_state.is_ship_invulnerable() {
+        let elapsed = game_state.invulnerability_timer.elapsed_secs();
+        ((elapsed / SHIP_BLINK_INTERVAL) as u32).is_multiple_of(2)
+    } else {
+        true
+    };
+    for mut visibility in ship_q.iter_mut() {
+        *visibility = if is_visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn hud_follow_camera_system(
+    viewport_size: Res<ViewportSize>,
+    camera_q: Query<&Transform, (With<Camera>, Without<HudElement>)>,
+    mut hud_q: Query<(&mut Transform, &HudElement)>,
+) {
+    let Ok(camera_transform) = camera_q.get_single() else { return; };
+    let half_viewport = Vec2::new(viewport_size.width, viewport_size.height) / 2.0;
+    for (mut transform, hud_element) in hud_q.iter_mut() {
+        let pos = camera_transform.translation.truncate()
+            + hud_element.anchor * half_viewport
+            + hud_element.offset;
+        transform.translation = pos.extend(transform.translation.z);
+    }
+}
+
+fn spawn_wrap_ghosts_system(
+    mut commands: Commands,
+    wrapping_q: Query<(Entity, &Handle<TextureAtlas>, &TextureAtlasSprite), Added<Wrapping>>,
+) {
+    for (entity, atlas_handle, sprite) in wrapping_q.iter() {
+        commands.spawn((
+            SpriteSheetBundle {
+                texture_atlas: atlas_handle.clone(),
+                sprite: TextureAtlasSprite::new(sprite.index),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            WrapGhost { source: entity },
+            AsteroidEntity,
+        ));
+    }
+}
+
+/// Shows the copy of each wrapping entity closest to the camera when it lies across the edge of
+/// the play area, so that nothing pops in or out of view when wrapping around
+fn wrap_ghosts_update_system(
+    mut commands: Commands,
+    camera_q: Query<&Transform, (With<Camera>, Without<WrapGhost>)>,
+    wrapping_q: Query<(&Transform, &TextureAtlasSprite, &Visibility), With<Wrapping>>,
+    mut ghost_q: Query<
+        (
+            Entity,
+            &WrapGhost,
+            &mut Transform,
+            &mut TextureAtlasSprite,
+            &mut Visibility,
+        ),
+        Without<Wrapping>,
+    >,
+) {
+    let Ok(camera_transform) = camera_q.get_single() else { return; };
+    let camera_pos = camera_transform.translation.truncate();
+
+    for (entity, ghost, mut transform, mut sprite, mut visibility) in ghost_q.iter_mut() {
+        let Ok(source) = wrapping_q.get(ghost.source) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let (source_transform, source_sprite, source_visibility) = source;
+
+        let source_pos = source_transform.translation.truncate();
+        let nearest_pos = camera_pos + wrapped_offset(camera_pos, source_pos);
+        if nearest_pos == source_pos {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        *transform = source_transform.with_translation(nearest_pos.extend(0.0));
+        sprite.index = source_sprite.index;
+        *visibility = *source_visibility;
+    }
+}
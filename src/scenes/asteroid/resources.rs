@@ -3,7 +3,10 @@ use bevy::asset::Handle;
 use bevy::prelude::*;
 use bevy::sprite::TextureAtlas;
 
-use crate::scenes::asteroid::constants::{ASTEROID_DEFAULT_SPAWN_CHANCE, ASTEROID_SPAWN_INTERVAL};
+use crate::scenes::asteroid::constants::{
+    ASTEROID_DEFAULT_SPAWN_CHANCE, ASTEROID_SPAWN_INTERVAL, SHIP_INVULNERABILITY_DURATION,
+    SHIP_LIVES, SHIP_RESPAWN_DELAY,
+};
 use crate::texture_atlas_loader::TextureAtlasLoader;
 
 pub struct ResourcesPlugin;
@@ -53,6 +56,13 @@ impl Default for SpaceShooterSpriteSheet {
 pub struct GameState {
     pub asteroid_spawn_timer: Timer,
     pub asteroid_spawn_chance: f32,
+    pub score: i32,
+    pub asteroids_destroyed: u32,
+    pub lives: i32,
+    pub is_ship_alive: bool,
+    pub has_game_ended: bool,
+    pub respawn_timer: Timer,
+    pub invulnerability_timer: Timer,
 }
 
 impl Default for GameState {
@@ -63,6 +73,26 @@ impl Default for GameState {
                 TimerMode::Repeating,
             ),
             asteroid_spawn_chance: ASTEROID_DEFAULT_SPAWN_CHANCE,
+            score: 0,
+            asteroids_destroyed: 0,
+            lives: SHIP_LIVES,
+            is_ship_alive: true,
+            has_game_ended: false,
+            respawn_timer: Timer::from_seconds(SHIP_RESPAWN_DELAY, TimerMode::Once),
+            invulnerability_timer: Timer::from_seconds(
+                SHIP_INVULNERABILITY_DURATION,
+                TimerMode::Once,
+            ),
         }
     }
 }
+
+impl GameState {
+    pub fn reset(&mut self) {
+        *self = GameState::default();
+    }
+
+    pub fn is_ship_invulnerable(&self) -> bool {
+        !self.invulnerability_timer.finished()
+    }
+}
@@ -1,6 +1,9 @@
+use bevy::math::Vec2;
 use bevy::prelude::SystemSet;
 use rand::{thread_rng, Rng};
 
+use crate::scenes::asteroid::constants::PLAY_AREA_SIZE;
+
 #[derive(SystemSet, Debug, Hash, Eq, PartialEq, Clone)]
 pub enum FrameSet {
     Input,
@@ -15,9 +18,17 @@ pub fn get_random_meteor_sprite(big_ratio: f32) -> (usize, String) {
     } else {
         2
     };
-    let size_name = match size_class {
-        2 => "med",
-        3 => "big",
+
+    (size_class, get_meteor_sprite(size_class))
+}
+
+/// A random meteor sprite of the given size class, from 0 (tiny) to 3 (big)
+pub fn get_meteor_sprite(size_class: usize) -> String {
+    let (size_name, variant_count) = match size_class {
+        0 => ("tiny", 2),
+        1 => ("small", 2),
+        2 => ("med", 2),
+        3 => ("big", 4),
         _ => panic!("Unsupported asteroid size class: {}", size_class),
     };
     let color = if thread_rng().gen_bool(0.5) {
@@ -25,14 +36,21 @@ pub fn get_random_meteor_sprite(big_ratio: f32) -> (usize, String) {
     } else {
         "Grey"
     };
-    let variant = if size_class == 3 {
-        thread_rng().gen_range(1..=4)
-    } else {
-        thread_rng().gen_range(1..=2)
-    };
+    let variant = thread_rng().gen_range(1..=variant_count);
+
+    format!("Meteors/meteor{}_{}{}.png", color, size_name, variant)
+}
 
-    (
-        size_class,
-        format!("Meteors/meteor{}_{}{}.png", color, size_name, variant),
+/// Brings a position back into the play area, centered on the origin
+pub fn wrap_position(pos: Vec2) -> Vec2 {
+    let half_size = PLAY_AREA_SIZE / 2.0;
+    Vec2::new(
+        (pos.x + half_size).rem_euclid(PLAY_AREA_SIZE) - half_size,
+        (pos.y + half_size).rem_euclid(PLAY_AREA_SIZE) - half_size,
     )
 }
+
+/// Shortest offset from one position to another, going across the edges of the play area if needed
+pub fn wrapped_offset(from: Vec2, to: Vec2) -> Vec2 {
+    wrap_position(to - from)
+}
@@ -0,0 +1,34 @@
+use bevy::app::App;
+use bevy::prelude::*;
+
+use crate::common::AppState;
+use crate::scenes::bomberman::components::BombermanEntity;
+use crate::scenes::bomberman::event_handlers::EventHandlerPlugin;
+use crate::scenes::bomberman::events::{EventsPlugin, ShowMatchSetupEvent};
+use crate::scenes::bomberman::input::InputPlugin;
+use crate::scenes::bomberman::logic::LogicPlugin;
+use crate::scenes::bomberman::resources::ResourcesPlugin;
+
+pub struct BombermanScenePlugin;
+
+impl Plugin for BombermanScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResourcesPlugin)
+            .add_plugin(EventsPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(EventHandlerPlugin)
+            .add_plugin(LogicPlugin)
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::Bomberman)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::Bomberman)));
+    }
+}
+
+fn setup_scene(mut show_match_setup_events: EventWriter<ShowMatchSetupEvent>) {
+    show_match_setup_events.send_default();
+}
+
+fn despawn_game(mut commands: Commands, entities: Query<Entity, With<BombermanEntity>>) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
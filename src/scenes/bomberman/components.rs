@@ -0,0 +1,99 @@
+use bevy::math::IVec2;
+use bevy::prelude::{Component, Timer};
+
+use crate::scenes::bomberman::resources::PowerUpKind;
+
+/// Grid movement shared by players and enemies: the actor slides from `cell` to `target`
+#[derive(Component, Clone, Debug)]
+pub struct GridMover {
+    pub cell: IVec2,
+    pub target: IVec2,
+    pub progress: f32,
+    pub speed: f32,
+}
+
+impl GridMover {
+    pub fn new(cell: IVec2, speed: f32) -> Self {
+        GridMover {
+            cell,
+            target: cell,
+            progress: 0.0,
+            speed,
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.cell == self.target
+    }
+
+    /// The cell the actor covers the most of
+    pub fn occupied_cell(&self) -> IVec2 {
+        if self.progress < 0.5 {
+            self.cell
+        } else {
+            self.target
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Player {
+    pub index: usize,
+    /// Direction currently held down
+    pub wish_dir: Option<IVec2>,
+    pub max_bombs: u32,
+    pub range: i32,
+    pub can_kick: bool,
+}
+
+#[derive(Component)]
+pub struct Enemy {
+    pub dir: IVec2,
+}
+
+#[derive(Component)]
+pub struct Bomb {
+    pub owner: usize,
+    pub cell: IVec2,
+    pub range: i32,
+    pub fuse: Timer,
+    /// Set once the bomb was kicked, until it runs into something
+    pub sliding: Option<IVec2>,
+    pub slide_progress: f32,
+}
+
+#[derive(Component)]
+pub struct Flame {
+    pub cell: IVec2,
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+pub struct SoftBlock {
+    pub cell: IVec2,
+}
+
+#[derive(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    pub cell: IVec2,
+}
+
+#[derive(Component)]
+pub struct StatusText;
+
+#[derive(Component)]
+pub struct MatchSetupText;
+
+/// Everything spawned for the current round
+#[derive(Component)]
+pub struct RoundEntity;
+
+#[derive(Component)]
+pub struct MatchSetupUIElement;
+
+#[derive(Component)]
+pub struct EndGameUIElement;
+
+#[derive(Component)]
+pub struct BombermanEntity;
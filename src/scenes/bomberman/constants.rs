@@ -0,0 +1,94 @@
+use bevy::math::IVec2;
+use bevy::prelude::{Color, KeyCode};
+
+pub const PLAY_AREA_WIDTH: f32 = 640.0;
+pub const PLAY_AREA_HEIGHT: f32 = 480.0;
+
+/// The classic 15 by 13 arena, outer wall included
+pub const ARENA_WIDTH: i32 = 15;
+pub const ARENA_HEIGHT: i32 = 13;
+pub const CELL_SIZE: f32 = 32.0;
+pub const ARENA_OFFSET_Y: f32 = -16.0;
+
+pub const SOFT_BLOCK_DENSITY: f64 = 0.7;
+pub const POWER_UP_CHANCE: f64 = 0.3;
+
+pub const MIN_PLAYERS: usize = 1;
+pub const MAX_PLAYERS: usize = 4;
+pub const MAX_ENEMIES: usize = 8;
+/// Manhattan distance between a player start and any enemy
+pub const ENEMY_MIN_START_DISTANCE: i32 = 6;
+
+/// Cells per second
+pub const BASE_SPEED: f32 = 4.0;
+pub const SPEED_STEP: f32 = 0.75;
+pub const MAX_SPEED: f32 = 8.0;
+pub const ENEMY_SPEED: f32 = 2.5;
+pub const BOMB_SLIDE_SPEED: f32 = 8.0;
+pub const START_BOMBS: u32 = 1;
+pub const MAX_BOMBS: u32 = 8;
+pub const START_RANGE: i32 = 2;
+pub const MAX_RANGE: i32 = 8;
+
+pub const BOMB_FUSE: f32 = 2.5;
+pub const FLAME_DURATION: f32 = 0.5;
+/// Strength of the screen shake when bombs go off, chain reactions included
+pub const EXPLOSION_SHAKE: f32 = 0.4;
+/// Chance an enemy changes direction when it reaches a crossing
+pub const ENEMY_TURN_CHANCE: f64 = 0.3;
+
+pub const PLAYER_SIZE: f32 = 24.0;
+pub const ENEMY_SIZE: f32 = 24.0;
+pub const BOMB_SIZE: f32 = 22.0;
+pub const POWER_UP_SIZE: f32 = 20.0;
+
+/// Corner each player starts in
+pub const START_CELLS: [IVec2; MAX_PLAYERS] = [
+    IVec2::new(1, ARENA_HEIGHT - 2),
+    IVec2::new(ARENA_WIDTH - 2, 1),
+    IVec2::new(ARENA_WIDTH - 2, ARENA_HEIGHT - 2),
+    IVec2::new(1, 1),
+];
+
+/// Up, down, left, right and bomb keys of each player
+pub const PLAYER_KEYS: [[KeyCode; 5]; MAX_PLAYERS] = [
+    [
+        KeyCode::W,
+        KeyCode::S,
+        KeyCode::A,
+        KeyCode::D,
+        KeyCode::Space,
+    ],
+    [
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Left,
+        KeyCode::Right,
+        KeyCode::Return,
+    ],
+    [KeyCode::I, KeyCode::K, KeyCode::J, KeyCode::L, KeyCode::U],
+    [
+        KeyCode::Numpad8,
+        KeyCode::Numpad5,
+        KeyCode::Numpad4,
+        KeyCode::Numpad6,
+        KeyCode::Numpad0,
+    ],
+];
+/// Keys of the players after the first, those of player one coming from their bindings
+pub const OTHER_PLAYER_KEY_NAMES: [&str; MAX_PLAYERS - 1] =
+    ["[Arrows] [Enter]", "[IJKL] [U]", "[Num 8456] [Num 0]"];
+
+pub const PLAYER_COLORS: [Color; MAX_PLAYERS] = [
+    Color::WHITE,
+    Color::rgb(0.2, 0.5, 1.0),
+    Color::rgb(1.0, 0.3, 0.3),
+    Color::rgb(0.3, 0.9, 0.3),
+];
+pub const FLOOR_COLOR: Color = Color::rgb(0.1, 0.45, 0.2);
+pub const PILLAR_COLOR: Color = Color::rgb(0.5, 0.5, 0.55);
+pub const SOFT_BLOCK_COLOR: Color = Color::rgb(0.7, 0.5, 0.3);
+pub const ENEMY_COLOR: Color = Color::rgb(1.0, 0.6, 0.0);
+pub const BOMB_COLOR: Color = Color::rgb(0.1, 0.1, 0.1);
+pub const BOMB_FLASH_COLOR: Color = Color::rgb(0.8, 0.1, 0.1);
+pub const FLAME_COLOR: Color = Color::rgb(1.0, 0.8, 0.2);
@@ -0,0 +1,588 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::utils::HashSet;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::music::DuckMusicEvent;
+use crate::scenes::bomberman::components::*;
+use crate::scenes::bomberman::constants::*;
+use crate::scenes::bomberman::events::*;
+use crate::scenes::bomberman::input::*;
+use crate::scenes::bomberman::logic::{bomb_fuses, damage, game_progression};
+use crate::scenes::bomberman::resources::{
+    cell_pos, Arena, GameState, MatchSettings, Screen, Tile,
+};
+use crate::screen_shake::ShakeScreenEvent;
+
+pub struct EventHandlerPlugin;
+
+impl Plugin for EventHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (place_bomb_event_handler, kick_bomb_event_handler)
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(player_input),
+        )
+        .add_system(
+            explosion_event_handler
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(bomb_fuses),
+        )
+        .add_system(
+            explosion_effects
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(bomb_fuses),
+        )
+        .add_system(
+            soft_block_destroyed_event_handler
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(explosion_event_handler),
+        )
+        .add_system(
+            player_killed_event_handler
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(damage)
+                .before(game_progression),
+        )
+        .add_system(
+            game_over_event_handler
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(game_progression),
+        )
+        .add_system(
+            restart_game_event_handler
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(game_over_event_handler)
+                .after(match_setup_input)
+                .after(end_game_input),
+        )
+        .add_system(
+            show_match_setup_event_handler
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(restart_game_event_handler),
+        );
+    }
+}
+
+/// Everything between two games
+type GameEntitiesFilter = Or<(
+    With<RoundEntity>,
+    With<MatchSetupUIElement>,
+    With<EndGameUIElement>,
+)>;
+
+fn cell_sprite(
+    color: Color,
+    cell: IVec2,
+    size: f32,
+    z: f32,
+    scale: f32,
+) -> (SpriteBundle, Renderable) {
+    let pos = cell_pos(cell);
+    (
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, z)
+                .with_scale(Vec3::new(scale, scale, 1.0)),
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT))
+            .with_size(Vec2::splat(size)),
+    )
+}
+
+fn text(
+    value: impl Into<String>,
+    font: Handle<Font>,
+    font_size: f32,
+    pos: Vec2,
+    anchor: Anchor,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 11.0),
+            text_anchor: anchor,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_scale(false, false),
+    )
+}
+
+fn overlay(viewport_size: &ViewportSize, alpha: f32) -> (SpriteBundle, Renderable) {
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    (
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, alpha),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        Renderable::new(Vec2::ZERO, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT))
+            .with_size(overlay_size),
+    )
+}
+
+pub fn place_bomb_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<PlaceBombEvent>,
+    player_query: Query<(&Player, &GridMover)>,
+    bomb_query: Query<&Bomb>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let mut placed: Vec<(usize, IVec2)> = Vec::new();
+    for event in events.iter() {
+        let Some((player, mover)) = player_query
+            .iter()
+            .find(|(player, _)| player.index == event.player)
+        else { continue; };
+        let cell = mover.occupied_cell();
+        let bombs = bomb_query
+            .iter()
+            .map(|bomb| (bomb.owner, bomb.cell))
+            .chain(placed.iter().copied());
+        let mut active = 0;
+        let mut is_taken = false;
+        for (owner, bomb_cell) in bombs {
+            active += (owner == player.index) as u32;
+            is_taken |= bomb_cell == cell;
+        }
+        if is_taken || active >= player.max_bombs {
+            continue;
+        }
+
+        placed.push((player.index, cell));
+        commands.spawn((
+            cell_sprite(BOMB_COLOR, cell, BOMB_SIZE, 2.0, scale),
+            Bomb {
+                owner: player.index,
+                cell,
+                range: player.range,
+                fuse: Timer::from_seconds(BOMB_FUSE, TimerMode::Once),
+                sliding: None,
+                slide_progress: 0.0,
+            },
+            RoundEntity,
+            BombermanEntity,
+        ));
+    }
+}
+
+pub fn kick_bomb_event_handler(
+    mut events: EventReader<KickBombEvent>,
+    mut bomb_query: Query<&mut Bomb>,
+) {
+    for event in events.iter() {
+        for mut bomb in bomb_query.iter_mut() {
+            if bomb.cell == event.cell && bomb.sliding.is_none() {
+                bomb.sliding = Some(event.dir);
+            }
+        }
+    }
+}
+
+/// Shakes and booms once for bombs going off together, the ones they set off included
+fn explosion_effects(
+    mut events: EventReader<ExplosionEvent>,
+    mut shake_events: EventWriter<ShakeScreenEvent>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    shake_events.send(ShakeScreenEvent {
+        strength: EXPLOSION_SHAKE,
+    });
+    play_sound_events.send(PlaySoundEvent(Sound::BombermanExplosion));
+}
+
+/// Sends flames out in a cross from every bomb going off, setting off the bombs they reach
+pub fn explosion_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<ExplosionEvent>,
+    mut arena: ResMut<Arena>,
+    bomb_query: Query<(Entity, &Bomb)>,
+    power_up_query: Query<(Entity, &PowerUp)>,
+    mut soft_block_destroyed_events: EventWriter<SoftBlockDestroyedEvent>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let mut pending: VecDeque<IVec2> = events.iter().map(|event| event.cell).collect();
+    if pending.is_empty() {
+        return;
+    }
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let mut exploded = HashSet::new();
+    let mut flame_cells = HashSet::new();
+    // blocks destroyed by this blast still stop the flames of the bombs it sets off
+    let mut burning_blocks = HashSet::new();
+    while let Some(cell) = pending.pop_front() {
+        let Some((entity, bomb)) = bomb_query.iter().find(|(_, bomb)| bomb.cell == cell) else { continue; };
+        if !exploded.insert(entity) {
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        flame_cells.insert(cell);
+        for dir in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            for distance in 1..=bomb.range {
+                let target = cell + dir * distance;
+                if burning_blocks.contains(&target) {
+                    break;
+                }
+                match arena.tile(target) {
+                    Tile::Pillar => break,
+                    Tile::SoftBlock => {
+                        let power_up = arena.destroy_soft_block(target);
+                        burning_blocks.insert(target);
+                        flame_cells.insert(target);
+                        soft_block_destroyed_events.send(SoftBlockDestroyedEvent {
+                            cell: target,
+                            power_up,
+                        });
+                        break;
+                    }
+                    Tile::Floor => {}
+                }
+                flame_cells.insert(target);
+                if bomb_query.iter().any(|(_, other)| other.cell == target) {
+                    pending.push_back(target);
+                    break;
+                }
+                if let Some((entity, _)) = power_up_query
+                    .iter()
+                    .find(|(_, power_up)| power_up.cell == target)
+                {
+                    commands.entity(entity).despawn_recursive();
+                    break;
+                }
+            }
+        }
+    }
+
+    for cell in flame_cells {
+        commands.spawn((
+            cell_sprite(FLAME_COLOR, cell, CELL_SIZE, 3.0, scale),
+            Flame {
+                cell,
+                timer: Timer::from_seconds(FLAME_DURATION, TimerMode::Once),
+            },
+            RoundEntity,
+            BombermanEntity,
+        ));
+    }
+}
+
+/// Clears the block and reveals the power-up it was hiding, if any
+pub fn soft_block_destroyed_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<SoftBlockDestroyedEvent>,
+    asset_server: Res<AssetServer>,
+    soft_block_query: Query<(Entity, &SoftBlock)>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for event in events.iter() {
+        for (entity, soft_block) in soft_block_query.iter() {
+            if soft_block.cell == event.cell {
+                commands.entity(entity).despawn();
+            }
+        }
+        let Some(kind) = event.power_up else { continue; };
+        commands
+            .spawn((
+                cell_sprite(kind.color(), event.cell, POWER_UP_SIZE, 1.0, scale),
+                PowerUp {
+                    kind,
+                    cell: event.cell,
+                },
+                RoundEntity,
+                BombermanEntity,
+            ))
+            .with_children(|parent| {
+                parent.spawn(Text2dBundle {
+                    text: Text::from_section(
+                        kind.label(),
+                        TextStyle {
+                            font: asset_server.load(FONT_FILE.to_string()),
+                            font_size: 16.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                    ..default()
+                });
+            });
+    }
+}
+
+pub fn player_killed_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<PlayerKilledEvent>,
+    mut game_state: ResMut<GameState>,
+    player_query: Query<(Entity, &Player)>,
+) {
+    for event in events.iter() {
+        if !game_state.alive_players[event.player] {
+            continue;
+        }
+        game_state.alive_players[event.player] = false;
+        for (entity, player) in player_query.iter() {
+            if player.index == event.player {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+pub fn game_over_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<GameOverEvent>,
+    asset_server: Res<AssetServer>,
+    settings: Res<MatchSettings>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    let Some(winner) = events.iter().last().map(|event| event.winner) else { return; };
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
+    game_state.screen = Screen::GameOver;
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    commands.spawn((
+        overlay(&viewport_size, 0.85),
+        EndGameUIElement,
+        BombermanEntity,
+    ));
+
+    let (title, color) = match winner {
+        Some(_) if settings.players == 1 => ("Stage Clear".to_string(), Color::WHITE),
+        Some(winner) => (format!("Player {} Wins", winner + 1), PLAYER_COLORS[winner]),
+        None if settings.players == 1 => ("Game Over".to_string(), Color::WHITE),
+        None => ("Draw".to_string(), Color::WHITE),
+    };
+    let font = asset_server.load(FONT_FILE.to_string());
+    let title_pos = Vec2::new(0.0, 40.0);
+    let mut title = text(
+        title,
+        font.clone(),
+        80.0,
+        title_pos,
+        Anchor::BottomCenter,
+        scale,
+    );
+    title.0.text.sections[0].style.color = color;
+    commands.spawn((title, EndGameUIElement, BombermanEntity));
+    commands.spawn((
+        text(
+            "[R]: New Game
+[Enter]: Match Setup
+[Esc]: Pause",
+            font,
+            20.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        EndGameUIElement,
+        BombermanEntity,
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn restart_game_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<RestartGameEvent>,
+    asset_server: Res<AssetServer>,
+    settings: Res<MatchSettings>,
+    mut game_state: ResMut<GameState>,
+    mut arena: ResMut<Arena>,
+    entities_query: Query<Entity, GameEntitiesFilter>,
+    viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
+
+    for entity in entities_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    game_state.screen = Screen::Playing;
+    game_state.alive_players = vec![true; settings.players];
+    arena.generate(settings.players);
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for y in 0..ARENA_HEIGHT {
+        for x in 0..ARENA_WIDTH {
+            let cell = IVec2::new(x, y);
+            let color = match arena.tile(cell) {
+                Tile::Floor => FLOOR_COLOR,
+                Tile::Pillar => PILLAR_COLOR,
+                Tile::SoftBlock => {
+                    commands.spawn((
+                        cell_sprite(SOFT_BLOCK_COLOR, cell, CELL_SIZE, 1.0, scale),
+                        SoftBlock { cell },
+                        RoundEntity,
+                        BombermanEntity,
+                    ));
+                    FLOOR_COLOR
+                }
+            };
+            commands.spawn((
+                cell_sprite(color, cell, CELL_SIZE, 0.0, scale),
+                RoundEntity,
+                BombermanEntity,
+            ));
+        }
+    }
+
+    for (index, cell) in START_CELLS.iter().take(settings.players).enumerate() {
+        commands.spawn((
+            cell_sprite(PLAYER_COLORS[index], *cell, PLAYER_SIZE, 4.0, scale),
+            GridMover::new(*cell, BASE_SPEED),
+            Player {
+                index,
+                wish_dir: None,
+                max_bombs: START_BOMBS,
+                range: START_RANGE,
+                can_kick: false,
+            },
+            RoundEntity,
+            BombermanEntity,
+        ));
+    }
+
+    // enemies start away from the players, so nobody is caught right away
+    let mut enemy_cells: Vec<IVec2> = arena
+        .floor_cells()
+        .into_iter()
+        .filter(|cell| {
+            START_CELLS.iter().take(settings.players).all(|start| {
+                let offset = (*cell - *start).abs();
+                offset.x + offset.y >= ENEMY_MIN_START_DISTANCE
+            })
+        })
+        .collect();
+    enemy_cells.shuffle(&mut thread_rng());
+    for cell in enemy_cells.into_iter().take(settings.enemies) {
+        commands.spawn((
+            cell_sprite(ENEMY_COLOR, cell, ENEMY_SIZE, 4.0, scale),
+            GridMover::new(cell, ENEMY_SPEED),
+            Enemy { dir: IVec2::X },
+            RoundEntity,
+            BombermanEntity,
+        ));
+    }
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let mut status = text(
+        "",
+        font,
+        20.0,
+        Vec2::new(0.0, PLAY_AREA_HEIGHT / 2.0 - 4.0),
+        Anchor::TopCenter,
+        scale,
+    );
+    status.0.text.sections = PLAYER_COLORS
+        .iter()
+        .take(settings.players)
+        .map(|color| TextSection {
+            value: String::new(),
+            style: TextStyle {
+                color: *color,
+                ..status.0.text.sections[0].style.clone()
+            },
+        })
+        .collect();
+    commands.spawn((status, StatusText, RoundEntity, BombermanEntity));
+}
+
+pub fn show_match_setup_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<ShowMatchSetupEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    entities_query: Query<Entity, GameEntitiesFilter>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    for entity in entities_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    game_state.screen = Screen::MatchSetup;
+    game_state.alive_players.clear();
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        text(
+            "Bomberman",
+            font.clone(),
+            64.0,
+            Vec2::new(0.0, 120.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        MatchSetupUIElement,
+        BombermanEntity,
+    ));
+    // the options are filled in by `match_setup_update`
+    let mut options = text(
+        "",
+        font.clone(),
+        28.0,
+        Vec2::new(0.0, 100.0),
+        Anchor::TopCenter,
+        scale,
+    );
+    options.0.text.alignment = TextAlignment::Left;
+    commands.spawn((
+        options,
+        MatchSetupText,
+        MatchSetupUIElement,
+        BombermanEntity,
+    ));
+    commands.spawn((
+        text(
+            "[Up/Down]: Choose  [Left/Right]: Change\n[Enter]: Start  [Esc]: Pause",
+            font,
+            20.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        MatchSetupUIElement,
+        BombermanEntity,
+    ));
+}
@@ -0,0 +1,53 @@
+use bevy::app::{App, Plugin};
+use bevy::math::IVec2;
+
+use crate::scenes::bomberman::resources::PowerUpKind;
+
+pub struct EventsPlugin;
+
+impl Plugin for EventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlaceBombEvent>()
+            .add_event::<KickBombEvent>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<SoftBlockDestroyedEvent>()
+            .add_event::<PlayerKilledEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<RestartGameEvent>()
+            .add_event::<ShowMatchSetupEvent>();
+    }
+}
+
+pub struct PlaceBombEvent {
+    pub player: usize,
+}
+
+pub struct KickBombEvent {
+    pub cell: IVec2,
+    pub dir: IVec2,
+}
+
+/// A bomb going off; bombs caught in the flames go off along with it
+pub struct ExplosionEvent {
+    pub cell: IVec2,
+}
+
+pub struct SoftBlockDestroyedEvent {
+    pub cell: IVec2,
+    pub power_up: Option<PowerUpKind>,
+}
+
+pub struct PlayerKilledEvent {
+    pub player: usize,
+}
+
+pub struct GameOverEvent {
+    /// `None` when nobody made it
+    pub winner: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct RestartGameEvent;
+
+#[derive(Default)]
+pub struct ShowMatchSetupEvent;
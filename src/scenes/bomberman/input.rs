@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use crate::actions::{Action, ActionBindings, Actions, Binding};
+use crate::common::{AppState, Game};
+use crate::scenes::bomberman::components::Player;
+use crate::scenes::bomberman::constants::PLAYER_KEYS;
+use crate::scenes::bomberman::events::*;
+use crate::scenes::bomberman::resources::{GameState, MatchSettings, Screen, SetupOption};
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(player_input.in_set(OnUpdate(AppState::Bomberman)))
+            .add_system(match_setup_input.in_set(OnUpdate(AppState::Bomberman)))
+            .add_system(end_game_input.in_set(OnUpdate(AppState::Bomberman)));
+    }
+}
+
+const DIRECTIONS: [IVec2; 4] = [IVec2::Y, IVec2::NEG_Y, IVec2::NEG_X, IVec2::X];
+/// Actions matching the up, down, left, right and bomb keys of the players
+const BINDING_ACTIONS: [Action; 5] = [
+    Action::MoveUp,
+    Action::MoveDown,
+    Action::MoveLeft,
+    Action::MoveRight,
+    Action::Fire,
+];
+
+/// Bindings of player one's up, down, left, right and bomb actions, without the keys of the other
+/// players of the match
+pub fn player_one_bindings(action_bindings: &ActionBindings, players: usize) -> [Binding; 5] {
+    let other_players_keys = PLAYER_KEYS[1..players.max(1)].concat();
+    BINDING_ACTIONS.map(|action| {
+        let mut binding = action_bindings.binding(Some(Game::Bomberman), action);
+        binding.keys.retain(|key| !other_players_keys.contains(key));
+        binding
+    })
+}
+
+/// Each player can use their keys or the gamepad connected in the same order. Player one's keys
+/// are the rebindable ones, minus those of the other players.
+pub fn player_input(
+    keys: Res<Input<KeyCode>>,
+    actions: Res<Actions>,
+    action_bindings: Res<ActionBindings>,
+    game_state: Res<GameState>,
+    settings: Res<MatchSettings>,
+    mut player_query: Query<&mut Player>,
+    mut place_bomb_events: EventWriter<PlaceBombEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let player_one_keys =
+        player_one_bindings(&action_bindings, settings.players).map(|binding| binding.keys);
+
+    for mut player in player_query.iter_mut() {
+        let bindings = if player.index == 0 {
+            player_one_keys.clone()
+        } else {
+            PLAYER_KEYS[player.index].map(|key| vec![key])
+        };
+        let gamepad = actions.gamepad(player.index);
+        let is_pressed = |index: usize| {
+            keys.any_pressed(bindings[index].iter().copied())
+                || gamepad.is_some_and(|state| state.pressed(BINDING_ACTIONS[index]))
+        };
+        let is_just_pressed = |index: usize| {
+            keys.any_just_pressed(bindings[index].iter().copied())
+                || gamepad.is_some_and(|state| state.just_pressed(BINDING_ACTIONS[index]))
+        };
+        let held = |dir: &IVec2| is_pressed(DIRECTIONS.iter().position(|d| d == dir).unwrap());
+        // the latest press wins, then whatever is still held
+        let pressed = (0..DIRECTIONS.len())
+            .find(|index| is_just_pressed(*index))
+            .map(|index| DIRECTIONS[index]);
+        let wish_dir = pressed
+            .or(player.wish_dir.filter(held))
+            .or(DIRECTIONS.iter().copied().find(held));
+        if player.wish_dir != wish_dir {
+            player.wish_dir = wish_dir;
+        }
+
+        if is_just_pressed(4) {
+            place_bomb_events.send(PlaceBombEvent {
+                player: player.index,
+            });
+        }
+    }
+}
+
+pub fn match_setup_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut settings: ResMut<MatchSettings>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+) {
+    if game_state.screen != Screen::MatchSetup {
+        return;
+    }
+
+    let option_count = SetupOption::ALL.len();
+    if actions.just_pressed(Action::MoveUp) {
+        settings.selected = (settings.selected + option_count - 1) % option_count;
+    }
+    if actions.just_pressed(Action::MoveDown) {
+        settings.selected = (settings.selected + 1) % option_count;
+    }
+    if actions.just_pressed(Action::MoveLeft) {
+        settings.adjust(-1);
+    }
+    if actions.just_pressed(Action::MoveRight) {
+        settings.adjust(1);
+    }
+    if actions.just_pressed(Action::Confirm) {
+        restart_events.send_default();
+    }
+}
+
+pub fn end_game_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+    mut show_match_setup_events: EventWriter<ShowMatchSetupEvent>,
+) {
+    if game_state.screen != Screen::GameOver {
+        return;
+    }
+
+    // restarting isn't tied to the bomb, which player one can move onto any key
+    if actions.just_pressed(Action::Restart) {
+        restart_events.send_default();
+    } else if actions.just_pressed(Action::Confirm) {
+        show_match_setup_events.send_default();
+    }
+}
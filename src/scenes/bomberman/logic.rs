@@ -0,0 +1,360 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+use crate::actions::{ActionBindings, Binding};
+use crate::common::{AppState, Renderable};
+use crate::scenes::bomberman::components::*;
+use crate::scenes::bomberman::constants::*;
+use crate::scenes::bomberman::event_handlers::{kick_bomb_event_handler, place_bomb_event_handler};
+use crate::scenes::bomberman::events::*;
+use crate::scenes::bomberman::input::player_one_bindings;
+use crate::scenes::bomberman::resources::{
+    cell_pos, Arena, GameState, MatchSettings, PowerUpKind, Screen, SetupOption,
+};
+
+pub struct LogicPlugin;
+
+impl Plugin for LogicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                move_players,
+                move_enemies,
+                bomb_fuses,
+                flames,
+                damage,
+                pick_up_power_ups,
+                game_progression,
+            )
+                .chain()
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(place_bomb_event_handler)
+                .after(kick_bomb_event_handler),
+        )
+        .add_system(
+            status_update
+                .in_set(OnUpdate(AppState::Bomberman))
+                .after(pick_up_power_ups),
+        )
+        .add_system(match_setup_update.in_set(OnUpdate(AppState::Bomberman)));
+    }
+}
+
+const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
+
+/// Advances a mover along its path; `next_target` picks where to go each time a cell is reached
+fn advance(mover: &mut GridMover, delta: f32, mut next_target: impl FnMut(IVec2) -> Option<IVec2>) {
+    if mover.is_idle() {
+        let Some(target) = next_target(mover.cell) else { return; };
+        mover.target = target;
+        mover.progress = 0.0;
+    }
+    mover.progress += mover.speed * delta;
+    if mover.progress >= 1.0 {
+        let leftover = mover.progress - 1.0;
+        mover.cell = mover.target;
+        mover.progress = 0.0;
+        if let Some(target) = next_target(mover.cell) {
+            mover.target = target;
+            mover.progress = leftover;
+        }
+    }
+}
+
+fn mover_pos(mover: &GridMover) -> Vec2 {
+    cell_pos(mover.cell).lerp(cell_pos(mover.target), mover.progress)
+}
+
+pub fn move_players(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    arena: Res<Arena>,
+    mut player_query: Query<(&Player, &mut GridMover, &mut Renderable), Without<Bomb>>,
+    bomb_query: Query<&Bomb>,
+    mut kick_bomb_events: EventWriter<KickBombEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let bomb_cells: HashSet<IVec2> = bomb_query.iter().map(|bomb| bomb.cell).collect();
+    for (player, mut mover, mut renderable) in player_query.iter_mut() {
+        // turning back is allowed halfway between two cells
+        if let Some(dir) = player.wish_dir {
+            if !mover.is_idle() && mover.cell - mover.target == dir {
+                let cell = mover.cell;
+                mover.cell = mover.target;
+                mover.target = cell;
+                mover.progress = 1.0 - mover.progress;
+            }
+        }
+
+        advance(&mut mover, time.delta_seconds(), |cell| {
+            let dir = player.wish_dir?;
+            let next = cell + dir;
+            if !arena.is_floor(next) {
+                return None;
+            }
+            if bomb_cells.contains(&next) {
+                if player.can_kick {
+                    kick_bomb_events.send(KickBombEvent { cell: next, dir });
+                }
+                return None;
+            }
+            Some(next)
+        });
+        renderable.pos = mover_pos(&mover);
+    }
+}
+
+/// Enemies wander the corridors, sometimes turning at crossings
+pub fn move_enemies(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    arena: Res<Arena>,
+    mut enemy_query: Query<(&mut Enemy, &mut GridMover, &mut Renderable)>,
+    bomb_query: Query<&Bomb>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let bomb_cells: HashSet<IVec2> = bomb_query.iter().map(|bomb| bomb.cell).collect();
+    let is_free = |cell: IVec2| arena.is_floor(cell) && !bomb_cells.contains(&cell);
+    for (mut enemy, mut mover, mut renderable) in enemy_query.iter_mut() {
+        advance(&mut mover, time.delta_seconds(), |cell| {
+            let mut rng = thread_rng();
+            let can_go_on = is_free(cell + enemy.dir);
+            if !can_go_on || rng.gen_bool(ENEMY_TURN_CHANCE) {
+                let free_dirs: Vec<IVec2> = DIRECTIONS
+                    .into_iter()
+                    .filter(|dir| is_free(cell + *dir))
+                    .collect();
+                // turning back only when there is nothing else
+                let forward_dirs: Vec<IVec2> = free_dirs
+                    .iter()
+                    .copied()
+                    .filter(|dir| *dir != -enemy.dir)
+                    .collect();
+                let choices = if forward_dirs.is_empty() {
+                    free_dirs
+                } else {
+                    forward_dirs
+                };
+                enemy.dir = *choices.choose(&mut rng)?;
+            }
+            Some(cell + enemy.dir)
+        });
+        renderable.pos = mover_pos(&mover);
+    }
+}
+
+/// Burns the fuses and slides kicked bombs until they run into something
+pub fn bomb_fuses(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    arena: Res<Arena>,
+    mut bomb_query: Query<(&mut Bomb, &mut Sprite, &mut Renderable)>,
+    mover_query: Query<&GridMover>,
+    power_up_query: Query<&PowerUp>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let mut blocked: HashSet<IVec2> = bomb_query.iter().map(|(bomb, _, _)| bomb.cell).collect();
+    blocked.extend(mover_query.iter().map(|mover| mover.occupied_cell()));
+    blocked.extend(power_up_query.iter().map(|power_up| power_up.cell));
+
+    for (mut bomb, mut sprite, mut renderable) in bomb_query.iter_mut() {
+        bomb.fuse.tick(time.delta());
+        if bomb.fuse.just_finished() {
+            explosion_events.send(ExplosionEvent { cell: bomb.cell });
+        }
+        // flashes faster as the fuse burns down
+        let remaining = bomb.fuse.percent_left();
+        let flash_rate = 4.0 + (1.0 - remaining) * 16.0;
+        sprite.color = if ((bomb.fuse.elapsed_secs() * flash_rate) as u32).is_multiple_of(2) {
+            BOMB_COLOR
+        } else {
+            BOMB_FLASH_COLOR
+        };
+
+        let Some(dir) = bomb.sliding else { continue; };
+        if bomb.slide_progress == 0.0 {
+            let next = bomb.cell + dir;
+            if !arena.is_floor(next) || blocked.contains(&next) {
+                bomb.sliding = None;
+                continue;
+            }
+        }
+        bomb.slide_progress += BOMB_SLIDE_SPEED * time.delta_seconds();
+        if bomb.slide_progress >= 1.0 {
+            blocked.remove(&bomb.cell);
+            bomb.cell += dir;
+            blocked.insert(bomb.cell);
+            bomb.slide_progress = 0.0;
+        }
+        renderable.pos = cell_pos(bomb.cell).lerp(cell_pos(bomb.cell + dir), bomb.slide_progress);
+    }
+}
+
+pub fn flames(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flame_query: Query<(Entity, &mut Flame)>,
+) {
+    for (entity, mut flame) in flame_query.iter_mut() {
+        flame.timer.tick(time.delta());
+        if flame.timer.just_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Flames burn players and enemies alike, and enemies kill the players they touch
+pub fn damage(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    flame_query: Query<&Flame>,
+    player_query: Query<(&Player, &GridMover), Without<Enemy>>,
+    enemy_query: Query<(Entity, &GridMover), With<Enemy>>,
+    mut player_killed_events: EventWriter<PlayerKilledEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let flame_cells: HashSet<IVec2> = flame_query.iter().map(|flame| flame.cell).collect();
+    let mut enemy_cells = HashSet::new();
+    for (entity, mover) in enemy_query.iter() {
+        if flame_cells.contains(&mover.occupied_cell()) {
+            commands.entity(entity).despawn();
+        } else {
+            enemy_cells.insert(mover.occupied_cell());
+        }
+    }
+    for (player, mover) in player_query.iter() {
+        let cell = mover.occupied_cell();
+        if flame_cells.contains(&cell) || enemy_cells.contains(&cell) {
+            player_killed_events.send(PlayerKilledEvent {
+                player: player.index,
+            });
+        }
+    }
+}
+
+pub fn pick_up_power_ups(
+    mut commands: Commands,
+    mut player_query: Query<(&mut Player, &mut GridMover)>,
+    power_up_query: Query<(Entity, &PowerUp)>,
+) {
+    for (mut player, mut mover) in player_query.iter_mut() {
+        let cell = mover.occupied_cell();
+        let Some((entity, power_up)) = power_up_query
+            .iter()
+            .find(|(_, power_up)| power_up.cell == cell)
+        else { continue; };
+        match power_up.kind {
+            PowerUpKind::ExtraBomb => player.max_bombs = (player.max_bombs + 1).min(MAX_BOMBS),
+            PowerUpKind::FireRange => player.range = (player.range + 1).min(MAX_RANGE),
+            PowerUpKind::Speed => mover.speed = (mover.speed + SPEED_STEP).min(MAX_SPEED),
+            PowerUpKind::Kick => player.can_kick = true,
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Alone, the player wins by clearing the enemies; together, the last one standing wins
+pub fn game_progression(
+    game_state: Res<GameState>,
+    settings: Res<MatchSettings>,
+    enemy_query: Query<(), With<Enemy>>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let alive: Vec<usize> = game_state
+        .alive_players
+        .iter()
+        .enumerate()
+        .filter(|(_, is_alive)| **is_alive)
+        .map(|(index, _)| index)
+        .collect();
+    let is_over = if settings.players == 1 {
+        alive.is_empty() || enemy_query.is_empty()
+    } else {
+        alive.len() <= 1
+    };
+    if is_over {
+        game_over_events.send(GameOverEvent {
+            winner: alive.first().copied(),
+        });
+    }
+}
+
+pub fn status_update(
+    player_query: Query<(&Player, &GridMover)>,
+    mut status_query: Query<&mut Text, With<StatusText>>,
+) {
+    for mut text in status_query.iter_mut() {
+        for section in text.sections.iter_mut() {
+            section.value.clear();
+        }
+        for (player, mover) in player_query.iter() {
+            let Some(section) = text.sections.get_mut(player.index) else { continue; };
+            let speed_level = ((mover.speed - BASE_SPEED) / SPEED_STEP).round() as i32 + 1;
+            section.value = format!(
+                "P{} B{} F{} S{}{}   ",
+                player.index + 1,
+                player.max_bombs,
+                player.range,
+                speed_level,
+                if player.can_kick { " K" } else { "" }
+            );
+        }
+    }
+}
+
+pub fn match_setup_update(
+    settings: Res<MatchSettings>,
+    action_bindings: Res<ActionBindings>,
+    mut text_query: Query<&mut Text, With<MatchSetupText>>,
+) {
+    let rows: Vec<String> = SetupOption::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let marker = if index == settings.selected { ">" } else { " " };
+            let (label, value) = match option {
+                SetupOption::Players => ("Players", settings.players),
+                SetupOption::Enemies => ("Enemies", settings.enemies),
+            };
+            format!("{} {:<8} < {} >", marker, label, value)
+        })
+        .collect();
+    let player_one = player_one_bindings(&action_bindings, settings.players);
+    let player_one_keys = format!(
+        "[{}] [{}]",
+        player_one[..4]
+            .iter()
+            .map(Binding::keys_label)
+            .collect::<Vec<_>>()
+            .join("/"),
+        player_one[4].keys_label()
+    );
+    let controls: Vec<String> = std::iter::once(player_one_keys)
+        .chain(OTHER_PLAYER_KEY_NAMES.iter().map(|keys| keys.to_string()))
+        .take(settings.players)
+        .enumerate()
+        .map(|(index, keys)| format!("P{}: {}", index + 1, keys))
+        .collect();
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("{}\n\n{}", rows.join("\n"), controls.join("\n"));
+    }
+}
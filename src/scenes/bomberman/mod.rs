@@ -0,0 +1,10 @@
+pub use bomberman_scene_plugin::BombermanScenePlugin;
+
+mod bomberman_scene_plugin;
+mod components;
+mod constants;
+mod event_handlers;
+mod events;
+mod input;
+mod logic;
+mod resources;
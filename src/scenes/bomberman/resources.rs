@@ -0,0 +1,195 @@
+use bevy::app::{App, Plugin};
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{Color, Resource};
+use bevy::utils::HashMap;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+use crate::scenes::bomberman::constants::*;
+
+pub struct ResourcesPlugin;
+
+impl Plugin for ResourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameState::default())
+            .insert_resource(MatchSettings::default())
+            .insert_resource(Arena::default());
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Screen {
+    #[default]
+    MatchSetup,
+    Playing,
+    GameOver,
+}
+
+#[derive(Resource, Default)]
+pub struct GameState {
+    pub screen: Screen,
+    pub alive_players: Vec<bool>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SetupOption {
+    Players,
+    Enemies,
+}
+
+impl SetupOption {
+    pub const ALL: [SetupOption; 2] = [SetupOption::Players, SetupOption::Enemies];
+}
+
+#[derive(Resource)]
+pub struct MatchSettings {
+    pub players: usize,
+    pub enemies: usize,
+    pub selected: usize,
+}
+
+impl Default for MatchSettings {
+    fn default() -> Self {
+        MatchSettings {
+            players: 2,
+            enemies: 3,
+            selected: 0,
+        }
+    }
+}
+
+impl MatchSettings {
+    pub fn adjust(&mut self, delta: i32) {
+        let step = |value: usize, min: usize, max: usize| {
+            (value as i32 + delta).clamp(min as i32, max as i32) as usize
+        };
+        match SetupOption::ALL[self.selected] {
+            SetupOption::Players => self.players = step(self.players, MIN_PLAYERS, MAX_PLAYERS),
+            SetupOption::Enemies => self.enemies = step(self.enemies, 0, MAX_ENEMIES),
+        }
+        // alone, the enemies are all there is to beat
+        if self.players == 1 {
+            self.enemies = self.enemies.max(1);
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PowerUpKind {
+    ExtraBomb,
+    FireRange,
+    Speed,
+    Kick,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::ExtraBomb,
+        PowerUpKind::FireRange,
+        PowerUpKind::Speed,
+        PowerUpKind::Kick,
+    ];
+
+    pub fn color(self) -> Color {
+        match self {
+            PowerUpKind::ExtraBomb => Color::rgb(0.3, 0.3, 0.9),
+            PowerUpKind::FireRange => Color::rgb(1.0, 0.4, 0.1),
+            PowerUpKind::Speed => Color::rgb(0.2, 0.9, 0.9),
+            PowerUpKind::Kick => Color::rgb(0.9, 0.2, 0.9),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerUpKind::ExtraBomb => "B",
+            PowerUpKind::FireRange => "F",
+            PowerUpKind::Speed => "S",
+            PowerUpKind::Kick => "K",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Tile {
+    #[default]
+    Floor,
+    Pillar,
+    SoftBlock,
+}
+
+/// The static layout of the round: pillars, soft blocks and what hides under them
+#[derive(Resource, Default)]
+pub struct Arena {
+    tiles: Vec<Tile>,
+    hidden_power_ups: HashMap<IVec2, PowerUpKind>,
+}
+
+impl Arena {
+    pub fn generate(&mut self, players: usize) {
+        let mut rng = thread_rng();
+        self.tiles = vec![Tile::Floor; (ARENA_WIDTH * ARENA_HEIGHT) as usize];
+        self.hidden_power_ups.clear();
+        for y in 0..ARENA_HEIGHT {
+            for x in 0..ARENA_WIDTH {
+                let cell = IVec2::new(x, y);
+                let is_border = x == 0 || y == 0 || x == ARENA_WIDTH - 1 || y == ARENA_HEIGHT - 1;
+                let tile = if is_border || (x % 2 == 0 && y % 2 == 0) {
+                    Tile::Pillar
+                } else if !is_near_start(cell, players) && rng.gen_bool(SOFT_BLOCK_DENSITY) {
+                    if rng.gen_bool(POWER_UP_CHANCE) {
+                        let kind = *PowerUpKind::ALL.choose(&mut rng).unwrap();
+                        self.hidden_power_ups.insert(cell, kind);
+                    }
+                    Tile::SoftBlock
+                } else {
+                    Tile::Floor
+                };
+                self.tiles[(y * ARENA_WIDTH + x) as usize] = tile;
+            }
+        }
+    }
+
+    pub fn tile(&self, cell: IVec2) -> Tile {
+        let is_inside = (0..ARENA_WIDTH).contains(&cell.x) && (0..ARENA_HEIGHT).contains(&cell.y);
+        if !is_inside {
+            return Tile::Pillar;
+        }
+        self.tiles[(cell.y * ARENA_WIDTH + cell.x) as usize]
+    }
+
+    pub fn is_floor(&self, cell: IVec2) -> bool {
+        self.tile(cell) == Tile::Floor
+    }
+
+    /// Turns a soft block into floor, returning the power-up it was hiding
+    pub fn destroy_soft_block(&mut self, cell: IVec2) -> Option<PowerUpKind> {
+        if self.tile(cell) != Tile::SoftBlock {
+            return None;
+        }
+        self.tiles[(cell.y * ARENA_WIDTH + cell.x) as usize] = Tile::Floor;
+        self.hidden_power_ups.remove(&cell)
+    }
+
+    pub fn floor_cells(&self) -> Vec<IVec2> {
+        (0..ARENA_HEIGHT)
+            .flat_map(|y| (0..ARENA_WIDTH).map(move |x| IVec2::new(x, y)))
+            .filter(|cell| self.is_floor(*cell))
+            .collect()
+    }
+}
+
+/// The start corner and the two cells next to it are kept free so nobody starts boxed in
+pub fn is_near_start(cell: IVec2, players: usize) -> bool {
+    START_CELLS.iter().take(players).any(|start| {
+        let offset = (cell - *start).abs();
+        offset.x + offset.y <= 1
+    })
+}
+
+/// Center of an arena cell in play area units, with row 0 at the bottom
+pub fn cell_pos(cell: IVec2) -> Vec2 {
+    Vec2::new(
+        (cell.x as f32 - (ARENA_WIDTH - 1) as f32 / 2.0) * CELL_SIZE,
+        (cell.y as f32 - (ARENA_HEIGHT - 1) as f32 / 2.0) * CELL_SIZE + ARENA_OFFSET_Y,
+    )
+}
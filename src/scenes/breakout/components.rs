@@ -1,11 +1,26 @@
 use bevy::math::Vec2;
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Timer, TimerMode};
 
-use crate::scenes::breakout::constants::BALL_DEFAULT_SPEED;
+use crate::scenes::breakout::constants::{
+    BALL_DEFAULT_SPEED, BLOCK_REGENERATE_INTERVAL, LASER_COOLDOWN,
+};
+use crate::scenes::breakout::level::BlockType;
+use crate::scenes::breakout::power_up::PowerUpKind;
 
 #[derive(Component)]
 pub struct Paddle {
     pub speed: f32,
+    /// Time until the laser paddle can shoot again
+    pub laser_cooldown: Timer,
+}
+
+impl Paddle {
+    pub fn new(speed: f32) -> Self {
+        Paddle {
+            speed,
+            laser_cooldown: Timer::from_seconds(LASER_COOLDOWN, TimerMode::Once),
+        }
+    }
 }
 
 #[derive(Component)]
@@ -13,6 +28,8 @@ pub struct Ball {
     pub is_attached: bool,
     pub dir: Vec2,
     pub speed: f32,
+    /// Distance from the middle of the paddle at which the ball is held while attached
+    pub attached_offset: f32,
 }
 
 impl Default for Ball {
@@ -21,20 +38,61 @@ impl Default for Ball {
             is_attached: true,
             dir: Vec2::ZERO,
             speed: BALL_DEFAULT_SPEED,
+            attached_offset: 0.0,
         }
     }
 }
 
 #[derive(Component)]
 pub struct Block {
-    pub score: i32,
+    pub block_type: BlockType,
+    pub hit_points: u32,
+    /// Restores a hit point whenever it finishes, restarting with each hit
+    pub regenerate_timer: Timer,
+}
+
+impl Block {
+    pub fn new(block_type: BlockType) -> Self {
+        Block {
+            block_type,
+            hit_points: block_type.hit_points(),
+            regenerate_timer: Timer::from_seconds(BLOCK_REGENERATE_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Falling from a destroyed block until the paddle catches it
+#[derive(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
 }
 
+/// Shot by the laser paddle
+#[derive(Component)]
+pub struct Laser;
+
 #[derive(Component)]
 pub struct ScoreText;
 
+#[derive(Component)]
+pub struct HighScoreText;
+
+#[derive(Component)]
+pub struct LivesText;
+
+#[derive(Component)]
+pub struct PowerUpText;
+
+#[derive(Component)]
+pub struct LevelText;
+
 #[derive(Component)]
 pub struct EndGameUIElement;
 
+/// Wall, background, balls, power-ups, lasers and stage clear screen of the level, replaced when
+/// the next one starts
+#[derive(Component)]
+pub struct LevelEntity;
+
 #[derive(Component)]
 pub struct BreakoutEntity;
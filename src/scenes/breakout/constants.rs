@@ -7,25 +7,41 @@ pub const PADDLE_DEFAULT_SPEED: f32 = 200.0;
 
 pub const BALL_SIZE: f32 = 8.0;
 pub const BALL_DEFAULT_SPEED: f32 = 200.0;
+/// Points a ball scores to speed up by `BALL_SPEED_INCREASE_VALUE`
 pub const BALL_SPEED_INCREASE_SCORE: i32 = 5000;
 pub const BALL_SPEED_INCREASE_VALUE: f32 = 10.0;
 
-pub const BLOCKS_PER_ROW: i32 = 10;
-pub const BLOCK_WIDTH: f32 = PLAY_AREA_WIDTH / BLOCKS_PER_ROW as f32;
+/// Folder under `assets/` holding a RON file per level, played in file name order
+pub const LEVELS_FOLDER: &str = "levels/breakout";
+pub const LEVEL_FILE_EXTENSION: &str = "ron";
+/// Letter of the cells without a block in the level files
+pub const EMPTY_CELL: char = '.';
+/// Seconds between two checks for edited level files
+pub const LEVEL_RELOAD_INTERVAL: f32 = 1.0;
+
+pub const WALL_TOP_Y: f32 = 200.0;
 pub const BLOCK_HEIGHT_RATIO: f32 = 1.0 / 4.0;
+/// Seconds a damaged regenerating block takes to heal each hit point
+pub const BLOCK_REGENERATE_INTERVAL: f32 = 4.0;
+/// Distance from an explosive block at which the others are destroyed, in blocks
+pub const EXPLOSION_RADIUS: f32 = 1.5;
+pub const EXPLOSION_SHAKE: f32 = 0.3;
 
-pub static ROW_SPRITES: [&str; 8] = [
-    "sprites/breakout/block_maroon.png",
-    "sprites/breakout/block_red.png",
-    "sprites/breakout/block_orange.png",
-    "sprites/breakout/block_yellow.png",
-    "sprites/breakout/block_green.png",
-    "sprites/breakout/block_teal.png",
-    "sprites/breakout/block_blue.png",
-    "sprites/breakout/block_purple.png",
-];
+pub const STARTING_LIVES: u32 = 3;
+/// Seconds the stage clear screen is shown before the next level starts
+pub const STAGE_CLEAR_DURATION: f32 = 2.5;
 
-pub enum SoundType {
-    BallHitWall,
-    BallHitPaddle,
-}
+/// Chance of a destroyed block dropping a power-up
+pub const POWER_UP_DROP_CHANCE: f64 = 0.15;
+pub const POWER_UP_WIDTH: f32 = 24.0;
+pub const POWER_UP_FALL_SPEED: f32 = 80.0;
+pub const WIDE_PADDLE_FACTOR: f32 = 1.5;
+pub const NARROW_PADDLE_FACTOR: f32 = 0.6;
+pub const SLOW_BALL_FACTOR: f32 = 0.6;
+/// Angle in radians between the ball and each of the two it splits off
+pub const MULTI_BALL_ANGLE: f32 = 0.5;
+pub const LASER_WIDTH: f32 = 2.0;
+pub const LASER_HEIGHT: f32 = 8.0;
+pub const LASER_SPEED: f32 = 400.0;
+/// Seconds between two shots of the laser paddle, for as long as fire is held
+pub const LASER_COOLDOWN: f32 = 0.3;
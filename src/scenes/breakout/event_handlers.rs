@@ -1,13 +1,21 @@
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
+use bevy::utils::HashSet;
+use rand::{thread_rng, Rng};
 
-use crate::common::{AppState, Renderable, ViewportSize};
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Game, Renderable, ViewportSize};
 use crate::constants::FONT_FILE;
+use crate::high_scores::{HighScores, SubmitScoreEvent};
+use crate::music::DuckMusicEvent;
 use crate::scenes::breakout::components::*;
 use crate::scenes::breakout::constants::*;
 use crate::scenes::breakout::events::*;
+use crate::scenes::breakout::level::BlockType;
 use crate::scenes::breakout::logic::move_ball;
-use crate::scenes::breakout::resources::GameState;
+use crate::scenes::breakout::power_up::PowerUpKind;
+use crate::scenes::breakout::resources::{ActivePowerUps, GameState, LevelCollection};
+use crate::screen_shake::ShakeScreenEvent;
 
 pub struct EventHandlerPlugin;
 
@@ -18,58 +26,288 @@ impl Plugin for EventHandlerPlugin {
                 .in_set(OnUpdate(AppState::Breakout))
                 .after(move_ball),
         )
+        .add_system(
+            stage_clear_event_handler
+                .in_set(OnUpdate(AppState::Breakout))
+                .after(block_destroyed_event_handler),
+        )
+        .add_system(
+            ball_lost_event_handler
+                .in_set(OnUpdate(AppState::Breakout))
+                .after(move_ball)
+                .after(stage_clear_event_handler),
+        )
         .add_system(
             game_over_event_handler
                 .in_set(OnUpdate(AppState::Breakout))
-                .after(move_ball),
+                .after(ball_lost_event_handler),
+        )
+        .add_system(
+            restart_game_event_handler
+                .in_set(OnUpdate(AppState::Breakout))
+                .before(start_level_event_handler),
+        )
+        .add_system(
+            start_level_event_handler
+                .in_set(OnUpdate(AppState::Breakout))
+                .after(block_destroyed_event_handler)
+                .after(power_up_caught_event_handler),
         )
-        .add_system(play_sound_event_handler.in_set(OnUpdate(AppState::Breakout)))
-        .add_system(restart_game_event_handler.in_set(OnUpdate(AppState::Breakout)));
+        .add_system(power_up_caught_event_handler.in_set(OnUpdate(AppState::Breakout)));
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn block_destroyed_event_handler(
     mut commands: Commands,
     mut events: EventReader<BlockDestroyedEvent>,
+    asset_server: Res<AssetServer>,
+    mut blocks_query: Query<(Entity, &mut Block, &Renderable, &mut Handle<Image>)>,
     mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+    mut shake_events: EventWriter<ShakeScreenEvent>,
+    mut stage_clear_events: EventWriter<StageClearEvent>,
 ) {
+    // two balls can hit the same block at once
+    let mut destroyed = HashSet::new();
+    let mut to_destroy = Vec::new();
+    let mut has_exploded = false;
+    let mut drops = Vec::new();
     for event in events.iter() {
-        commands.entity(event.entity).despawn();
-        game_state.score += event.block_value;
+        match *event {
+            BlockDestroyedEvent::Damaged { entity } => {
+                if destroyed.contains(&entity) {
+                    continue;
+                }
+                let Ok((_, mut block, _, mut texture)) = blocks_query.get_mut(entity) else {
+                    continue;
+                };
+                if block.block_type.is_indestructible() {
+                    continue;
+                }
+                block.hit_points = block.hit_points.saturating_sub(1);
+                if block.hit_points == 0 {
+                    to_destroy.push(entity);
+                } else {
+                    *texture = asset_server.load(block.block_type.sprite(block.hit_points));
+                    block.regenerate_timer.reset();
+                }
+            }
+            BlockDestroyedEvent::Destroyed { entity } => to_destroy.push(entity),
+        }
+
+        while let Some(entity) = to_destroy.pop() {
+            let Ok((_, block, renderable, _)) = blocks_query.get(entity) else { continue; };
+            if block.block_type.is_indestructible() || !destroyed.insert(entity) {
+                continue;
+            }
+            commands.entity(entity).despawn();
+            game_state.score += block.block_type.score();
+            game_state.blocks_destroyed += 1;
+            game_state.blocks_left = game_state.blocks_left.saturating_sub(1);
+            if thread_rng().gen_bool(POWER_UP_DROP_CHANCE) {
+                drops.push(renderable.pos);
+            }
+
+            if block.block_type == BlockType::Explosive {
+                has_exploded = true;
+                // the radius is in blocks, so it reaches as many rows as columns
+                let (pos, size) = (renderable.pos, renderable.size);
+                for (other_entity, _, other, _) in blocks_query.iter() {
+                    if ((other.pos - pos) / size).length() <= EXPLOSION_RADIUS {
+                        to_destroy.push(other_entity);
+                    }
+                }
+            }
+        }
+    }
+    // the stage clear sweeps the play area, so the last blocks drop nothing
+    if game_state.blocks_left == 0 {
+        drops.clear();
+    }
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for pos in drops {
+        spawn_power_up(
+            &mut commands,
+            &asset_server,
+            PowerUpKind::random(),
+            pos,
+            scale,
+        );
+    }
+    if has_exploded {
+        play_sound_events.send(PlaySoundEvent(Sound::BreakoutExplosion));
+        shake_events.send(ShakeScreenEvent {
+            strength: EXPLOSION_SHAKE,
+        });
+    }
+    if !destroyed.is_empty() && game_state.blocks_left == 0 {
+        stage_clear_events.send_default();
     }
 }
 
-pub fn play_sound_event_handler(
-    mut events: EventReader<PlaySoundEvent>,
-    audio: Res<Audio>,
+/// Balls, power-ups and lasers, still moving when the wall is cleared or the last ball is lost
+type MovingEntitiesFilter = Or<(With<Ball>, With<PowerUp>, With<Laser>)>;
+
+#[allow(clippy::too_many_arguments)]
+pub fn stage_clear_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<StageClearEvent>,
     asset_server: Res<AssetServer>,
+    levels: Res<LevelCollection>,
+    moving_query: Query<Entity, MovingEntitiesFilter>,
+    mut game_state: ResMut<GameState>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    viewport_size: Res<ViewportSize>,
 ) {
-    for event in events.iter() {
-        let sound = match event.0 {
-            SoundType::BallHitWall => asset_server.load("sound/breakout/ball_impact.ogg"),
-            SoundType::BallHitPaddle => asset_server.load("sound/breakout/ball_paddle_impact.ogg"),
-        };
-        audio.play(sound);
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    for entity in moving_query.iter() {
+        commands.entity(entity).despawn();
     }
+    active_power_ups.clear();
+    game_state.level += 1;
+    game_state.is_stage_clear = true;
+    game_state.stage_clear_timer.reset();
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.6),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        LevelEntity,
+        BreakoutEntity,
+        Renderable::new(Vec2::new(0.0, 0.0), target_resolution).with_size(overlay_size),
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let title_pos = Vec2::new(0.0, 0.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "Stage Clear",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 80.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(title_pos.x * scale, title_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        LevelEntity,
+        BreakoutEntity,
+        Renderable::new(title_pos, target_resolution).with_scale(false, false),
+    ));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                format!(
+                    "Next: Level {}: {}",
+                    game_state.level,
+                    levels.level(game_state.level).name
+                ),
+                TextStyle {
+                    font,
+                    font_size: 30.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(title_pos.x * scale, title_pos.y * scale, 11.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        LevelEntity,
+        BreakoutEntity,
+        Renderable::new(title_pos, target_resolution).with_scale(false, false),
+    ));
+}
+
+/// Takes a life, serving a new ball from the paddle unless it was the last one
+#[allow(clippy::too_many_arguments)]
+pub fn ball_lost_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<BallLostEvent>,
+    asset_server: Res<AssetServer>,
+    levels: Res<LevelCollection>,
+    moving_query: Query<Entity, MovingEntitiesFilter>,
+    paddle_query: Query<&Renderable, With<Paddle>>,
+    mut game_state: ResMut<GameState>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    viewport_size: Res<ViewportSize>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    // the ball fell as the wall was cleared, which it was in time for
+    if game_state.is_stage_clear {
+        return;
+    }
+
+    for entity in moving_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    active_power_ups.clear();
+    game_state.lives = game_state.lives.saturating_sub(1);
+    if game_state.lives == 0 {
+        game_over_events.send_default();
+        return;
+    }
+
+    let paddle_x = paddle_query
+        .get_single()
+        .map(|paddle| paddle.pos.x)
+        .unwrap_or_default();
+    serve_ball(
+        &mut commands,
+        &asset_server,
+        levels.level(game_state.level).ball_speed,
+        paddle_x,
+        viewport_size.height / PLAY_AREA_HEIGHT,
+    );
 }
 
 pub fn game_over_event_handler(
     mut commands: Commands,
     mut events: EventReader<GameOverEvent>,
-    ball_query: Query<Entity, With<Ball>>,
     asset_server: Res<AssetServer>,
     mut game_state: ResMut<GameState>,
     viewport_size: Res<ViewportSize>,
+    mut submit_score_events: EventWriter<SubmitScoreEvent>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
 ) {
     if events.is_empty() {
         return;
     }
     events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
     game_state.has_game_ended = true;
-
-    for entity in ball_query.iter() {
-        commands.entity(entity).despawn()
-    }
+    submit_score_events.send(SubmitScoreEvent {
+        game: Game::Breakout,
+        score: game_state.score,
+        stats: vec![
+            ("Level".to_string(), game_state.level.to_string()),
+            (
+                "Blocks".to_string(),
+                game_state.blocks_destroyed.to_string(),
+            ),
+        ],
+    });
 
     let scale = viewport_size.height / PLAY_AREA_HEIGHT;
     let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
@@ -133,7 +371,7 @@ pub fn game_over_event_handler(
     commands.spawn((
         Text2dBundle {
             text: Text::from_section(
-                "[Space]: New Game\n[Esc]: Menu",
+                "[Space]: New Game\n[Esc]: Pause",
                 TextStyle {
                     font: font.clone(),
                     font_size: 20.0,
@@ -151,20 +389,29 @@ pub fn game_over_event_handler(
     ));
 }
 
+#[allow(clippy::too_many_arguments)]
 fn restart_game_event_handler(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut restart_events: EventReader<RestartGameEvent>,
-    entities_to_clear: Query<Entity, With<BreakoutEntity>>,
+    // the level entities are cleared when the level starts
+    entities_to_clear: Query<Entity, (With<BreakoutEntity>, Without<LevelEntity>)>,
     mut game_state: ResMut<GameState>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    high_scores: Res<HighScores>,
     viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+    mut start_level_events: EventWriter<StartLevelEvent>,
 ) {
     if restart_events.is_empty() {
         return;
     }
     restart_events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
 
     game_state.reset();
+    game_state.high_score = high_scores.best(Game::Breakout).unwrap_or(0);
+    active_power_ups.clear();
     for entity in entities_to_clear.iter() {
         commands.entity(entity).despawn();
     }
@@ -175,7 +422,6 @@ fn restart_game_event_handler(
     let border_pos = PLAY_AREA_WIDTH / 2.0 + 1.0;
     let paddle_size = Vec2::new(PADDLE_WIDTH_RATIO * PADDLE_HEIGHT, PADDLE_HEIGHT);
     let paddle_pos = Vec2::new(0.0, -(PLAY_AREA_HEIGHT - PADDLE_HEIGHT) / 2.0);
-    let ball_pos = Vec2::new(0.0, paddle_pos.y + (paddle_size.y + BALL_SIZE) / 2.0);
     // Spawn left border
     commands.spawn((
         SpriteBundle {
@@ -184,8 +430,7 @@ fn restart_game_event_handler(
                 custom_size: Some(Vec2::new(1.0, PLAY_AREA_HEIGHT)),
                 ..default()
             },
-            transform: Transform::from_xyz(-border_pos * scale, 0.0, 1.0)
-                .with_scale(scale_vec.clone()),
+            transform: Transform::from_xyz(-border_pos * scale, 0.0, 1.0).with_scale(scale_vec),
             ..default()
         },
         Renderable::new(Vec2::new(-border_pos, 0.0), target_resolution)
@@ -202,7 +447,7 @@ fn restart_game_event_handler(
                 ..default()
             },
             transform: Transform::from_xyz(PLAY_AREA_WIDTH / 2.0 * scale, 0.0, 1.0)
-                .with_scale(scale_vec.clone()),
+                .with_scale(scale_vec),
             ..default()
         },
         Renderable::new(Vec2::new(border_pos, 0.0), target_resolution)
@@ -214,83 +459,279 @@ fn restart_game_event_handler(
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
-                custom_size: Some(paddle_size.clone()),
+                custom_size: Some(paddle_size),
                 ..default()
             },
             texture: asset_server.load("sprites/breakout/paddle.png"),
-            transform: Transform::from_xyz(paddle_pos.x * scale, paddle_pos.y * scale, 0.0)
-                .with_scale(scale_vec.clone()),
+            transform: Transform::from_xyz(paddle_pos.x * scale, paddle_pos.y * scale, 0.5)
+                .with_scale(scale_vec),
             ..default()
         },
         Renderable::new(paddle_pos, target_resolution).with_size(paddle_size),
-        Paddle {
-            speed: PADDLE_DEFAULT_SPEED,
+        Paddle::new(PADDLE_DEFAULT_SPEED),
+        BreakoutEntity,
+    ));
+    // Spawn score text
+    let font = asset_server.load(FONT_FILE.to_string());
+    let score_style = TextStyle {
+        font,
+        font_size: 30.0,
+        color: Color::WHITE,
+    };
+    let score_pos_y = PLAY_AREA_HEIGHT / 2.0 - 5.0;
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section("0", score_style.clone())
+                .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(0.0, score_pos_y * scale, 2.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
         },
+        Renderable::new(Vec2::new(0.0, score_pos_y), target_resolution).with_scale(false, false),
+        ScoreText,
         BreakoutEntity,
     ));
-    // Spawn ball
+    // Spawn the rest of the HUD, in smaller text in the top corners
+    let hud_style = TextStyle {
+        font_size: 20.0,
+        ..score_style
+    };
+    let hud_line_height = 15.0;
+    let left_x = -PLAY_AREA_WIDTH / 2.0 + 10.0;
+    let right_x = PLAY_AREA_WIDTH / 2.0 - 10.0;
+    let hud_items = [
+        (left_x, 0, Anchor::TopLeft, TextAlignment::Left),
+        (left_x, 1, Anchor::TopLeft, TextAlignment::Left),
+        (right_x, 0, Anchor::TopRight, TextAlignment::Right),
+        (right_x, 1, Anchor::TopRight, TextAlignment::Right),
+    ];
+    for (index, (pos_x, line, anchor, alignment)) in hud_items.into_iter().enumerate() {
+        let pos = Vec2::new(pos_x, score_pos_y - line as f32 * hud_line_height);
+        let mut entity = commands.spawn((
+            Text2dBundle {
+                text: Text::from_section("", hud_style.clone()).with_alignment(alignment),
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 2.0),
+                text_anchor: anchor,
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_scale(false, false),
+            BreakoutEntity,
+        ));
+        match index {
+            0 => entity.insert(LevelText),
+            1 => entity.insert(LivesText),
+            2 => entity.insert(HighScoreText),
+            _ => entity.insert(PowerUpText),
+        };
+    }
+
+    start_level_events.send(StartLevelEvent {
+        paddle_x: Some(paddle_pos.x),
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_level_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<StartLevelEvent>,
+    asset_server: Res<AssetServer>,
+    levels: Res<LevelCollection>,
+    mut game_state: ResMut<GameState>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    level_entities: Query<Entity, With<LevelEntity>>,
+    paddle_query: Query<&Renderable, With<Paddle>>,
+    mut level_text_query: Query<&mut Text, With<LevelText>>,
+    viewport_size: Res<ViewportSize>,
+    mut stage_clear_events: EventWriter<StageClearEvent>,
+) {
+    let Some(event) = events.iter().last() else { return; };
+
+    for entity in level_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    active_power_ups.clear();
+    let level = levels.level(game_state.level);
+    for mut text in level_text_query.iter_mut() {
+        text.sections[0].value = format!("Level {}: {}", game_state.level, level.name);
+    }
+
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let scale_vec = Vec3::new(scale, scale, 1.0);
+    // Spawn background
+    let background_size = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
-                custom_size: Some(Vec2::new(BALL_SIZE, BALL_SIZE)),
+                color: level.background_color(),
+                custom_size: Some(background_size),
                 ..default()
             },
-            texture: asset_server.load("sprites/breakout/ball.png"),
-            transform: Transform::from_xyz(ball_pos.x * scale, ball_pos.y * scale, 1.0)
-                .with_scale(scale_vec.clone()),
+            transform: Transform::from_xyz(0.0, 0.0, 0.0).with_scale(scale_vec),
             ..default()
         },
-        Renderable::new(ball_pos, target_resolution).with_size(Vec2::new(BALL_SIZE, BALL_SIZE)),
-        Ball::default(),
+        Renderable::new(Vec2::ZERO, target_resolution).with_size(background_size),
+        LevelEntity,
         BreakoutEntity,
     ));
-
-    let row_count = ROW_SPRITES.iter().len() as i32;
-    let block_size = Vec2::new(BLOCK_WIDTH, BLOCK_WIDTH * BLOCK_HEIGHT_RATIO);
-    for (row, sprite_path) in ROW_SPRITES.iter().enumerate() {
-        let pos_y = 200.0 - row as f32 * block_size.y;
-        for i in 0..BLOCKS_PER_ROW {
-            let pos = Vec2::new(
-                -(PLAY_AREA_WIDTH - BLOCK_WIDTH) / 2.0 + i as f32 * block_size.x,
-                pos_y,
-            );
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        custom_size: Some(block_size),
-                        ..default()
-                    },
-                    texture: asset_server.load(sprite_path.to_string()),
-                    transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 0.0)
-                        .with_scale(Vec3::new(scale, scale, 1.0)),
+    // Spawn ball, on the paddle wherever it is
+    let paddle_x = event
+        .paddle_x
+        .or_else(|| paddle_query.get_single().ok().map(|paddle| paddle.pos.x))
+        .unwrap_or_default();
+    serve_ball(
+        &mut commands,
+        &asset_server,
+        level.ball_speed,
+        paddle_x,
+        scale,
+    );
+    // Spawn wall
+    let block_width = PLAY_AREA_WIDTH / level.columns().max(1) as f32;
+    let block_size = Vec2::new(block_width, block_width * BLOCK_HEIGHT_RATIO);
+    game_state.blocks_left = 0;
+    for (row, column, block_type) in level.blocks() {
+        let pos = Vec2::new(
+            -(PLAY_AREA_WIDTH - block_size.x) / 2.0 + column as f32 * block_size.x,
+            WALL_TOP_Y - row as f32 * block_size.y,
+        );
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(block_size),
                     ..default()
                 },
-                Renderable::new(pos, target_resolution).with_size(block_size),
-                Block {
-                    score: (row_count - row as i32) * 1000,
-                },
-                BreakoutEntity,
-            ));
+                texture: asset_server.load(block_type.sprite(block_type.hit_points())),
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 0.5)
+                    .with_scale(scale_vec),
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_size(block_size),
+            Block::new(block_type),
+            LevelEntity,
+            BreakoutEntity,
+        ));
+        if !block_type.is_indestructible() {
+            game_state.blocks_left += 1;
         }
     }
+    // nothing to destroy, so nothing to wait for either
+    if game_state.blocks_left == 0 {
+        stage_clear_events.send_default();
+    }
+}
 
-    // Spawn score text
-    let font = asset_server.load(FONT_FILE.to_string());
-    let score_style = TextStyle {
-        font,
-        font_size: 30.0,
-        color: Color::WHITE,
-    };
-    let score_pos_y = PLAY_AREA_HEIGHT / 2.0 - 5.0;
+#[allow(clippy::too_many_arguments)]
+pub fn power_up_caught_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<PowerUpCaughtEvent>,
+    asset_server: Res<AssetServer>,
+    ball_query: Query<(&Ball, &Renderable)>,
+    mut game_state: ResMut<GameState>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    viewport_size: Res<ViewportSize>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for event in events.iter() {
+        play_sound_events.send(PlaySoundEvent(Sound::BreakoutPowerUp));
+        match event.kind {
+            PowerUpKind::MultiBall => {
+                // splits the ball in play, or launches two from the paddle when none is
+                let split_ball = ball_query
+                    .iter()
+                    .find(|(ball, _)| !ball.is_attached)
+                    .or_else(|| ball_query.iter().next());
+                let Some((ball, renderable)) = split_ball else { continue; };
+                let dir = if ball.is_attached { Vec2::Y } else { ball.dir };
+                for angle in [-MULTI_BALL_ANGLE, MULTI_BALL_ANGLE] {
+                    spawn_ball(
+                        &mut commands,
+                        &asset_server,
+                        Ball {
+                            is_attached: false,
+                            dir: Vec2::from_angle(angle).rotate(dir),
+                            speed: ball.speed,
+                            attached_offset: 0.0,
+                        },
+                        renderable.pos,
+                        scale,
+                    );
+                }
+            }
+            PowerUpKind::ExtraLife => game_state.lives += 1,
+            kind => active_power_ups.activate(kind),
+        }
+    }
+}
+
+/// Spawns a ball on the paddle, waiting to be launched
+fn serve_ball(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    speed: f32,
+    paddle_x: f32,
+    scale: f32,
+) {
+    let paddle_top = -PLAY_AREA_HEIGHT / 2.0 + PADDLE_HEIGHT;
+    spawn_ball(
+        commands,
+        asset_server,
+        Ball { speed, ..default() },
+        Vec2::new(paddle_x, paddle_top + BALL_SIZE / 2.0),
+        scale,
+    );
+}
+
+fn spawn_ball(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    ball: Ball,
+    pos: Vec2,
+    scale: f32,
+) {
+    let size = Vec2::new(BALL_SIZE, BALL_SIZE);
     commands.spawn((
-        Text2dBundle {
-            text: Text::from_section("0", score_style).with_alignment(TextAlignment::Center),
-            transform: Transform::from_xyz(0.0, score_pos_y * scale, 2.0),
-            text_anchor: Anchor::TopCenter,
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(size),
+                ..default()
+            },
+            texture: asset_server.load("sprites/breakout/ball.png"),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0)
+                .with_scale(Vec3::new(scale, scale, 1.0)),
             ..default()
         },
-        Renderable::new(Vec2::new(0.0, score_pos_y), target_resolution).with_scale(false, false),
-        ScoreText,
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_size(size),
+        ball,
+        LevelEntity,
+        BreakoutEntity,
+    ));
+}
+
+fn spawn_power_up(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    kind: PowerUpKind,
+    pos: Vec2,
+    scale: f32,
+) {
+    // the sprites are three times as wide as they are high
+    let size = Vec2::new(POWER_UP_WIDTH, POWER_UP_WIDTH / 3.0);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(size),
+                ..default()
+            },
+            texture: asset_server.load(kind.sprite()),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0)
+                .with_scale(Vec3::new(scale, scale, 1.0)),
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_size(size),
+        PowerUp { kind },
+        LevelEntity,
         BreakoutEntity,
     ));
 }
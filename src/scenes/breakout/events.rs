@@ -1,28 +1,54 @@
 use bevy::app::{App, Plugin};
 use bevy::prelude::Entity;
 
-use crate::scenes::breakout::constants::SoundType;
+use crate::scenes::breakout::power_up::PowerUpKind;
 
 pub struct EventsPlugin;
 
 impl Plugin for EventsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<BlockDestroyedEvent>()
+            .add_event::<BallLostEvent>()
+            .add_event::<StageClearEvent>()
             .add_event::<GameOverEvent>()
-            .add_event::<PlaySoundEvent>()
-            .add_event::<RestartGameEvent>();
+            .add_event::<RestartGameEvent>()
+            .add_event::<StartLevelEvent>()
+            .add_event::<PowerUpCaughtEvent>();
     }
 }
 
-pub struct BlockDestroyedEvent {
-    pub entity: Entity,
-    pub block_value: i32,
+/// A block was hit by a ball or caught in an explosion
+pub enum BlockDestroyedEvent {
+    /// Lost a hit point, which destroys it too if it was hit again since it was sent
+    Damaged {
+        entity: Entity,
+    },
+    Destroyed {
+        entity: Entity,
+    },
 }
 
-pub struct PlaySoundEvent(pub SoundType);
+/// The last ball in play fell past the paddle
+#[derive(Default)]
+pub struct BallLostEvent;
+
+/// Every block needed to clear the wall was destroyed
+#[derive(Default)]
+pub struct StageClearEvent;
 
 #[derive(Default)]
 pub struct GameOverEvent;
 
 #[derive(Default)]
 pub struct RestartGameEvent;
+
+/// Builds the wall of `GameState::level`, with a new ball on the paddle
+#[derive(Default)]
+pub struct StartLevelEvent {
+    /// Where the paddle was only just spawned, as it can't be queried before the next frame
+    pub paddle_x: Option<f32>,
+}
+
+pub struct PowerUpCaughtEvent {
+    pub kind: PowerUpKind,
+}
@@ -1,25 +1,35 @@
 use bevy::prelude::*;
 use rand::{thread_rng, Rng};
 
-use crate::common::{AppState, Renderable};
-use crate::scenes::breakout::components::{Ball, Paddle};
+use crate::actions::{Action, Actions};
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::scenes::breakout::components::{Ball, BreakoutEntity, Laser, LevelEntity, Paddle};
+use crate::scenes::breakout::constants::{
+    LASER_HEIGHT, LASER_WIDTH, PLAY_AREA_HEIGHT, PLAY_AREA_WIDTH,
+};
 use crate::scenes::breakout::events::RestartGameEvent;
-use crate::scenes::breakout::resources::GameState;
+use crate::scenes::breakout::power_up::PowerUpKind;
+use crate::scenes::breakout::resources::{ActivePowerUps, GameState};
 
 pub struct InputPlugin;
 
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(paddle_keyboard_input.in_set(OnUpdate(AppState::Breakout)))
-            .add_system(ball_keyboard_input.in_set(OnUpdate(AppState::Breakout)))
-            .add_system(end_game_keyboard_input.in_set(OnUpdate(AppState::Breakout)))
-            .add_system(back_to_menu_keyboard_input.in_set(OnUpdate(AppState::Breakout)));
+        app.add_system(paddle_input.in_set(OnUpdate(AppState::Breakout)))
+            .add_system(ball_input.in_set(OnUpdate(AppState::Breakout)))
+            .add_system(
+                laser_input
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .before(ball_input),
+            )
+            .add_system(end_game_input.in_set(OnUpdate(AppState::Breakout)));
     }
 }
 
-pub fn paddle_keyboard_input(
+pub fn paddle_input(
     time: Res<Time>,
-    keys: Res<Input<KeyCode>>,
+    actions: Res<Actions>,
     game_state: Res<GameState>,
     mut query: Query<(&mut Renderable, &Paddle)>,
 ) {
@@ -27,53 +37,104 @@ pub fn paddle_keyboard_input(
         return;
     }
 
+    // follows how far the stick is pushed
+    let dir = actions.axis(Action::MoveLeft, Action::MoveRight);
+    if dir == 0.0 {
+        return;
+    }
     for (mut renderable, paddle) in query.iter_mut() {
-        if keys.any_pressed([KeyCode::A, KeyCode::Left]) {
-            let mut new_pos = renderable.pos.x - time.delta_seconds() * paddle.speed;
-            if new_pos < renderable.min_x() {
-                new_pos = renderable.min_x();
-            }
-            renderable.pos.x = new_pos;
-        } else if keys.any_pressed([KeyCode::D, KeyCode::Right]) {
-            let mut new_pos = renderable.pos.x + time.delta_seconds() * paddle.speed;
-            if new_pos > renderable.max_x() {
-                new_pos = renderable.max_x();
-            }
-            renderable.pos.x = new_pos;
-        }
+        let new_pos = renderable.pos.x + dir * time.delta_seconds() * paddle.speed;
+        renderable.pos.x = new_pos.clamp(renderable.min_x(), renderable.max_x());
     }
 }
 
-pub fn ball_keyboard_input(keys: Res<Input<KeyCode>>, mut query: Query<&mut Ball>) {
-    if keys.just_pressed(KeyCode::Space) {
-        for mut ball in query.iter_mut() {
-            if ball.is_attached {
-                ball.is_attached = false;
-                ball.dir = Vec2::new(thread_rng().gen_range(-1.0..1.0), 1.0).normalize()
-            }
+pub fn ball_input(
+    actions: Res<Actions>,
+    paddle_query: Query<&Renderable, With<Paddle>>,
+    mut query: Query<&mut Ball>,
+) {
+    if !actions.just_pressed(Action::Fire) {
+        return;
+    }
+    let Ok(paddle) = paddle_query.get_single() else { return; };
+    for mut ball in query.iter_mut() {
+        if ball.is_attached {
+            // a served ball leaves at random, a caught one as if it bounced where it lies
+            let dir_x = if ball.attached_offset == 0.0 {
+                thread_rng().gen_range(-1.0..1.0)
+            } else {
+                (ball.attached_offset / (paddle.size.x / 2.0)).clamp(-1.0, 1.0)
+            };
+            ball.is_attached = false;
+            ball.attached_offset = 0.0;
+            ball.dir = Vec2::new(dir_x, 1.0).normalize();
         }
     }
 }
 
-pub fn end_game_keyboard_input(
-    keys: Res<Input<KeyCode>>,
+/// Shoots a laser from each side of the paddle while fire is held, once no ball is left on it
+#[allow(clippy::too_many_arguments)]
+pub fn laser_input(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: Res<Actions>,
     game_state: Res<GameState>,
-    mut restart_events: EventWriter<RestartGameEvent>,
+    active_power_ups: Res<ActivePowerUps>,
+    viewport_size: Res<ViewportSize>,
+    mut paddle_query: Query<(&mut Paddle, &Renderable)>,
+    ball_query: Query<&Ball>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
 ) {
-    if !game_state.has_game_ended {
+    let Ok((mut paddle, paddle_renderable)) = paddle_query.get_single_mut() else { return; };
+    paddle.laser_cooldown.tick(time.delta());
+    if game_state.has_game_ended
+        || !active_power_ups.is_active(PowerUpKind::Laser)
+        || !actions.pressed(Action::Fire)
+        || !paddle.laser_cooldown.finished()
+        || ball_query.iter().any(|ball| ball.is_attached)
+    {
         return;
     }
+    paddle.laser_cooldown.reset();
+    play_sound_events.send(PlaySoundEvent(Sound::BreakoutLaser));
 
-    if keys.just_pressed(KeyCode::Space) {
-        restart_events.send_default();
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let laser_size = Vec2::new(LASER_WIDTH, LASER_HEIGHT);
+    for side in [-1.0, 1.0] {
+        let pos = Vec2::new(
+            paddle_renderable.pos.x + side * (paddle_renderable.size.x - laser_size.x) / 2.0,
+            paddle_renderable.top() + laser_size.y / 2.0,
+        );
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(1.0, 0.3, 0.3),
+                    custom_size: Some(laser_size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0)
+                    .with_scale(Vec3::new(scale, scale, 1.0)),
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_size(laser_size),
+            Laser,
+            LevelEntity,
+            BreakoutEntity,
+        ));
     }
 }
 
-pub fn back_to_menu_keyboard_input(
-    keys: Res<Input<KeyCode>>,
-    mut next_state: ResMut<NextState<AppState>>,
+pub fn end_game_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut restart_events: EventWriter<RestartGameEvent>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) {
-        next_state.set(AppState::Menu);
+    if !game_state.has_game_ended {
+        return;
+    }
+
+    if actions.just_pressed(Action::Confirm) {
+        restart_events.send_default();
     }
 }
@@ -0,0 +1,232 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use bevy::prelude::{warn, Color};
+use serde::Deserialize;
+
+use crate::scenes::breakout::constants::{EMPTY_CELL, LEVELS_FOLDER, LEVEL_FILE_EXTENSION};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlockType {
+    Maroon,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Teal,
+    Blue,
+    Purple,
+    /// Takes several hits, cracking a bit more with each
+    Hard,
+    /// Never destroyed, and so not needed to clear the wall
+    Steel,
+    /// Takes its neighbors down with it
+    Explosive,
+    /// Takes several hits, and heals a hit point at a time when left alone
+    Regenerating,
+}
+
+impl BlockType {
+    /// Letter of the block in the level files, `None` for an empty cell or an unknown letter
+    pub fn from_char(c: char) -> Option<BlockType> {
+        match c {
+            'M' => Some(BlockType::Maroon),
+            'R' => Some(BlockType::Red),
+            'O' => Some(BlockType::Orange),
+            'Y' => Some(BlockType::Yellow),
+            'G' => Some(BlockType::Green),
+            'T' => Some(BlockType::Teal),
+            'B' => Some(BlockType::Blue),
+            'P' => Some(BlockType::Purple),
+            'H' => Some(BlockType::Hard),
+            'S' => Some(BlockType::Steel),
+            'X' => Some(BlockType::Explosive),
+            '+' => Some(BlockType::Regenerating),
+            _ => None,
+        }
+    }
+
+    pub fn hit_points(self) -> u32 {
+        match self {
+            BlockType::Hard | BlockType::Regenerating => 3,
+            _ => 1,
+        }
+    }
+
+    pub fn is_indestructible(self) -> bool {
+        self == BlockType::Steel
+    }
+
+    /// Sprite of the block with the given hit points left, showing the damage taken
+    pub fn sprite(self, hit_points: u32) -> &'static str {
+        match self {
+            BlockType::Maroon => "sprites/breakout/block_maroon.png",
+            BlockType::Red => "sprites/breakout/block_red.png",
+            BlockType::Orange => "sprites/breakout/block_orange.png",
+            BlockType::Yellow => "sprites/breakout/block_yellow.png",
+            BlockType::Green => "sprites/breakout/block_green.png",
+            BlockType::Teal => "sprites/breakout/block_teal.png",
+            BlockType::Blue => "sprites/breakout/block_blue.png",
+            BlockType::Purple => "sprites/breakout/block_purple.png",
+            BlockType::Hard => match hit_points {
+                0 | 1 => "sprites/breakout/block_hard_cracked_2.png",
+                2 => "sprites/breakout/block_hard_cracked_1.png",
+                _ => "sprites/breakout/block_hard.png",
+            },
+            BlockType::Steel => "sprites/breakout/block_steel.png",
+            BlockType::Explosive => "sprites/breakout/block_explosive.png",
+            BlockType::Regenerating => match hit_points {
+                0 | 1 => "sprites/breakout/block_regenerating_cracked_2.png",
+                2 => "sprites/breakout/block_regenerating_cracked_1.png",
+                _ => "sprites/breakout/block_regenerating.png",
+            },
+        }
+    }
+
+    /// The warmer the color, the more it is worth, as the classic wall has them on top. Blocks
+    /// taking more hits are worth more.
+    pub fn score(self) -> i32 {
+        match self {
+            BlockType::Maroon => 8000,
+            BlockType::Red => 7000,
+            BlockType::Orange => 6000,
+            BlockType::Yellow => 5000,
+            BlockType::Green => 4000,
+            BlockType::Teal => 3000,
+            BlockType::Blue => 2000,
+            BlockType::Purple => 1000,
+            BlockType::Hard => 10000,
+            BlockType::Steel => 0,
+            BlockType::Explosive => 2000,
+            BlockType::Regenerating => 12000,
+        }
+    }
+}
+
+/// A level as read from a RON file of the levels folder
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Level {
+    pub name: String,
+    pub ball_speed: f32,
+    /// Color drawn behind the wall, as red, green and blue
+    pub background: (f32, f32, f32),
+    /// Top row first, with a letter per block as in `BlockType::from_char`
+    pub rows: Vec<String>,
+}
+
+impl Level {
+    /// Played when the levels folder has no readable level
+    fn fallback() -> Self {
+        Level {
+            name: "Classic".to_string(),
+            ball_speed: 200.0,
+            background: (0.0, 0.0, 0.0),
+            rows: ["M", "R", "O", "Y", "G", "T", "B", "P"]
+                .iter()
+                .map(|letter| letter.repeat(10))
+                .collect(),
+        }
+    }
+
+    /// Blocks wide the wall is, from its longest row
+    pub fn columns(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Row, column and type of every block of the wall
+    pub fn blocks(&self) -> impl Iterator<Item = (usize, usize, BlockType)> + '_ {
+        self.rows.iter().enumerate().flat_map(|(row, cells)| {
+            cells.chars().enumerate().filter_map(move |(column, cell)| {
+                BlockType::from_char(cell).map(|block_type| (row, column, block_type))
+            })
+        })
+    }
+
+    /// Letters of the rows that are neither a block nor the `.` of an empty cell, once each
+    pub fn unknown_letters(&self) -> Vec<char> {
+        let mut letters: Vec<char> = self
+            .rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .filter(|cell| *cell != EMPTY_CELL && BlockType::from_char(*cell).is_none())
+            .collect();
+        letters.sort_unstable();
+        letters.dedup();
+        letters
+    }
+
+    /// Whether the wall can be cleared, steel blocks not being needed to
+    pub fn has_destructible_blocks(&self) -> bool {
+        self.blocks()
+            .any(|(_, _, block_type)| !block_type.is_indestructible())
+    }
+
+    pub fn background_color(&self) -> Color {
+        let (red, green, blue) = self.background;
+        Color::rgb(red, green, blue)
+    }
+}
+
+/// Every file of the levels folder with the time it was last changed, in file name order
+pub fn level_files() -> Vec<(PathBuf, SystemTime)> {
+    let Ok(entries) = fs::read_dir(format!("assets/{}", LEVELS_FOLDER)) else {
+        return Vec::new();
+    };
+    let mut files: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == LEVEL_FILE_EXTENSION)
+        })
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|metadata| metadata.modified());
+            modified.ok().map(|modified| (path, modified))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Reads the level files, skipping the unreadable ones so that a typo doesn't end the game
+pub fn load_levels(files: &[(PathBuf, SystemTime)]) -> Vec<Level> {
+    let levels: Vec<Level> = files
+        .iter()
+        .filter_map(|(path, _)| {
+            let content = match fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(err) => {
+                    warn!("Ignoring unreadable level file {:?}: {}", path, err);
+                    return None;
+                }
+            };
+            let level = match ron::from_str::<Level>(&content) {
+                Ok(level) => level,
+                Err(err) => {
+                    warn!("Ignoring unreadable level file {:?}: {}", path, err);
+                    return None;
+                }
+            };
+            for letter in level.unknown_letters() {
+                warn!(
+                    "Leaving the cells with unknown letter {:?} of level file {:?} empty",
+                    letter, path
+                );
+            }
+            if !level.has_destructible_blocks() {
+                warn!("Ignoring level file {:?} without blocks to destroy", path);
+                return None;
+            }
+            Some(level)
+        })
+        .collect();
+    if levels.is_empty() {
+        warn!("No Breakout level found in assets/{}", LEVELS_FOLDER);
+        return vec![Level::fallback()];
+    }
+    levels
+}
@@ -2,14 +2,25 @@ use bevy::math::Vec2;
 use bevy::prelude::*;
 use bevy::time::Time;
 
+use crate::audio::{PlaySoundEvent, Sound};
 use crate::common::{AppState, Renderable};
-use crate::scenes::breakout::components::{Ball, Block, Paddle, ScoreText};
+use crate::scenes::breakout::components::{
+    Ball, Block, HighScoreText, Laser, LivesText, Paddle, PowerUp, PowerUpText, ScoreText,
+};
 use crate::scenes::breakout::constants::{
-    SoundType, BALL_DEFAULT_SPEED, BALL_SPEED_INCREASE_SCORE, BALL_SPEED_INCREASE_VALUE,
+    BALL_SPEED_INCREASE_SCORE, BALL_SPEED_INCREASE_VALUE, LASER_SPEED, PADDLE_HEIGHT,
+    PADDLE_WIDTH_RATIO, POWER_UP_FALL_SPEED,
+};
+use crate::scenes::breakout::event_handlers::{
+    ball_lost_event_handler, block_destroyed_event_handler, power_up_caught_event_handler,
+    start_level_event_handler,
+};
+use crate::scenes::breakout::events::{
+    BallLostEvent, BlockDestroyedEvent, PowerUpCaughtEvent, StartLevelEvent,
 };
-use crate::scenes::breakout::event_handlers::block_destroyed_event_handler;
-use crate::scenes::breakout::events::{BlockDestroyedEvent, GameOverEvent, PlaySoundEvent};
-use crate::scenes::breakout::resources::GameState;
+use crate::scenes::breakout::level::BlockType;
+use crate::scenes::breakout::power_up::PowerUpKind;
+use crate::scenes::breakout::resources::{ActivePowerUps, GameState, LevelCollection};
 
 pub struct LogicPlugin;
 
@@ -18,9 +29,60 @@ impl Plugin for LogicPlugin {
         app.add_system(move_ball_with_paddle.in_set(OnUpdate(AppState::Breakout)))
             .add_system(move_ball.in_set(OnUpdate(AppState::Breakout)))
             .add_system(
-                score_change
+                hud_update
                     .in_set(OnUpdate(AppState::Breakout))
                     .after(block_destroyed_event_handler),
+            )
+            .add_system(
+                stage_clear_countdown
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .before(start_level_event_handler),
+            )
+            .add_system(
+                regenerate_blocks
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .after(block_destroyed_event_handler),
+            )
+            .add_system(
+                move_power_ups
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .before(power_up_caught_event_handler),
+            )
+            .add_system(
+                move_lasers
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .before(block_destroyed_event_handler),
+            )
+            // the balls, capsules and lasers despawned while moving are gone before the stage
+            // clear or the lost ball sweeps the play area, so none of them is despawned twice
+            .add_system(
+                apply_system_buffers
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .after(move_ball)
+                    .after(move_power_ups)
+                    .after(move_lasers)
+                    .before(block_destroyed_event_handler)
+                    .before(ball_lost_event_handler),
+            )
+            .add_system(
+                tick_power_ups
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .after(power_up_caught_event_handler),
+            )
+            .add_system(
+                resize_paddle
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .after(tick_power_ups),
+            )
+            .add_system(
+                power_up_text
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .after(tick_power_ups),
+            )
+            .add_system(
+                reload_levels
+                    .in_set(OnUpdate(AppState::Breakout))
+                    .before(start_level_event_handler),
             );
     }
 }
@@ -30,50 +92,59 @@ pub fn move_ball_with_paddle(
     mut ball_query: Query<(&Ball, &mut Renderable), Without<Paddle>>,
 ) {
     let Ok(paddle_renderable) = paddle_query.get_single() else { return; };
+    // each ball keeps its place on the paddle, even once the paddle got narrower
+    let half_width = paddle_renderable.size.x / 2.0;
     for (ball, mut renderable) in ball_query.iter_mut() {
         if ball.is_attached {
-            renderable.pos.x = paddle_renderable.pos.x;
+            renderable.pos.x =
+                paddle_renderable.pos.x + ball.attached_offset.clamp(-half_width, half_width);
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn move_ball(
+    mut commands: Commands,
     time: Res<Time>,
+    active_power_ups: Res<ActivePowerUps>,
     mut block_destroyed_events: EventWriter<BlockDestroyedEvent>,
     mut play_sound_events: EventWriter<PlaySoundEvent>,
-    mut game_over_events: EventWriter<GameOverEvent>,
-    mut ball_query: Query<(&mut Ball, &mut Renderable), (Without<Paddle>, Without<Block>)>,
+    mut ball_lost_events: EventWriter<BallLostEvent>,
+    mut ball_query: Query<(Entity, &mut Ball, &mut Renderable), (Without<Paddle>, Without<Block>)>,
     paddle_query: Query<&Renderable, (With<Paddle>, Without<Block>)>,
     blocks_query: Query<(Entity, &Block, &Renderable)>,
 ) {
-    for (mut ball, mut ball_renderable) in ball_query.iter_mut() {
-        if blocks_query.is_empty() {
-            // cleared all blocks: win
-            game_over_events.send_default();
-            return;
-        }
+    let mut balls_left = ball_query.iter().count();
+    for (ball_entity, mut ball, mut ball_renderable) in ball_query.iter_mut() {
         if !ball.is_attached {
-            let mut sound: Option<SoundType> = None;
-            let mut new_pos = ball_renderable.pos + ball.dir * ball.speed * time.delta_seconds();
+            let mut sound: Option<Sound> = None;
+            let speed = ball.speed * active_power_ups.ball_speed_factor();
+            let mut new_pos = ball_renderable.pos + ball.dir * speed * time.delta_seconds();
             if new_pos.x < ball_renderable.min_x() {
                 // Collision with left wall
                 new_pos.x = ball_renderable.min_x();
                 ball.dir.x = -ball.dir.x;
-                sound = Some(SoundType::BallHitWall);
+                sound = Some(Sound::BreakoutWallHit);
             } else if new_pos.x > ball_renderable.max_x() {
                 // Collision with right wall
                 new_pos.x = ball_renderable.max_x();
                 ball.dir.x = -ball.dir.x;
-                sound = Some(SoundType::BallHitWall);
+                sound = Some(Sound::BreakoutWallHit);
             }
             if new_pos.y > ball_renderable.max_y() {
                 // Collision with top
                 new_pos.y = ball_renderable.max_y();
                 ball.dir.y = -ball.dir.y;
-                sound = Some(SoundType::BallHitWall);
+                sound = Some(Sound::BreakoutWallHit);
             } else if new_pos.y < ball_renderable.min_y() {
-                // Ball reached bottom
-                game_over_events.send_default();
+                // Ball reached bottom, which only matters once no other ball is left
+                if balls_left > 1 {
+                    commands.entity(ball_entity).despawn();
+                    balls_left -= 1;
+                } else {
+                    ball_lost_events.send_default();
+                }
+                continue;
             }
 
             // Check for collision with paddle
@@ -85,7 +156,11 @@ pub fn move_ball(
                 new_pos.y = paddle.top() + ball_renderable.size.y / 2.0;
                 ball.dir =
                     Vec2::new((new_pos.x - paddle.pos.x) / (paddle.size.x / 2.0), 1.0).normalize();
-                sound = Some(SoundType::BallHitPaddle);
+                if active_power_ups.is_active(PowerUpKind::Sticky) {
+                    ball.is_attached = true;
+                    ball.attached_offset = new_pos.x - paddle.pos.x;
+                }
+                sound = Some(Sound::BreakoutPaddleHit);
             }
 
             // check for collision with any block
@@ -130,35 +205,209 @@ pub fn move_ball(
                 }
 
                 if block_hit {
-                    block_destroyed_events.send(BlockDestroyedEvent {
-                        entity,
-                        block_value: block.score,
-                    });
-                    sound = Some(SoundType::BallHitWall);
+                    if block.block_type.is_indestructible() {
+                        // steel only bounces the ball back
+                    } else if block.hit_points > 1 {
+                        block_destroyed_events.send(BlockDestroyedEvent::Damaged { entity });
+                    } else {
+                        block_destroyed_events.send(BlockDestroyedEvent::Destroyed { entity });
+                        // each ball speeds up with the points it scores itself
+                        ball.speed += block.block_type.score() as f32
+                            / BALL_SPEED_INCREASE_SCORE as f32
+                            * BALL_SPEED_INCREASE_VALUE;
+                    }
+                    sound = Some(Sound::BreakoutWallHit);
                 }
             }
 
             ball_renderable.pos = new_pos;
-            if let Some(sound_type) = sound {
-                play_sound_events.send(PlaySoundEvent(sound_type));
+            if let Some(sound) = sound {
+                play_sound_events.send(PlaySoundEvent(sound));
             }
         }
     }
 }
 
-pub fn score_change(
+pub fn hud_update(
     game_state: Res<GameState>,
-    mut ball_query: Query<&mut Ball>,
-    mut score_query: Query<&mut Text, With<ScoreText>>,
+    mut score_query: Query<&mut Text, (With<ScoreText>, Without<HighScoreText>)>,
+    mut high_score_query: Query<&mut Text, (With<HighScoreText>, Without<LivesText>)>,
+    mut lives_query: Query<&mut Text, (With<LivesText>, Without<ScoreText>)>,
 ) {
     if !game_state.is_changed() {
         return;
     }
-    for mut ball in ball_query.iter_mut() {
-        ball.speed = BALL_DEFAULT_SPEED
-            + (game_state.score / BALL_SPEED_INCREASE_SCORE) as f32 * BALL_SPEED_INCREASE_VALUE;
-    }
     for mut score_label in score_query.iter_mut() {
         score_label.sections[0].value = format!("{}", game_state.score);
     }
+    for mut text in high_score_query.iter_mut() {
+        text.sections[0].value = format!(
+            "High score: {}",
+            game_state.high_score.max(game_state.score)
+        );
+    }
+    for mut text in lives_query.iter_mut() {
+        text.sections[0].value = format!("Lives: {}", game_state.lives);
+    }
+}
+
+/// Starts the next level once the stage clear screen was shown long enough
+pub fn stage_clear_countdown(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut start_level_events: EventWriter<StartLevelEvent>,
+) {
+    if !game_state.is_stage_clear {
+        return;
+    }
+    game_state.stage_clear_timer.tick(time.delta());
+    if game_state.stage_clear_timer.just_finished() {
+        game_state.is_stage_clear = false;
+        start_level_events.send_default();
+    }
+}
+
+/// Power-ups fall until the paddle catches them or they leave the screen
+pub fn move_power_ups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut power_up_query: Query<(Entity, &PowerUp, &mut Renderable), Without<Paddle>>,
+    paddle_query: Query<&Renderable, With<Paddle>>,
+    mut power_up_caught_events: EventWriter<PowerUpCaughtEvent>,
+) {
+    let Ok(paddle) = paddle_query.get_single() else { return; };
+    for (entity, power_up, mut renderable) in power_up_query.iter_mut() {
+        renderable.pos.y -= POWER_UP_FALL_SPEED * time.delta_seconds();
+        if renderable.bottom() <= paddle.top()
+            && renderable.top() >= paddle.bottom()
+            && renderable.right() >= paddle.left()
+            && renderable.left() <= paddle.right()
+        {
+            power_up_caught_events.send(PowerUpCaughtEvent {
+                kind: power_up.kind,
+            });
+            commands.entity(entity).despawn();
+        } else if renderable.top() < renderable.min_y() - renderable.size.y {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Lasers fly up until they hit a block or the top of the screen
+pub fn move_lasers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut laser_query: Query<(Entity, &mut Renderable), With<Laser>>,
+    blocks_query: Query<(Entity, &Block, &Renderable), Without<Laser>>,
+    mut block_destroyed_events: EventWriter<BlockDestroyedEvent>,
+) {
+    for (laser_entity, mut laser) in laser_query.iter_mut() {
+        laser.pos.y += LASER_SPEED * time.delta_seconds();
+        let hit_block = blocks_query.iter().find(|(_, _, block)| {
+            laser.pos.x >= block.left()
+                && laser.pos.x <= block.right()
+                && laser.top() >= block.bottom()
+                && laser.bottom() <= block.top()
+        });
+        if let Some((entity, block, _)) = hit_block {
+            // steel stops the laser as well, without a scratch
+            if !block.block_type.is_indestructible() {
+                block_destroyed_events.send(if block.hit_points > 1 {
+                    BlockDestroyedEvent::Damaged { entity }
+                } else {
+                    BlockDestroyedEvent::Destroyed { entity }
+                });
+            }
+            commands.entity(laser_entity).despawn();
+        } else if laser.bottom() > laser.max_y() + laser.size.y {
+            commands.entity(laser_entity).despawn();
+        }
+    }
+}
+
+pub fn tick_power_ups(time: Res<Time>, mut active_power_ups: ResMut<ActivePowerUps>) {
+    // left untouched when nothing is active, so that the HUD isn't redone every frame
+    if active_power_ups.is_empty() {
+        return;
+    }
+    active_power_ups.tick(time.delta());
+}
+
+/// Keeps the paddle as wide as the active power-ups make it, back to normal when they wear off
+pub fn resize_paddle(
+    active_power_ups: Res<ActivePowerUps>,
+    mut paddle_query: Query<(&mut Renderable, &mut Sprite), With<Paddle>>,
+) {
+    let width = PADDLE_WIDTH_RATIO * PADDLE_HEIGHT * active_power_ups.paddle_width_factor();
+    for (mut renderable, mut sprite) in paddle_query.iter_mut() {
+        if renderable.size.x == width {
+            continue;
+        }
+        renderable.size.x = width;
+        let max_x = renderable.max_x();
+        renderable.pos.x = renderable.pos.x.clamp(-max_x, max_x);
+        sprite.custom_size = Some(renderable.size);
+    }
+}
+
+/// Lists the active power-ups with the seconds they have left
+pub fn power_up_text(
+    active_power_ups: Res<ActivePowerUps>,
+    mut text_query: Query<&mut Text, With<PowerUpText>>,
+) {
+    if !active_power_ups.is_changed() {
+        return;
+    }
+    let value = active_power_ups
+        .remaining()
+        .map(|(kind, seconds)| format!("{} {}s", kind.label(), seconds.ceil()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    // the seconds left only change once a second
+    for mut text in text_query.iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
+        }
+    }
+}
+
+/// Heals the damaged regenerating blocks a hit point at a time, for as long as they aren't hit
+pub fn regenerate_blocks(
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut blocks_query: Query<(&mut Block, &mut Handle<Image>)>,
+) {
+    for (mut block, mut texture) in blocks_query.iter_mut() {
+        if block.block_type != BlockType::Regenerating
+            || block.hit_points >= block.block_type.hit_points()
+        {
+            continue;
+        }
+        block.regenerate_timer.tick(time.delta());
+        if block.regenerate_timer.just_finished() {
+            block.hit_points += 1;
+            *texture = asset_server.load(block.block_type.sprite(block.hit_points));
+        }
+    }
+}
+
+/// Lets the level files be edited while playing, the wall being built again from them
+pub fn reload_levels(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut levels: ResMut<LevelCollection>,
+    mut start_level_events: EventWriter<StartLevelEvent>,
+) {
+    if game_state.has_game_ended || game_state.is_stage_clear {
+        return;
+    }
+    levels.reload_timer.tick(time.delta());
+    if !levels.reload_timer.just_finished() {
+        return;
+    }
+    // editing another level, or saving the file unchanged, leaves the wall being played alone
+    let current_level = levels.level(game_state.level).clone();
+    if levels.reload_if_changed() && *levels.level(game_state.level) != current_level {
+        start_level_events.send_default();
+    }
 }
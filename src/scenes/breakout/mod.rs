@@ -6,5 +6,7 @@ mod constants;
 mod event_handlers;
 mod events;
 mod input;
+mod level;
 mod logic;
+mod power_up;
 mod resources;
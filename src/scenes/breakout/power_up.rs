@@ -0,0 +1,93 @@
+use rand::{thread_rng, Rng};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PowerUpKind {
+    WidePaddle,
+    /// A setback, so that not every power-up is worth catching
+    NarrowPaddle,
+    SlowBall,
+    /// Splits the ball in three
+    MultiBall,
+    /// Holds the ball on the paddle until fired again
+    Sticky,
+    /// Lets the paddle shoot at the wall
+    Laser,
+    ExtraLife,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 7] = [
+        PowerUpKind::WidePaddle,
+        PowerUpKind::NarrowPaddle,
+        PowerUpKind::SlowBall,
+        PowerUpKind::MultiBall,
+        PowerUpKind::Sticky,
+        PowerUpKind::Laser,
+        PowerUpKind::ExtraLife,
+    ];
+
+    /// Picks the kind of a dropped power-up, extra lives being the rarest
+    pub fn random() -> PowerUpKind {
+        let total: u32 = PowerUpKind::ALL.iter().map(|kind| kind.drop_weight()).sum();
+        let mut pick = thread_rng().gen_range(0..total);
+        for kind in PowerUpKind::ALL {
+            if pick < kind.drop_weight() {
+                return kind;
+            }
+            pick -= kind.drop_weight();
+        }
+        PowerUpKind::WidePaddle
+    }
+
+    fn drop_weight(self) -> u32 {
+        match self {
+            PowerUpKind::ExtraLife => 1,
+            PowerUpKind::MultiBall | PowerUpKind::Laser => 2,
+            _ => 3,
+        }
+    }
+
+    /// Seconds the effect lasts, `None` for the ones taking effect once when caught
+    pub fn duration(self) -> Option<f32> {
+        match self {
+            PowerUpKind::WidePaddle | PowerUpKind::NarrowPaddle => Some(15.0),
+            PowerUpKind::SlowBall => Some(10.0),
+            PowerUpKind::Sticky => Some(20.0),
+            PowerUpKind::Laser => Some(10.0),
+            PowerUpKind::MultiBall | PowerUpKind::ExtraLife => None,
+        }
+    }
+
+    /// Kind whose effect is undone by this one, as both can't be active at once
+    pub fn opposite(self) -> Option<PowerUpKind> {
+        match self {
+            PowerUpKind::WidePaddle => Some(PowerUpKind::NarrowPaddle),
+            PowerUpKind::NarrowPaddle => Some(PowerUpKind::WidePaddle),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerUpKind::WidePaddle => "Wide",
+            PowerUpKind::NarrowPaddle => "Narrow",
+            PowerUpKind::SlowBall => "Slow",
+            PowerUpKind::MultiBall => "Multi-ball",
+            PowerUpKind::Sticky => "Catch",
+            PowerUpKind::Laser => "Laser",
+            PowerUpKind::ExtraLife => "Extra life",
+        }
+    }
+
+    pub fn sprite(self) -> &'static str {
+        match self {
+            PowerUpKind::WidePaddle => "sprites/breakout/power_up_wide.png",
+            PowerUpKind::NarrowPaddle => "sprites/breakout/power_up_narrow.png",
+            PowerUpKind::SlowBall => "sprites/breakout/power_up_slow.png",
+            PowerUpKind::MultiBall => "sprites/breakout/power_up_multi_ball.png",
+            PowerUpKind::Sticky => "sprites/breakout/power_up_catch.png",
+            PowerUpKind::Laser => "sprites/breakout/power_up_laser.png",
+            PowerUpKind::ExtraLife => "sprites/breakout/power_up_extra_life.png",
+        }
+    }
+}
@@ -1,5 +1,15 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
 use bevy::app::{App, Plugin};
-use bevy::prelude::Resource;
+use bevy::prelude::{Resource, Timer, TimerMode};
+
+use crate::scenes::breakout::constants::{
+    LEVEL_RELOAD_INTERVAL, NARROW_PADDLE_FACTOR, SLOW_BALL_FACTOR, STAGE_CLEAR_DURATION,
+    STARTING_LIVES, WIDE_PADDLE_FACTOR,
+};
+use crate::scenes::breakout::level::{level_files, load_levels, Level};
+use crate::scenes::breakout::power_up::PowerUpKind;
 
 pub struct ResourcesPlugin;
 
@@ -7,20 +17,145 @@ impl Plugin for ResourcesPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(GameState {
             score: 0,
+            blocks_destroyed: 0,
             has_game_ended: false,
-        });
+            level: 1,
+            blocks_left: 0,
+            lives: STARTING_LIVES,
+            high_score: 0,
+            is_stage_clear: false,
+            stage_clear_timer: Timer::from_seconds(STAGE_CLEAR_DURATION, TimerMode::Once),
+        })
+        .insert_resource(LevelCollection::default())
+        .init_resource::<ActivePowerUps>();
     }
 }
 
 #[derive(Resource)]
 pub struct GameState {
     pub score: i32,
+    pub blocks_destroyed: u32,
     pub has_game_ended: bool,
+    /// Starting from 1, and going on past the last level by playing them all again
+    pub level: u32,
+    /// Blocks still to be destroyed to clear the wall
+    pub blocks_left: u32,
+    /// Balls that can still be lost before the game ends, the one in play included
+    pub lives: u32,
+    /// Best score of the high score table when the game started
+    pub high_score: i32,
+    /// Whether the wall was cleared, the next level starting once `stage_clear_timer` finishes
+    pub is_stage_clear: bool,
+    pub stage_clear_timer: Timer,
 }
 
 impl GameState {
     pub fn reset(&mut self) {
         self.score = 0;
+        self.blocks_destroyed = 0;
         self.has_game_ended = false;
+        self.level = 1;
+        self.blocks_left = 0;
+        self.lives = STARTING_LIVES;
+        self.is_stage_clear = false;
+    }
+}
+
+/// The levels of the levels folder, read again whenever its files are edited
+#[derive(Resource)]
+pub struct LevelCollection {
+    levels: Vec<Level>,
+    files: Vec<(PathBuf, SystemTime)>,
+    pub reload_timer: Timer,
+}
+
+impl Default for LevelCollection {
+    fn default() -> Self {
+        let files = level_files();
+        LevelCollection {
+            levels: load_levels(&files),
+            files,
+            reload_timer: Timer::from_seconds(LEVEL_RELOAD_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl LevelCollection {
+    /// Level played as the given one, starting from 1
+    pub fn level(&self, level: u32) -> &Level {
+        &self.levels[(level as usize - 1) % self.levels.len()]
+    }
+
+    /// Whether the files changed since last read, in which case the levels are read again
+    pub fn reload_if_changed(&mut self) -> bool {
+        let files = level_files();
+        if files == self.files {
+            return false;
+        }
+        self.levels = load_levels(&files);
+        self.files = files;
+        true
+    }
+}
+
+/// Power-ups caught whose effect hasn't worn off yet, in the order they were caught
+#[derive(Resource, Default)]
+pub struct ActivePowerUps {
+    effects: Vec<(PowerUpKind, Timer)>,
+}
+
+impl ActivePowerUps {
+    /// Starts the effect of the power-up, or starts it over when it was already active
+    pub fn activate(&mut self, kind: PowerUpKind) {
+        let Some(duration) = kind.duration() else { return; };
+        self.effects
+            .retain(|(active, _)| *active != kind && Some(*active) != kind.opposite());
+        self.effects
+            .push((kind, Timer::from_seconds(duration, TimerMode::Once)));
+    }
+
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.effects.iter().any(|(active, _)| *active == kind)
+    }
+
+    /// Ticks the effects, removing the ones that wore off
+    pub fn tick(&mut self, delta: Duration) {
+        for (_, timer) in self.effects.iter_mut() {
+            timer.tick(delta);
+        }
+        self.effects.retain(|(_, timer)| !timer.finished());
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Every active effect with the seconds it has left
+    pub fn remaining(&self) -> impl Iterator<Item = (PowerUpKind, f32)> + '_ {
+        self.effects
+            .iter()
+            .map(|(kind, timer)| (*kind, timer.remaining_secs()))
+    }
+
+    pub fn paddle_width_factor(&self) -> f32 {
+        if self.is_active(PowerUpKind::WidePaddle) {
+            WIDE_PADDLE_FACTOR
+        } else if self.is_active(PowerUpKind::NarrowPaddle) {
+            NARROW_PADDLE_FACTOR
+        } else {
+            1.0
+        }
+    }
+
+    pub fn ball_speed_factor(&self) -> f32 {
+        if self.is_active(PowerUpKind::SlowBall) {
+            SLOW_BALL_FACTOR
+        } else {
+            1.0
+        }
     }
 }
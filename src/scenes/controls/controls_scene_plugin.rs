@@ -0,0 +1,275 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::actions::{Action, ActionBindings, Actions, BindingInput};
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::constants::{FONT_FILE, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::scenes::controls::components::ControlsComponent;
+use crate::scenes::controls::resources::ControlsScreen;
+
+pub struct ControlsScenePlugin;
+
+const HIGHLIGHT_COLOR: Color = Color::YELLOW;
+const ROW_HEIGHT: f32 = 40.0;
+const ACTION_COLUMN_X: f32 = -480.0;
+const KEYS_COLUMN_X: f32 = -160.0;
+const GAMEPAD_BUTTONS_COLUMN_X: f32 = 160.0;
+
+impl Plugin for ControlsScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ControlsScreen>()
+            .add_system(setup_screen.in_schedule(OnEnter(AppState::Controls)))
+            .add_system(despawn_screen.in_schedule(OnExit(AppState::Controls)))
+            .add_systems(
+                (
+                    controls_input,
+                    redraw_screen
+                        .run_if(resource_changed::<ControlsScreen>())
+                        .after(controls_input),
+                )
+                    .in_set(OnUpdate(AppState::Controls)),
+            );
+    }
+}
+
+fn setup_screen(mut screen: ResMut<ControlsScreen>) {
+    screen.selected = 0;
+    screen.is_listening = false;
+    screen.message = None;
+}
+
+fn despawn_screen(mut commands: Commands, components: Query<Entity, With<ControlsComponent>>) {
+    for component in components.iter() {
+        commands.entity(component).despawn();
+    }
+}
+
+/// While listening, the next key or gamepad button pressed is bound to the selected action.
+/// Handled in the same system as the navigation so that the press selecting an action is not
+/// bound to it, and the press bound is not used to navigate.
+fn controls_input(
+    keys: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    actions: Res<Actions>,
+    mut bindings: ResMut<ActionBindings>,
+    mut screen: ResMut<ControlsScreen>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if screen.is_listening {
+        if keys.just_pressed(KeyCode::Escape) {
+            screen.is_listening = false;
+            return;
+        }
+        let input = keys
+            .get_just_pressed()
+            .next()
+            .map(|key| BindingInput::Key(*key))
+            .or_else(|| {
+                gamepad_buttons
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| BindingInput::GamepadButton(button.button_type))
+            });
+        let Some(input) = input else { return; };
+        let Some(action) = screen.selected_action() else { return; };
+
+        let game = screen.game;
+        screen.is_listening = false;
+        if let Some(other) = bindings.conflict(game, action, input) {
+            screen.message = Some(format!(
+                "{} is already used by {}",
+                input.label(),
+                other.name(game)
+            ));
+            return;
+        }
+        bindings.bind(game, action, input);
+        bindings.save();
+        screen.message = Some(format!("{} bound to {}", action.name(game), input.label()));
+        return;
+    }
+
+    if actions.just_pressed(Action::MoveUp) {
+        screen.move_selection(-1);
+    } else if actions.just_pressed(Action::MoveDown) {
+        screen.move_selection(1);
+    } else if actions.just_pressed(Action::MoveLeft) {
+        screen.cycle_game(-1);
+    } else if actions.just_pressed(Action::MoveRight) {
+        screen.cycle_game(1);
+    } else if actions.just_pressed(Action::Confirm) {
+        if screen.selected_action().is_some() {
+            screen.is_listening = true;
+            screen.message = None;
+        } else {
+            bindings.reset(screen.game);
+            bindings.save();
+            screen.message = Some("Defaults restored".to_string());
+        }
+    } else if actions.just_pressed(Action::Back) {
+        next_state.set(AppState::Menu);
+    }
+}
+
+fn redraw_screen(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    viewport_size: Res<ViewportSize>,
+    screen: Res<ControlsScreen>,
+    bindings: Res<ActionBindings>,
+    components: Query<Entity, With<ControlsComponent>>,
+) {
+    for component in components.iter() {
+        commands.entity(component).despawn();
+    }
+
+    let font = assets.load(FONT_FILE.to_string());
+    let scale = viewport_size.height / WINDOW_HEIGHT;
+    let mut spawn_text =
+        |value: String, font_size: f32, color: Color, pos: Vec2, anchor: Anchor| {
+            commands.spawn((
+                text(value, font.clone(), font_size, color, pos, anchor, scale),
+                ControlsComponent,
+            ));
+        };
+
+    spawn_text(
+        "Controls".to_string(),
+        60.0,
+        Color::WHITE,
+        Vec2::new(0.0, WINDOW_HEIGHT / 2.0 - 20.0),
+        Anchor::TopCenter,
+    );
+    spawn_text(
+        format!("< {} >", screen.game.name()),
+        40.0,
+        screen.game.color(),
+        Vec2::new(0.0, 270.0),
+        Anchor::Center,
+    );
+
+    let header_y = 200.0;
+    for (label, x) in [
+        ("Action", ACTION_COLUMN_X),
+        ("Keyboard", KEYS_COLUMN_X),
+        ("Gamepad", GAMEPAD_BUTTONS_COLUMN_X),
+    ] {
+        spawn_text(
+            label.to_string(),
+            26.0,
+            Color::GRAY,
+            Vec2::new(x, header_y),
+            Anchor::CenterLeft,
+        );
+    }
+
+    let actions = Action::of_game(screen.game);
+    for (row, action) in actions.iter().enumerate() {
+        let is_selected = row == screen.selected;
+        let color = if is_selected {
+            HIGHLIGHT_COLOR
+        } else {
+            Color::WHITE
+        };
+        let y = header_y - (row + 1) as f32 * ROW_HEIGHT;
+        let binding = bindings.binding(Some(screen.game), *action);
+        let (keys_label, gamepad_buttons_label) = if is_selected && screen.is_listening {
+            ("Press a key...".to_string(), "...or a button".to_string())
+        } else {
+            (binding.keys_label(), binding.gamepad_buttons_label())
+        };
+        let prefix = if is_selected { "> " } else { "  " };
+        spawn_text(
+            format!("{}{}", prefix, action.name(screen.game)),
+            26.0,
+            color,
+            Vec2::new(ACTION_COLUMN_X, y),
+            Anchor::CenterLeft,
+        );
+        spawn_text(
+            keys_label,
+            26.0,
+            color,
+            Vec2::new(KEYS_COLUMN_X, y),
+            Anchor::CenterLeft,
+        );
+        spawn_text(
+            gamepad_buttons_label,
+            26.0,
+            color,
+            Vec2::new(GAMEPAD_BUTTONS_COLUMN_X, y),
+            Anchor::CenterLeft,
+        );
+    }
+
+    let reset_row = actions.len();
+    let is_reset_selected = screen.selected == reset_row;
+    spawn_text(
+        format!(
+            "{}Reset to Defaults",
+            if is_reset_selected { "> " } else { "  " }
+        ),
+        26.0,
+        if is_reset_selected {
+            HIGHLIGHT_COLOR
+        } else {
+            Color::WHITE
+        },
+        Vec2::new(
+            ACTION_COLUMN_X,
+            header_y - (reset_row + 2) as f32 * ROW_HEIGHT,
+        ),
+        Anchor::CenterLeft,
+    );
+
+    if let Some(message) = &screen.message {
+        spawn_text(
+            message.clone(),
+            26.0,
+            HIGHLIGHT_COLOR,
+            Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 + 80.0),
+            Anchor::Center,
+        );
+    }
+
+    let hint = if screen.is_listening {
+        "[Esc]: Cancel"
+    } else {
+        "[Up/Down]: Select   [Left/Right]: Change Game   [Enter]: Rebind   [Esc]: Menu"
+    };
+    spawn_text(
+        hint.to_string(),
+        20.0,
+        Color::WHITE,
+        Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 + 10.0),
+        Anchor::BottomCenter,
+    );
+}
+
+fn text(
+    value: String,
+    font: Handle<Font>,
+    font_size: f32,
+    color: Color,
+    pos: Vec2,
+    anchor: Anchor,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0),
+            text_anchor: anchor,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT)).with_scale(false, false),
+    )
+}
@@ -0,0 +1,5 @@
+pub use controls_scene_plugin::ControlsScenePlugin;
+
+mod components;
+mod controls_scene_plugin;
+mod resources;
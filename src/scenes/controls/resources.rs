@@ -0,0 +1,58 @@
+use bevy::prelude::Resource;
+
+use crate::actions::Action;
+use crate::common::Game;
+
+#[derive(Resource)]
+pub struct ControlsScreen {
+    pub game: Game,
+    /// Selected row, the one after the actions of the game resetting them to their defaults
+    pub selected: usize,
+    /// Waiting for the key or button to bind to the selected action
+    pub is_listening: bool,
+    /// Outcome of the last change, such as a conflict with another action
+    pub message: Option<String>,
+}
+
+impl Default for ControlsScreen {
+    fn default() -> Self {
+        ControlsScreen {
+            game: rebindable_games()[0],
+            selected: 0,
+            is_listening: false,
+            message: None,
+        }
+    }
+}
+
+impl ControlsScreen {
+    pub fn cycle_game(&mut self, delta: i32) {
+        let games = rebindable_games();
+        let count = games.len() as i32;
+        let index = games
+            .iter()
+            .position(|game| *game == self.game)
+            .unwrap_or(0) as i32;
+        self.game = games[(index + delta).rem_euclid(count) as usize];
+        self.selected = 0;
+        self.message = None;
+    }
+
+    pub fn move_selection(&mut self, delta: i32) {
+        let row_count = Action::of_game(self.game).len() as i32 + 1;
+        self.selected = (self.selected as i32 + delta).rem_euclid(row_count) as usize;
+        self.message = None;
+    }
+
+    pub fn selected_action(&self) -> Option<Action> {
+        Action::of_game(self.game).get(self.selected).copied()
+    }
+}
+
+/// Games with actions the player can rebind
+pub fn rebindable_games() -> Vec<Game> {
+    Game::supported_games()
+        .filter(|game| !Action::of_game(**game).is_empty())
+        .copied()
+        .collect()
+}
@@ -0,0 +1,317 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::actions::{Action, Actions};
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::constants::{FONT_FILE, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::high_scores::{HighScores, PendingHighScore};
+use crate::scenes::high_scores::components::HighScoresComponent;
+use crate::scenes::high_scores::resources::{HighScoresScreen, InitialsEntry};
+
+pub struct HighScoresScenePlugin;
+
+const LETTER_KEYS: [KeyCode; 26] = [
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+];
+const HIGHLIGHT_COLOR: Color = Color::YELLOW;
+const ROW_HEIGHT: f32 = 40.0;
+
+impl Plugin for HighScoresScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HighScoresScreen>()
+            .add_system(setup_screen.in_schedule(OnEnter(AppState::HighScores)))
+            .add_system(despawn_screen.in_schedule(OnExit(AppState::HighScores)))
+            .add_systems(
+                (
+                    initials_entry_input,
+                    // Before the initials entry so that confirming it does not start a game
+                    table_input.before(initials_entry_input),
+                    redraw_screen
+                        .run_if(resource_changed::<HighScoresScreen>())
+                        .after(initials_entry_input)
+                        .after(table_input),
+                )
+                    .in_set(OnUpdate(AppState::HighScores)),
+            );
+    }
+}
+
+/// Starts with the initials entry if a game just ended with a high score, with the tables otherwise
+fn setup_screen(pending_high_score: Res<PendingHighScore>, mut screen: ResMut<HighScoresScreen>) {
+    screen.new_entry_rank = None;
+    screen.initials_entry = None;
+    if let Some((game, _)) = &pending_high_score.0 {
+        screen.game = *game;
+        screen.initials_entry = Some(InitialsEntry::default());
+    }
+}
+
+fn despawn_screen(mut commands: Commands, components: Query<Entity, With<HighScoresComponent>>) {
+    for component in components.iter() {
+        commands.entity(component).despawn();
+    }
+}
+
+fn initials_entry_input(
+    keys: Res<Input<KeyCode>>,
+    actions: Res<Actions>,
+    mut screen: ResMut<HighScoresScreen>,
+    mut pending_high_score: ResMut<PendingHighScore>,
+    mut high_scores: ResMut<HighScores>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let has_input = keys.get_just_pressed().next().is_some()
+        || Action::ALL
+            .iter()
+            .any(|action| actions.just_pressed(*action));
+    if screen.initials_entry.is_none() || !has_input {
+        return;
+    }
+    let Some(entry) = screen.initials_entry.as_mut() else { return; };
+
+    // Typed letters come first, as the movement actions are also bound to letters
+    if let Some(index) = LETTER_KEYS.iter().position(|key| keys.just_pressed(*key)) {
+        entry.type_letter((b'A' + index as u8) as char);
+    } else if actions.just_pressed(Action::MoveUp) {
+        entry.cycle_letter(1);
+    } else if actions.just_pressed(Action::MoveDown) {
+        entry.cycle_letter(-1);
+    } else if actions.just_pressed(Action::MoveRight) {
+        entry.move_cursor(1);
+    } else if actions.just_pressed(Action::MoveLeft) || keys.just_pressed(KeyCode::Back) {
+        entry.move_cursor(-1);
+    }
+
+    // Leaving keeps the score with the initials entered so far
+    let is_leaving = actions.just_pressed(Action::Back);
+    if !is_leaving && !actions.just_pressed(Action::Confirm) {
+        return;
+    }
+    let initials = entry.initials();
+    let Some((game, mut high_score)) = pending_high_score.0.take() else { return; };
+    high_score.initials = initials;
+    let rank = high_scores.insert(game, high_score);
+    high_scores.save();
+
+    screen.initials_entry = None;
+    screen.new_entry_rank = Some(rank);
+    if is_leaving {
+        next_state.set(AppState::Menu);
+    }
+}
+
+fn table_input(
+    actions: Res<Actions>,
+    mut screen: ResMut<HighScoresScreen>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if screen.initials_entry.is_some() {
+        return;
+    }
+
+    if actions.just_pressed(Action::MoveLeft) {
+        screen.cycle_game(-1);
+    } else if actions.just_pressed(Action::MoveRight) {
+        screen.cycle_game(1);
+    } else if actions.just_pressed(Action::Confirm) {
+        next_state.set(screen.game.app_state());
+    } else if actions.just_pressed(Action::Back) {
+        next_state.set(AppState::Menu);
+    }
+}
+
+fn redraw_screen(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    viewport_size: Res<ViewportSize>,
+    screen: Res<HighScoresScreen>,
+    pending_high_score: Res<PendingHighScore>,
+    high_scores: Res<HighScores>,
+    components: Query<Entity, With<HighScoresComponent>>,
+) {
+    for component in components.iter() {
+        commands.entity(component).despawn();
+    }
+
+    let font = assets.load(FONT_FILE.to_string());
+    let scale = viewport_size.height / WINDOW_HEIGHT;
+    let mut spawn_text =
+        |value: String, font_size: f32, color: Color, pos: Vec2, anchor: Anchor| {
+            commands.spawn((
+                text(value, font.clone(), font_size, color, pos, anchor, scale),
+                HighScoresComponent,
+            ));
+        };
+
+    if let Some(entry) = &screen.initials_entry {
+        let score = pending_high_score
+            .0
+            .as_ref()
+            .map_or(0, |(_, high_score)| high_score.score);
+        spawn_text(
+            "New High Score!".to_string(),
+            60.0,
+            HIGHLIGHT_COLOR,
+            Vec2::new(0.0, 250.0),
+            Anchor::Center,
+        );
+        spawn_text(
+            format!("{}: {}", screen.game.name(), score),
+            40.0,
+            Color::WHITE,
+            Vec2::new(0.0, 150.0),
+            Anchor::Center,
+        );
+        spawn_text(
+            "Enter your initials".to_string(),
+            30.0,
+            Color::WHITE,
+            Vec2::new(0.0, 60.0),
+            Anchor::Center,
+        );
+        let letter_spacing = 80.0;
+        let start_x = -(entry.letters.len() as f32 - 1.0) * letter_spacing / 2.0;
+        for (i, letter) in entry.letters.iter().enumerate() {
+            let is_selected = i == entry.cursor;
+            let pos = Vec2::new(start_x + i as f32 * letter_spacing, -40.0);
+            spawn_text(
+                letter.to_string(),
+                80.0,
+                if is_selected {
+                    HIGHLIGHT_COLOR
+                } else {
+                    Color::WHITE
+                },
+                pos,
+                Anchor::Center,
+            );
+            if is_selected {
+                spawn_text(
+                    "^".to_string(),
+                    40.0,
+                    HIGHLIGHT_COLOR,
+                    pos - Vec2::new(0.0, 70.0),
+                    Anchor::Center,
+                );
+            }
+        }
+        spawn_text(
+            "[A-Z]: Type   [Up/Down]: Change Letter   [Left/Right]: Move   [Enter]: Confirm"
+                .to_string(),
+            20.0,
+            Color::WHITE,
+            Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+        );
+        return;
+    }
+
+    spawn_text(
+        "High Scores".to_string(),
+        60.0,
+        Color::WHITE,
+        Vec2::new(0.0, WINDOW_HEIGHT / 2.0 - 20.0),
+        Anchor::TopCenter,
+    );
+    spawn_text(
+        format!("< {} >", screen.game.name()),
+        40.0,
+        screen.game.color(),
+        Vec2::new(0.0, 270.0),
+        Anchor::Center,
+    );
+
+    let table = high_scores.table(screen.game);
+    if table.is_empty() {
+        spawn_text(
+            "No scores yet".to_string(),
+            30.0,
+            Color::GRAY,
+            Vec2::new(0.0, 0.0),
+            Anchor::Center,
+        );
+    }
+    let table_left = -WINDOW_WIDTH / 2.0 + 120.0;
+    for (rank, entry) in table.iter().enumerate() {
+        let row = format!(
+            "{:>2}. {:<3} {:>10}   {}   {}",
+            rank + 1,
+            entry.initials,
+            entry.score,
+            entry.date(),
+            entry.stats_summary()
+        );
+        spawn_text(
+            row,
+            26.0,
+            if screen.new_entry_rank == Some(rank) {
+                HIGHLIGHT_COLOR
+            } else {
+                Color::WHITE
+            },
+            Vec2::new(table_left, 200.0 - rank as f32 * ROW_HEIGHT),
+            Anchor::CenterLeft,
+        );
+    }
+
+    spawn_text(
+        "[Left/Right]: Change Game   [Enter]: Play   [Esc]: Menu".to_string(),
+        20.0,
+        Color::WHITE,
+        Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 + 10.0),
+        Anchor::BottomCenter,
+    );
+}
+
+fn text(
+    value: String,
+    font: Handle<Font>,
+    font_size: f32,
+    color: Color,
+    pos: Vec2,
+    anchor: Anchor,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0),
+            text_anchor: anchor,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT)).with_scale(false, false),
+    )
+}
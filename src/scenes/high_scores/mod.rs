@@ -0,0 +1,5 @@
+pub use high_scores_scene_plugin::HighScoresScenePlugin;
+
+mod components;
+mod high_scores_scene_plugin;
+mod resources;
@@ -0,0 +1,70 @@
+use bevy::prelude::Resource;
+
+use crate::common::Game;
+use crate::high_scores::{HIGH_SCORE_GAMES, INITIALS_LENGTH};
+
+#[derive(Resource)]
+pub struct HighScoresScreen {
+    pub game: Game,
+    pub initials_entry: Option<InitialsEntry>,
+    /// Rank of the entry just added to the table, highlighted
+    pub new_entry_rank: Option<usize>,
+}
+
+impl Default for HighScoresScreen {
+    fn default() -> Self {
+        HighScoresScreen {
+            game: HIGH_SCORE_GAMES[0],
+            initials_entry: None,
+            new_entry_rank: None,
+        }
+    }
+}
+
+impl HighScoresScreen {
+    pub fn cycle_game(&mut self, delta: i32) {
+        let count = HIGH_SCORE_GAMES.len() as i32;
+        let index = HIGH_SCORE_GAMES
+            .iter()
+            .position(|game| *game == self.game)
+            .unwrap_or(0) as i32;
+        self.game = HIGH_SCORE_GAMES[(index + delta).rem_euclid(count) as usize];
+        self.new_entry_rank = None;
+    }
+}
+
+/// Arcade style initials, each letter being typed or scrolled through
+pub struct InitialsEntry {
+    pub letters: [char; INITIALS_LENGTH],
+    pub cursor: usize,
+}
+
+impl Default for InitialsEntry {
+    fn default() -> Self {
+        InitialsEntry {
+            letters: ['A'; INITIALS_LENGTH],
+            cursor: 0,
+        }
+    }
+}
+
+impl InitialsEntry {
+    pub fn cycle_letter(&mut self, delta: i32) {
+        let letter = &mut self.letters[self.cursor];
+        let index = (*letter as u8 - b'A') as i32;
+        *letter = (b'A' + (index + delta).rem_euclid(26) as u8) as char;
+    }
+
+    pub fn move_cursor(&mut self, delta: i32) {
+        self.cursor = (self.cursor as i32 + delta).clamp(0, INITIALS_LENGTH as i32 - 1) as usize;
+    }
+
+    pub fn type_letter(&mut self, letter: char) {
+        self.letters[self.cursor] = letter;
+        self.move_cursor(1);
+    }
+
+    pub fn initials(&self) -> String {
+        self.letters.iter().collect()
+    }
+}
@@ -1,10 +1,29 @@
 use bevy::prelude::Component;
 
 use crate::common::Game;
+use crate::scenes::menu::resources::MenuButton;
 
 #[derive(Component)]
 pub struct MenuComponent;
 
+#[derive(Component)]
+pub struct MenuButtonItem {
+    pub button: MenuButton,
+}
+
+/// Grows the menu item while it is focused
+#[derive(Component)]
+pub struct MenuItemAnimation {
+    pub scale: f32,
+}
+
+#[derive(Component)]
+pub struct MenuDetailsText;
+
+/// Frame drawn around the focused menu item
+#[derive(Component)]
+pub struct MenuFocusHighlight;
+
 #[derive(Component, Debug)]
 pub struct MenuGameItem {
     pub game: Game,
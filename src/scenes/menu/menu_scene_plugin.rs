@@ -1,26 +1,67 @@
 use bevy::asset::AssetServer;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
-use bevy::text::{Text, Text2dBundle, TextStyle};
+use bevy::text::{Text, Text2dBounds, Text2dBundle, TextStyle};
 use bevy::utils::default;
+use bevy::window::CursorMoved;
 
+use crate::actions::{Action, ActionBindings, Actions};
 use crate::common::{AppState, Game, Renderable, ViewportSize};
 use crate::constants::{FONT_FILE, WINDOW_HEIGHT, WINDOW_WIDTH};
-use crate::scenes::menu::components::{MenuComponent, MenuGameItem};
+use crate::high_scores::{HighScores, HIGH_SCORE_GAMES};
+use crate::scenes::menu::components::{
+    MenuButtonItem, MenuComponent, MenuDetailsText, MenuFocusHighlight, MenuGameItem,
+    MenuItemAnimation,
+};
+use crate::scenes::menu::resources::{MenuButton, MenuFocus};
 
 pub struct MenuScenePlugin;
 
 const GAMES_HORIZONTAL_MARGIN: f32 = 50.0;
+/// Shifts the cards to the left to make room for the details panel
+const GAMES_OFFSET_X: f32 = -150.0;
+const CARD_PADDING: f32 = 6.0;
+/// Height of the thumbnails relative to their width
+const THUMBNAIL_RATIO: f32 = 0.75;
+const DETAILS_PANEL_POS: Vec2 = Vec2::new(425.0, -50.0);
+const DETAILS_PANEL_SIZE: Vec2 = Vec2::new(380.0, 520.0);
+const DETAILS_PANEL_PADDING: f32 = 20.0;
+const BUTTON_SIZE: Vec2 = Vec2::new(260.0, 50.0);
+const BUTTONS_HORIZONTAL_MARGIN: f32 = 40.0;
+const FOCUS_HIGHLIGHT_MARGIN: f32 = 8.0;
+const FOCUSED_SCALE: f32 = 1.08;
+const FOCUS_ANIMATION_SPEED: f32 = 12.0;
 
 impl Plugin for MenuScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(setup_menu.in_schedule(OnEnter(AppState::Menu)))
+        app.init_resource::<MenuFocus>()
+            .add_system(setup_menu.in_schedule(OnEnter(AppState::Menu)))
             .add_system(despawn_menu.in_schedule(OnExit(AppState::Menu)))
-            .add_system(game_click_system.in_set(OnUpdate(AppState::Menu)));
+            .add_system(game_click_system.in_set(OnUpdate(AppState::Menu)))
+            .add_system(button_shortcut_input.in_set(OnUpdate(AppState::Menu)))
+            .add_system(focus_navigation_input.in_set(OnUpdate(AppState::Menu)))
+            .add_system(hover_focus_system.in_set(OnUpdate(AppState::Menu)))
+            .add_systems(
+                (
+                    focus_highlight_update,
+                    focus_animation_update,
+                    details_update,
+                )
+                    .in_set(OnUpdate(AppState::Menu))
+                    .after(focus_navigation_input)
+                    .after(hover_focus_system),
+            );
     }
 }
 
-fn setup_menu(mut commands: Commands, assets: Res<AssetServer>, viewport_size: Res<ViewportSize>) {
+fn setup_menu(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    viewport_size: Res<ViewportSize>,
+    high_scores: Res<HighScores>,
+    bindings: Res<ActionBindings>,
+    mut focus: ResMut<MenuFocus>,
+) {
     let font = assets.load(FONT_FILE.to_string());
     let title_style = TextStyle {
         font: font.clone(),
@@ -53,6 +94,10 @@ fn setup_menu(mut commands: Commands, assets: Res<AssetServer>, viewport_size: R
             game_count / 2 + 1
         }
     };
+    focus.game_count = game_count;
+    focus.first_row_count = row_1_count;
+    focus.game_index = focus.game_index.min(game_count - 1);
+
     let (game_size, trans_y) = if game_count <= row_1_count {
         (Vec2::new(270.0, 480.0), 0.0)
     } else {
@@ -69,34 +114,176 @@ fn setup_menu(mut commands: Commands, assets: Res<AssetServer>, viewport_size: R
         } as f32;
         let start_x = (-(row_count - 1.0)) * game_size.x / 2.0
             - (row_count - 1.0) * GAMES_HORIZONTAL_MARGIN / 2.0;
-        let final_trans_x = start_x + row_index * (game_size.x + GAMES_HORIZONTAL_MARGIN);
+        let final_trans_x =
+            start_x + row_index * (game_size.x + GAMES_HORIZONTAL_MARGIN) + GAMES_OFFSET_X;
         let final_trans_y = if is_first_row {
             trans_y
         } else {
             -1.0 * trans_y
         } - 50.0;
         let pos = Vec2::new(final_trans_x, final_trans_y);
+        let game_color = game.color();
+        let card_color = Color::rgb(
+            game_color.r() * 0.3,
+            game_color.g() * 0.3,
+            game_color.b() * 0.3,
+        );
+        let content_width = game_size.x - CARD_PADDING * 2.0;
+        let thumbnail_size = Vec2::new(content_width, content_width * THUMBNAIL_RATIO);
+        let thumbnail_y = (game_size.y - thumbnail_size.y) / 2.0 - CARD_PADDING;
+        let title_y = thumbnail_y - thumbnail_size.y / 2.0 - CARD_PADDING;
+        let font_scale = game_size.x / 135.0;
+
+        commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: card_color,
+                        custom_size: Some(game_size),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0)
+                        .with_scale(Vec3::new(scale, scale, 1.0)),
+                    ..default()
+                },
+                MenuComponent,
+                MenuGameItem { game: game.clone() },
+                MenuItemAnimation { scale: 1.0 },
+                Renderable::new(pos, target_resolution).with_size(game_size),
+            ))
+            .with_children(|card| {
+                card.spawn(SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::WHITE,
+                        custom_size: Some(thumbnail_size),
+                        ..default()
+                    },
+                    texture: assets.load(game.thumbnail()),
+                    transform: Transform::from_xyz(0.0, thumbnail_y, 0.1),
+                    ..default()
+                });
+                card.spawn(Text2dBundle {
+                    text: Text::from_section(
+                        game.name(),
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 20.0 * font_scale,
+                            color: game_color,
+                        },
+                    )
+                    .with_alignment(TextAlignment::Center),
+                    text_2d_bounds: Text2dBounds {
+                        size: Vec2::new(content_width, f32::INFINITY),
+                    },
+                    transform: Transform::from_xyz(0.0, title_y, 0.1),
+                    text_anchor: Anchor::TopCenter,
+                    ..default()
+                });
+            });
+    }
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.12, 0.12, 0.12),
+                custom_size: Some(DETAILS_PANEL_SIZE),
+                ..default()
+            },
+            transform: Transform::from_xyz(
+                DETAILS_PANEL_POS.x * scale,
+                DETAILS_PANEL_POS.y * scale,
+                1.0,
+            )
+            .with_scale(Vec3::new(scale, scale, 1.0)),
+            ..default()
+        },
+        MenuComponent,
+        Renderable::new(DETAILS_PANEL_POS, target_resolution).with_size(DETAILS_PANEL_SIZE),
+    ));
+    let details_pos = DETAILS_PANEL_POS
+        + Vec2::new(-DETAILS_PANEL_SIZE.x, DETAILS_PANEL_SIZE.y) / 2.0
+        + Vec2::new(DETAILS_PANEL_PADDING, -DETAILS_PANEL_PADDING);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_sections(details_sections(&focus, &high_scores, &bindings, &font)),
+            text_2d_bounds: Text2dBounds {
+                size: Vec2::new(
+                    DETAILS_PANEL_SIZE.x - DETAILS_PANEL_PADDING * 2.0,
+                    f32::INFINITY,
+                ),
+            },
+            transform: Transform::from_xyz(details_pos.x * scale, details_pos.y * scale, 2.0),
+            text_anchor: Anchor::TopLeft,
+            ..default()
+        },
+        MenuComponent,
+        MenuDetailsText,
+        Renderable::new(details_pos, target_resolution).with_scale(false, false),
+    ));
+
+    // Placed on the focused item by focus_highlight_update
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::WHITE,
+                custom_size: Some(Vec2::ZERO),
+                ..default()
+            },
+            transform: Transform::from_scale(Vec3::new(scale, scale, 0.0)),
+            ..default()
+        },
+        MenuComponent,
+        MenuFocusHighlight,
+        Renderable::new(Vec2::ZERO, target_resolution),
+    ));
+
+    let button_count = MenuButton::ALL.len() as f32;
+    let buttons_start_x =
+        GAMES_OFFSET_X - (button_count - 1.0) * (BUTTON_SIZE.x + BUTTONS_HORIZONTAL_MARGIN) / 2.0;
+    for (i, button) in MenuButton::ALL.iter().enumerate() {
+        let button_pos = Vec2::new(
+            buttons_start_x + i as f32 * (BUTTON_SIZE.x + BUTTONS_HORIZONTAL_MARGIN),
+            -WINDOW_HEIGHT / 2.0 + 40.0,
+        );
         commands.spawn((
             SpriteBundle {
                 sprite: Sprite {
-                    color: game.color(),
-                    custom_size: Some(game_size),
+                    color: Color::DARK_GRAY,
+                    custom_size: Some(BUTTON_SIZE),
                     ..default()
                 },
-                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 0.0)
-                    .with_scale(Vec3::new(scale, scale, 0.0)),
+                transform: Transform::from_xyz(button_pos.x * scale, button_pos.y * scale, 1.0)
+                    .with_scale(Vec3::new(scale, scale, 1.0)),
+                ..default()
+            },
+            MenuComponent,
+            MenuButtonItem { button: *button },
+            MenuItemAnimation { scale: 1.0 },
+            Renderable::new(button_pos, target_resolution).with_size(BUTTON_SIZE),
+        ));
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    format!("{} [{:?}]", button.name(), button.shortcut()),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(button_pos.x * scale, button_pos.y * scale, 2.0),
+                text_anchor: Anchor::Center,
                 ..default()
             },
             MenuComponent,
-            MenuGameItem { game: game.clone() },
-            Renderable::new(pos, target_resolution).with_size(game_size),
+            Renderable::new(button_pos, target_resolution).with_scale(false, false),
         ));
     }
 }
 
 pub fn despawn_menu(mut commands: Commands, components: Query<Entity, With<MenuComponent>>) {
     for component in components.iter() {
-        commands.entity(component).despawn();
+        commands.entity(component).despawn_recursive();
     }
 }
 
@@ -105,36 +292,244 @@ pub fn game_click_system(
     mut next_state: ResMut<NextState<AppState>>,
     windows: Query<&Window>,
     games: Query<(&MenuGameItem, &Sprite, &Transform)>,
+    buttons: Query<(&MenuButtonItem, &Sprite, &Transform)>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
 ) {
     if !mouse.just_pressed(MouseButton::Left) {
         return;
     }
 
-    let window = windows.single();
     let (camera, camera_transform) = camera_query.single();
-    let Some(cursor_position) = window.cursor_position()
+    let Some(cursor_position) = windows.single().cursor_position()
         .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
         .map(|ray| ray.origin.truncate()) else { return; };
 
     for (game, sprite, transform) in games.iter() {
         if let Some(bounds) = game_bounds(transform, sprite) {
             if bounds.contains(cursor_position) {
-                match game.game {
-                    Game::Asteroid => {}
-                    Game::Bomberman => {}
-                    Game::Breakout => next_state.set(AppState::Breakout),
-                    Game::PacMan => {}
-                    Game::Sokoban => {}
-                    Game::SpaceInvaders => {}
-                    Game::Tetris => {}
-                    Game::Tron => {}
-                };
+                next_state.set(game.game.app_state());
+            }
+        }
+    }
+
+    for (item, sprite, transform) in buttons.iter() {
+        if let Some(bounds) = game_bounds(transform, sprite) {
+            if bounds.contains(cursor_position) {
+                next_state.set(item.button.app_state());
+            }
+        }
+    }
+}
+
+fn button_shortcut_input(keys: Res<Input<KeyCode>>, mut next_state: ResMut<NextState<AppState>>) {
+    for button in MenuButton::ALL {
+        if keys.just_pressed(button.shortcut()) {
+            next_state.set(button.app_state());
+        }
+    }
+}
+
+fn focus_navigation_input(
+    actions: Res<Actions>,
+    mut focus: ResMut<MenuFocus>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if actions.just_pressed(Action::MoveLeft) {
+        focus.move_horizontally(-1);
+    } else if actions.just_pressed(Action::MoveRight) {
+        focus.move_horizontally(1);
+    } else if actions.just_pressed(Action::MoveUp) {
+        focus.move_vertically(-1);
+    } else if actions.just_pressed(Action::MoveDown) {
+        focus.move_vertically(1);
+    } else if actions.just_pressed(Action::Confirm) {
+        if let Some(button) = focus.focused_button() {
+            next_state.set(button.app_state());
+            return;
+        }
+        let Some(game) = Game::supported_games().nth(focus.game_index) else { return; };
+        next_state.set(game.app_state());
+    }
+}
+
+/// Frames the focused item, drawn behind it
+fn focus_highlight_update(
+    focus: Res<MenuFocus>,
+    games: Query<(&MenuGameItem, &Renderable), Without<MenuFocusHighlight>>,
+    buttons: Query<(&MenuButtonItem, &Renderable), Without<MenuFocusHighlight>>,
+    mut highlight_q: Query<(&mut Renderable, &mut Sprite), With<MenuFocusHighlight>>,
+) {
+    let Ok((mut highlight, mut highlight_sprite)) = highlight_q.get_single_mut() else { return; };
+    let focused = if let Some(focused_button) = focus.focused_button() {
+        buttons
+            .iter()
+            .find(|(item, _)| item.button == focused_button)
+            .map(|(_, renderable)| renderable)
+    } else {
+        let focused_game = Game::supported_games().nth(focus.game_index);
+        games
+            .iter()
+            .find(|(item, _)| Some(&item.game) == focused_game)
+            .map(|(_, renderable)| renderable)
+    };
+    let Some(focused) = focused else { return; };
+
+    let size = focused.size + Vec2::splat(FOCUS_HIGHLIGHT_MARGIN * 2.0);
+    if highlight.pos != focused.pos || highlight.size != size {
+        highlight.pos = focused.pos;
+        highlight.size = size;
+        highlight_sprite.custom_size = Some(size);
+    }
+}
+
+/// Focuses the item under the mouse cursor whenever it moves
+fn hover_focus_system(
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    mut focus: ResMut<MenuFocus>,
+    games: Query<(&MenuGameItem, &Sprite, &Transform)>,
+    buttons: Query<(&MenuButtonItem, &Sprite, &Transform)>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let Some(cursor) = cursor_moved_events.iter().last().map(|event| event.position) else { return; };
+    let (camera, camera_transform) = camera_query.single();
+    let Some(cursor_position) = camera.viewport_to_world(camera_transform, cursor)
+        .map(|ray| ray.origin.truncate()) else { return; };
+
+    let is_hovered = |sprite: &Sprite, transform: &Transform| {
+        game_bounds(transform, sprite).is_some_and(|bounds| bounds.contains(cursor_position))
+    };
+    let hovered_game = games
+        .iter()
+        .find(|(_, sprite, transform)| is_hovered(sprite, transform))
+        .and_then(|(item, _, _)| Game::supported_games().position(|game| *game == item.game));
+    let hovered_button = buttons
+        .iter()
+        .find(|(_, sprite, transform)| is_hovered(sprite, transform))
+        .and_then(|(item, _, _)| {
+            MenuButton::ALL
+                .iter()
+                .position(|button| *button == item.button)
+        });
+    if let Some(game_index) = hovered_game {
+        if focus.button_index.is_some() || focus.game_index != game_index {
+            focus.button_index = None;
+            focus.game_index = game_index;
+        }
+    } else if hovered_button.is_some() && focus.button_index != hovered_button {
+        focus.button_index = hovered_button;
+    }
+}
+
+type AnimatedItem<'a> = (
+    &'a mut Transform,
+    &'a mut MenuItemAnimation,
+    Option<&'a MenuGameItem>,
+    Option<&'a MenuButtonItem>,
+);
+
+/// Eases the focused item towards a bigger scale and the others back to their normal one
+fn focus_animation_update(
+    time: Res<Time>,
+    viewport_size: Res<ViewportSize>,
+    focus: Res<MenuFocus>,
+    mut items: Query<AnimatedItem, Without<MenuFocusHighlight>>,
+    mut highlight_q: Query<&mut Transform, With<MenuFocusHighlight>>,
+) {
+    let viewport_scale = viewport_size.height / WINDOW_HEIGHT;
+    let focused_game = focus.focused_game();
+    let step = (time.delta_seconds() * FOCUS_ANIMATION_SPEED).min(1.0);
+    let focused_button = focus.focused_button();
+    for (mut transform, mut animation, game_item, button_item) in items.iter_mut() {
+        let is_focused = match (game_item, button_item) {
+            (Some(item), _) => focused_game == Some(item.game),
+            (None, Some(item)) => focused_button == Some(item.button),
+            (None, None) => false,
+        };
+        let target = if is_focused { FOCUSED_SCALE } else { 1.0 };
+        animation.scale += (target - animation.scale) * step;
+
+        let scale = Vec3::new(
+            viewport_scale * animation.scale,
+            viewport_scale * animation.scale,
+            1.0,
+        );
+        transform.scale = scale;
+        if is_focused {
+            for mut highlight_transform in highlight_q.iter_mut() {
+                highlight_transform.scale = scale;
             }
         }
     }
 }
 
+fn details_update(
+    assets: Res<AssetServer>,
+    focus: Res<MenuFocus>,
+    high_scores: Res<HighScores>,
+    bindings: Res<ActionBindings>,
+    mut details_q: Query<&mut Text, With<MenuDetailsText>>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+
+    let font = assets.load(FONT_FILE.to_string());
+    for mut text in details_q.iter_mut() {
+        text.sections = details_sections(&focus, &high_scores, &bindings, &font);
+    }
+}
+
+/// Name, description, controls and personal best of the focused game
+fn details_sections(
+    focus: &MenuFocus,
+    high_scores: &HighScores,
+    bindings: &ActionBindings,
+    font: &Handle<Font>,
+) -> Vec<TextSection> {
+    let style = |font_size: f32, color: Color| TextStyle {
+        font: font.clone(),
+        font_size,
+        color,
+    };
+    if let Some(button) = focus.focused_button() {
+        return vec![
+            TextSection::new(format!("{}\n\n", button.name()), style(30.0, Color::WHITE)),
+            TextSection::new(button.description(), style(18.0, Color::WHITE)),
+        ];
+    }
+    let Some(game) = focus.focused_game() else { return vec![]; };
+
+    // the current bindings rather than the default ones
+    let controls = Action::of_game(game)
+        .iter()
+        .map(|action| {
+            let binding = bindings.binding(Some(game), *action);
+            format!("[{}] {}", binding.keys_label(), action.name(game))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut sections = vec![
+        TextSection::new(format!("{}\n\n", game.name()), style(30.0, game.color())),
+        TextSection::new(
+            format!("{}\n\n", game.description()),
+            style(18.0, Color::WHITE),
+        ),
+        TextSection::new("Controls\n", style(18.0, Color::GRAY)),
+        TextSection::new(format!("{}\n\n", controls), style(18.0, Color::WHITE)),
+    ];
+    if HIGH_SCORE_GAMES.contains(&game) {
+        let best = high_scores
+            .best(game)
+            .map_or("-".to_string(), |score| score.to_string());
+        sections.push(TextSection::new(
+            format!("Personal Best: {}", best),
+            style(22.0, Color::YELLOW),
+        ));
+    }
+    sections
+}
+
 fn game_bounds(transform: &Transform, sprite: &Sprite) -> Option<Rect> {
     sprite.custom_size.map(|size| {
         let width = size.x * transform.scale.x;
@@ -2,3 +2,4 @@ pub use menu_scene_plugin::MenuScenePlugin;
 
 mod components;
 mod menu_scene_plugin;
+mod resources;
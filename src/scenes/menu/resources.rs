@@ -0,0 +1,125 @@
+use bevy::prelude::{KeyCode, Resource};
+
+use crate::common::{AppState, Game};
+
+/// Buttons in the row below the games
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MenuButton {
+    HighScores,
+    Controls,
+    Settings,
+}
+
+impl MenuButton {
+    pub const ALL: [MenuButton; 3] = [
+        MenuButton::HighScores,
+        MenuButton::Controls,
+        MenuButton::Settings,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MenuButton::HighScores => "High Scores",
+            MenuButton::Controls => "Controls",
+            MenuButton::Settings => "Settings",
+        }
+    }
+
+    pub fn shortcut(self) -> KeyCode {
+        match self {
+            MenuButton::HighScores => KeyCode::H,
+            MenuButton::Controls => KeyCode::C,
+            MenuButton::Settings => KeyCode::O,
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            MenuButton::HighScores => {
+                "Browse the best scores of every game, along with the initials of their players."
+            }
+            MenuButton::Controls => {
+                "Rebind the keys and gamepad buttons of every game, or restore their defaults."
+            }
+            MenuButton::Settings => {
+                "Adjust the volumes, fullscreen, vsync, the FPS counter and the screen shake."
+            }
+        }
+    }
+
+    pub fn app_state(self) -> AppState {
+        match self {
+            MenuButton::HighScores => AppState::HighScores,
+            MenuButton::Controls => AppState::Controls,
+            MenuButton::Settings => AppState::Settings,
+        }
+    }
+}
+
+/// Item of the menu selected with the keyboard or a gamepad
+#[derive(Resource, Default)]
+pub struct MenuFocus {
+    pub game_index: usize,
+    /// Index of the focused button, keeping the game index to go back up to
+    pub button_index: Option<usize>,
+    pub game_count: usize,
+    pub first_row_count: usize,
+}
+
+impl MenuFocus {
+    pub fn focused_game(&self) -> Option<Game> {
+        if self.button_index.is_some() {
+            return None;
+        }
+        Game::supported_games().nth(self.game_index).copied()
+    }
+
+    pub fn focused_button(&self) -> Option<MenuButton> {
+        self.button_index.map(|index| MenuButton::ALL[index])
+    }
+
+    pub fn move_horizontally(&mut self, delta: i32) {
+        if let Some(index) = self.button_index {
+            let column = (index as i32 + delta).clamp(0, MenuButton::ALL.len() as i32 - 1);
+            self.button_index = Some(column as usize);
+            return;
+        }
+        let (row_start, row_count) = self.row_of(self.game_index);
+        let column = (self.game_index - row_start) as i32 + delta;
+        self.game_index = row_start + column.clamp(0, row_count as i32 - 1) as usize;
+    }
+
+    pub fn move_vertically(&mut self, delta: i32) {
+        let is_first_row = self.game_index < self.first_row_count;
+        let has_second_row = self.game_count > self.first_row_count;
+        if delta < 0 {
+            if self.button_index.is_some() {
+                self.button_index = None;
+            } else if !is_first_row {
+                self.move_to_row(0);
+            }
+        } else if self.button_index.is_some() {
+            // already on the last row
+        } else if is_first_row && has_second_row {
+            self.move_to_row(self.first_row_count);
+        } else {
+            self.button_index = Some(0);
+        }
+    }
+
+    /// Keeps the column, clamped to the length of the row
+    fn move_to_row(&mut self, row_start: usize) {
+        let (current_row_start, _) = self.row_of(self.game_index);
+        let (row_start, row_count) = self.row_of(row_start);
+        let column = (self.game_index - current_row_start).min(row_count - 1);
+        self.game_index = row_start + column;
+    }
+
+    fn row_of(&self, index: usize) -> (usize, usize) {
+        if index < self.first_row_count {
+            (0, self.first_row_count)
+        } else {
+            (self.first_row_count, self.game_count - self.first_row_count)
+        }
+    }
+}
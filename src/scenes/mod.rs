@@ -1,7 +1,27 @@
 pub use asteroid::AsteroidScenePlugin;
+pub use bomberman::BombermanScenePlugin;
 pub use breakout::BreakoutScenePlugin;
+pub use controls::ControlsScenePlugin;
+pub use high_scores::HighScoresScenePlugin;
 pub use menu::MenuScenePlugin;
+pub use pacman::PacManScenePlugin;
+pub use pause::PauseScenePlugin;
+pub use settings::SettingsScenePlugin;
+pub use sokoban::SokobanScenePlugin;
+pub use space_invaders::SpaceInvadersScenePlugin;
+pub use tetris::TetrisScenePlugin;
+pub use tron::TronScenePlugin;
 
 mod asteroid;
+mod bomberman;
 mod breakout;
+mod controls;
+mod high_scores;
 mod menu;
+mod pacman;
+mod pause;
+mod settings;
+mod sokoban;
+mod space_invaders;
+mod tetris;
+mod tron;
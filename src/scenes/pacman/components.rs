@@ -0,0 +1,108 @@
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{Component, Timer};
+
+use crate::scenes::pacman::ghost::{GhostKind, GhostState};
+
+/// Moves a character from tile to tile; `progress` is how far it got towards `tile + dir`
+#[derive(Component, Debug, Default)]
+pub struct GridMover {
+    pub tile: IVec2,
+    pub dir: IVec2,
+    pub progress: f32,
+    pub speed: f32,
+}
+
+impl GridMover {
+    pub fn new(tile: IVec2, dir: IVec2) -> Self {
+        GridMover {
+            tile,
+            dir,
+            ..Default::default()
+        }
+    }
+
+    /// Position in tile units
+    pub fn position(&self) -> Vec2 {
+        self.tile.as_vec2() + self.dir.as_vec2() * self.progress
+    }
+
+    /// The tile the character is mostly standing on
+    pub fn current_tile(&self) -> IVec2 {
+        if self.progress < 0.5 {
+            self.tile
+        } else {
+            self.tile + self.dir
+        }
+    }
+
+    /// Turns around in the middle of a tile
+    pub fn reverse(&mut self) {
+        if self.dir == IVec2::ZERO {
+            return;
+        }
+        self.tile += self.dir;
+        self.progress = 1.0 - self.progress;
+        self.dir = -self.dir;
+    }
+}
+
+#[derive(Component, Default)]
+pub struct PacMan {
+    pub desired_dir: IVec2,
+    /// The last direction Pac-Man moved in, kept while standing against a wall
+    pub facing: IVec2,
+}
+
+#[derive(Component)]
+pub struct PacManMouth;
+
+#[derive(Component)]
+pub struct Ghost {
+    pub kind: GhostKind,
+    pub state: GhostState,
+    pub is_frightened: bool,
+    pub release_timer: Timer,
+}
+
+#[derive(Component)]
+pub struct GhostEye {
+    pub offset: Vec2,
+}
+
+#[derive(Component)]
+pub struct PelletSprite {
+    pub tile: IVec2,
+}
+
+#[derive(Component)]
+pub struct PowerPelletSprite;
+
+#[derive(Component)]
+pub struct Fruit;
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct HighScoreText;
+
+#[derive(Component)]
+pub struct LevelText;
+
+#[derive(Component)]
+pub struct LifeIcon {
+    pub index: i32,
+}
+
+#[derive(Component)]
+pub struct ReadyText;
+
+#[derive(Component)]
+pub struct EndGameUIElement;
+
+/// Pac-Man and the ghosts, respawned at the start of every round
+#[derive(Component)]
+pub struct Actor;
+
+#[derive(Component)]
+pub struct PacManEntity;
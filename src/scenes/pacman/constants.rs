@@ -0,0 +1,39 @@
+use bevy::math::IVec2;
+
+pub const TILE_SIZE: f32 = 8.0;
+pub const MAZE_WIDTH: i32 = 28;
+pub const MAZE_HEIGHT: i32 = 31;
+/// Rows of HUD above and below the maze, like the arcade screen
+pub const HUD_TOP_ROWS: i32 = 3;
+pub const HUD_BOTTOM_ROWS: i32 = 2;
+
+pub const PLAY_AREA_WIDTH: f32 = MAZE_WIDTH as f32 * TILE_SIZE;
+pub const PLAY_AREA_HEIGHT: f32 = (MAZE_HEIGHT + HUD_TOP_ROWS + HUD_BOTTOM_ROWS) as f32 * TILE_SIZE;
+
+/// Speed of a character moving at 100%, in tiles per second
+pub const BASE_SPEED: f32 = 9.47;
+pub const EYES_SPEED_RATIO: f32 = 1.5;
+pub const COLLISION_DISTANCE: f32 = 0.6;
+
+pub const PACMAN_START_TILE: IVec2 = IVec2::new(13, 23);
+pub const PACMAN_START_LIVES: i32 = 3;
+pub const EXTRA_LIFE_SCORE: i32 = 10000;
+
+pub const HOUSE_EXIT_TILE: IVec2 = IVec2::new(13, 11);
+pub const HOUSE_CENTER_TILE: IVec2 = IVec2::new(13, 14);
+pub const FRUIT_TILE: IVec2 = IVec2::new(13, 17);
+pub const TUNNEL_ROW: i32 = 14;
+pub const TUNNEL_LENGTH: i32 = 6;
+
+pub const PELLET_SCORE: i32 = 10;
+pub const POWER_PELLET_SCORE: i32 = 50;
+pub const GHOST_BASE_SCORE: i32 = 200;
+pub const FRUIT_PELLET_COUNTS: [usize; 2] = [70, 170];
+pub const FRUIT_DURATION: f32 = 9.5;
+
+pub const READY_DURATION: f32 = 2.0;
+pub const DEATH_DURATION: f32 = 1.5;
+pub const LEVEL_CLEARED_DURATION: f32 = 2.0;
+pub const FRIGHTENED_FLASH_DURATION: f32 = 2.0;
+pub const BLINK_INTERVAL: f32 = 0.2;
+pub const CHOMP_SPEED: f32 = 12.0;
@@ -0,0 +1,719 @@
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::sprite::{Anchor, MaterialMesh2dBundle};
+
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Game, Renderable, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::high_scores::{HighScores, SubmitScoreEvent};
+use crate::music::DuckMusicEvent;
+use crate::scenes::pacman::components::*;
+use crate::scenes::pacman::constants::*;
+use crate::scenes::pacman::events::*;
+use crate::scenes::pacman::ghost::{GhostKind, GhostState};
+use crate::scenes::pacman::input::end_game_input;
+use crate::scenes::pacman::logic::collision_system;
+use crate::scenes::pacman::maze::{tile_to_world, Maze, Tile, LEFT, RIGHT};
+use crate::scenes::pacman::resources::{GameState, RoundState};
+
+pub struct EventHandlerPlugin;
+
+const PACMAN_RADIUS: f32 = 6.5;
+const GHOST_RADIUS: f32 = 7.0;
+const POWER_PELLET_RADIUS: f32 = 3.5;
+const FRUIT_RADIUS: f32 = 4.5;
+
+impl Plugin for EventHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            pellet_eaten_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(collision_system),
+        )
+        .add_system(
+            ghost_eaten_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(pellet_eaten_event_handler),
+        )
+        .add_system(
+            fruit_eaten_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(ghost_eaten_event_handler),
+        )
+        .add_system(
+            pacman_caught_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(fruit_eaten_event_handler),
+        )
+        .add_system(
+            level_cleared_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(pacman_caught_event_handler),
+        )
+        .add_system(
+            next_level_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(level_cleared_event_handler),
+        )
+        .add_system(
+            start_round_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(next_level_event_handler),
+        )
+        .add_system(
+            game_over_event_handler
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(collision_system)
+                .after(end_game_input),
+        )
+        .add_system(restart_game_event_handler.in_set(OnUpdate(AppState::PacMan)));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn pellet_eaten_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<PelletEatenEvent>,
+    mut game_state: ResMut<GameState>,
+    mut maze: ResMut<Maze>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    pellet_query: Query<(Entity, &PelletSprite)>,
+    mut ghost_query: Query<(&mut Ghost, &mut GridMover)>,
+    mut level_cleared_events: EventWriter<LevelClearedEvent>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+    viewport_size: Res<ViewportSize>,
+) {
+    for event in events.iter() {
+        if maze.eat(event.tile).is_none() {
+            continue;
+        }
+        play_sound_events.send(PlaySoundEvent(Sound::PacManPellet));
+        for (entity, pellet) in pellet_query.iter() {
+            if pellet.tile == event.tile {
+                commands.entity(entity).despawn();
+            }
+        }
+        game_state.pellets_eaten += 1;
+
+        if event.is_power_pellet {
+            game_state.add_score(POWER_PELLET_SCORE);
+            let duration = game_state.level_spec().frightened_duration;
+            game_state.frightened_timer = Timer::from_seconds(duration, TimerMode::Once);
+            game_state.ghosts_eaten = 0;
+            for (mut ghost, mut mover) in ghost_query.iter_mut() {
+                if ghost.state == GhostState::Active {
+                    mover.reverse();
+                }
+                if duration > 0.0
+                    && ghost.state != GhostState::Eyes
+                    && ghost.state != GhostState::EnteringHouse
+                {
+                    ghost.is_frightened = true;
+                }
+            }
+        } else {
+            game_state.add_score(PELLET_SCORE);
+        }
+
+        if FRUIT_PELLET_COUNTS.contains(&game_state.pellets_eaten) {
+            game_state.fruit_timer = Timer::from_seconds(FRUIT_DURATION, TimerMode::Once);
+            let color = game_state.level_spec().fruit.color();
+            let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+            let pos = tile_to_world(FRUIT_TILE.as_vec2() + Vec2::new(0.5, 0.0));
+            commands.spawn((
+                MaterialMesh2dBundle {
+                    mesh: meshes.add(shape::Circle::new(FRUIT_RADIUS).into()).into(),
+                    material: materials.add(ColorMaterial::from(color)),
+                    transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 2.0)
+                        .with_scale(Vec3::new(scale, scale, 1.0)),
+                    ..default()
+                },
+                Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT))
+                    .with_size(Vec2::splat(FRUIT_RADIUS * 2.0)),
+                Fruit,
+                PacManEntity,
+            ));
+        }
+
+        if maze.pellets_left == 0 {
+            level_cleared_events.send_default();
+        }
+    }
+}
+
+pub fn ghost_eaten_event_handler(
+    mut events: EventReader<GhostEatenEvent>,
+    mut game_state: ResMut<GameState>,
+    mut ghost_query: Query<&mut Ghost>,
+) {
+    for event in events.iter() {
+        let Ok(mut ghost) = ghost_query.get_mut(event.entity) else { continue; };
+        if ghost.state != GhostState::Active {
+            continue;
+        }
+        ghost.state = GhostState::Eyes;
+        ghost.is_frightened = false;
+        // 200, 400, 800, 1600 for the ghosts eaten with the same power pellet
+        let score = GHOST_BASE_SCORE * 2_i32.pow(game_state.ghosts_eaten.min(3));
+        game_state.ghosts_eaten += 1;
+        game_state.add_score(score);
+    }
+}
+
+pub fn fruit_eaten_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<FruitEatenEvent>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in events.iter() {
+        commands.entity(event.entity).despawn();
+        let score = game_state.level_spec().fruit.score();
+        game_state.add_score(score);
+    }
+}
+
+pub fn pacman_caught_event_handler(
+    mut events: EventReader<PacManCaughtEvent>,
+    mut game_state: ResMut<GameState>,
+    mut ghost_query: Query<&mut Visibility, With<Ghost>>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    if game_state.round_state != RoundState::Playing {
+        return;
+    }
+
+    game_state.set_round_state(RoundState::Dying, DEATH_DURATION);
+    play_sound_events.send(PlaySoundEvent(Sound::PacManDeath));
+    for mut visibility in ghost_query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+pub fn level_cleared_event_handler(
+    mut events: EventReader<LevelClearedEvent>,
+    mut game_state: ResMut<GameState>,
+    mut ghost_query: Query<&mut Visibility, With<Ghost>>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    game_state.set_round_state(RoundState::LevelCleared, LEVEL_CLEARED_DURATION);
+    for mut visibility in ghost_query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn next_level_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<NextLevelEvent>,
+    mut game_state: ResMut<GameState>,
+    mut maze: ResMut<Maze>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    fruit_query: Query<Entity, With<Fruit>>,
+    mut start_round_events: EventWriter<StartRoundEvent>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    game_state.level += 1;
+    game_state.pellets_eaten = 0;
+    maze.reset();
+    for entity in fruit_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    spawn_pellets(&mut commands, &maze, &mut meshes, &mut materials, scale);
+    start_round_events.send_default();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_round_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<StartRoundEvent>,
+    mut game_state: ResMut<GameState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    actor_query: Query<Entity, With<Actor>>,
+    mut ready_text_query: Query<&mut Visibility, With<ReadyText>>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    game_state.set_round_state(RoundState::Ready, READY_DURATION);
+    game_state.reset_ghost_mode();
+    for entity in actor_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    for mut visibility in ready_text_query.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let scale_vec = Vec3::new(scale, scale, 1.0);
+
+    // Characters start halfway between two tiles, one tile to the right moving left
+    let half_tile_left = |tile: IVec2| {
+        let mut mover = GridMover::new(tile + RIGHT, LEFT);
+        mover.progress = 0.5;
+        mover
+    };
+
+    // Spawn Pac-Man with a black wedge on top as the mouth
+    let pacman_mover = half_tile_left(PACMAN_START_TILE);
+    let pos = tile_to_world(pacman_mover.position());
+    commands
+        .spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(shape::Circle::new(PACMAN_RADIUS).into()).into(),
+                material: materials.add(ColorMaterial::from(Color::YELLOW)),
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 4.0)
+                    .with_scale(scale_vec),
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_size(Vec2::splat(PACMAN_RADIUS * 2.0)),
+            PacMan {
+                desired_dir: LEFT,
+                facing: LEFT,
+            },
+            pacman_mover,
+            Actor,
+            PacManEntity,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                MaterialMesh2dBundle {
+                    mesh: meshes.add(mouth_mesh(PACMAN_RADIUS + 1.0)).into(),
+                    material: materials.add(ColorMaterial::from(Color::BLACK)),
+                    transform: Transform::from_xyz(0.0, 0.0, 0.1)
+                        .with_scale(Vec3::new(1.0, 0.5, 1.0))
+                        .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
+                    ..default()
+                },
+                PacManMouth,
+            ));
+        });
+
+    // Spawn the ghosts, each with its own material so they can turn blue independently
+    let body_mesh = meshes.add(shape::Circle::new(GHOST_RADIUS).into());
+    let eye_mesh = meshes.add(shape::Circle::new(2.2).into());
+    let pupil_mesh = meshes.add(shape::Circle::new(1.2).into());
+    let eye_material = materials.add(ColorMaterial::from(Color::WHITE));
+    let pupil_material = materials.add(ColorMaterial::from(Color::MIDNIGHT_BLUE));
+    for (index, kind) in GhostKind::all().into_iter().enumerate() {
+        let mover = half_tile_left(kind.start_tile());
+        let pos = tile_to_world(mover.position());
+        commands
+            .spawn((
+                MaterialMesh2dBundle {
+                    mesh: body_mesh.clone().into(),
+                    material: materials.add(ColorMaterial::from(kind.color())),
+                    transform: Transform::from_xyz(
+                        pos.x * scale,
+                        pos.y * scale,
+                        3.0 + index as f32 * 0.1,
+                    )
+                    .with_scale(scale_vec),
+                    ..default()
+                },
+                Renderable::new(pos, target_resolution).with_size(Vec2::splat(GHOST_RADIUS * 2.0)),
+                Ghost {
+                    kind,
+                    state: kind.start_state(),
+                    is_frightened: false,
+                    release_timer: Timer::from_seconds(kind.release_delay(), TimerMode::Once),
+                },
+                mover,
+                Actor,
+                PacManEntity,
+            ))
+            .with_children(|parent| {
+                for offset in [Vec2::new(-2.5, 1.5), Vec2::new(2.5, 1.5)] {
+                    parent
+                        .spawn((
+                            MaterialMesh2dBundle {
+                                mesh: eye_mesh.clone().into(),
+                                material: eye_material.clone(),
+                                transform: Transform::from_xyz(offset.x, offset.y, 0.05),
+                                ..default()
+                            },
+                            GhostEye { offset },
+                        ))
+                        .with_children(|eye| {
+                            eye.spawn(MaterialMesh2dBundle {
+                                mesh: pupil_mesh.clone().into(),
+                                material: pupil_material.clone(),
+                                transform: Transform::from_xyz(0.0, 0.0, 0.05),
+                                ..default()
+                            });
+                        });
+                }
+            });
+    }
+}
+
+pub fn game_over_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<GameOverEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut submit_score_events: EventWriter<SubmitScoreEvent>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
+    game_state.has_game_ended = true;
+    submit_score_events.send(SubmitScoreEvent {
+        game: Game::PacMan,
+        score: game_state.score,
+        stats: vec![("Level".to_string(), game_state.level.to_string())],
+    });
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.85),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        EndGameUIElement,
+        PacManEntity,
+        Renderable::new(Vec2::new(0.0, 0.0), target_resolution).with_size(overlay_size),
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let game_over_pos = Vec2::new(0.0, 20.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "Game Over",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 100.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(game_over_pos.x * scale, game_over_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        PacManEntity,
+        Renderable::new(game_over_pos, target_resolution).with_scale(false, false),
+    ));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                format!("Score: {}\nLevel: {}", game_state.score, game_state.level),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(game_over_pos.x * scale, game_over_pos.y * scale, 11.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        PacManEntity,
+        Renderable::new(game_over_pos, target_resolution).with_scale(false, false),
+    ));
+
+    let buttons_pos = Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 6.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "[Space]: New Game\n[Esc]: Pause",
+                TextStyle {
+                    font,
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(buttons_pos.x * scale, buttons_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        PacManEntity,
+        Renderable::new(buttons_pos, target_resolution).with_scale(false, false),
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn restart_game_event_handler(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut restart_events: EventReader<RestartGameEvent>,
+    entities_to_clear: Query<Entity, With<PacManEntity>>,
+    mut game_state: ResMut<GameState>,
+    mut maze: ResMut<Maze>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut start_round_events: EventWriter<StartRoundEvent>,
+    viewport_size: Res<ViewportSize>,
+    high_scores: Res<HighScores>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if restart_events.is_empty() {
+        return;
+    }
+    restart_events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
+
+    game_state.reset();
+    game_state.high_score = game_state
+        .high_score
+        .max(high_scores.best(Game::PacMan).unwrap_or(0));
+    maze.reset();
+    for entity in entities_to_clear.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let scale_vec = Vec3::new(scale, scale, 1.0);
+
+    // Spawn walls and the ghost house door
+    for (tile, kind) in maze.tiles() {
+        let (color, size, offset) = match kind {
+            Tile::Wall => (Color::MIDNIGHT_BLUE, Vec2::splat(TILE_SIZE), 0.0),
+            Tile::Door => (Color::PINK, Vec2::new(TILE_SIZE, 2.0), -2.0),
+            _ => continue,
+        };
+        let pos = tile_to_world(tile.as_vec2()) + Vec2::new(0.0, offset);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 0.0)
+                    .with_scale(scale_vec),
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_size(size),
+            PacManEntity,
+        ));
+    }
+    // Cover both tunnel exits so characters disappear while wrapping around
+    for x in [-1, MAZE_WIDTH] {
+        let pos = tile_to_world(Vec2::new(x as f32, TUNNEL_ROW as f32));
+        let size = Vec2::new(TILE_SIZE * 3.0, TILE_SIZE * 3.0);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 5.0)
+                    .with_scale(scale_vec),
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_size(size),
+            PacManEntity,
+        ));
+    }
+    spawn_pellets(&mut commands, &maze, &mut meshes, &mut materials, scale);
+
+    // Spawn HUD
+    let font = asset_server.load(FONT_FILE.to_string());
+    let text_style = TextStyle {
+        font: font.clone(),
+        font_size: 22.0,
+        color: Color::WHITE,
+    };
+    let hud_top_y = PLAY_AREA_HEIGHT / 2.0 - 2.0;
+    let score_pos = Vec2::new(-PLAY_AREA_WIDTH / 2.0 + 2.0 * TILE_SIZE, hud_top_y);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_sections([
+                TextSection::new("1UP\n", text_style.clone()),
+                TextSection::new("0", text_style.clone()),
+            ]),
+            transform: Transform::from_xyz(score_pos.x * scale, score_pos.y * scale, 6.0),
+            text_anchor: Anchor::TopLeft,
+            ..default()
+        },
+        Renderable::new(score_pos, target_resolution).with_scale(false, false),
+        ScoreText,
+        PacManEntity,
+    ));
+    let high_score_pos = Vec2::new(0.0, hud_top_y);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_sections([
+                TextSection::new("HIGH SCORE\n", text_style.clone()),
+                TextSection::new(game_state.high_score.to_string(), text_style.clone()),
+            ])
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(high_score_pos.x * scale, high_score_pos.y * scale, 6.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        Renderable::new(high_score_pos, target_resolution).with_scale(false, false),
+        HighScoreText,
+        PacManEntity,
+    ));
+    let level_pos = Vec2::new(
+        PLAY_AREA_WIDTH / 2.0 - 2.0 * TILE_SIZE,
+        -PLAY_AREA_HEIGHT / 2.0 + HUD_BOTTOM_ROWS as f32 * TILE_SIZE / 2.0,
+    );
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section("LEVEL 1", text_style.clone()),
+            transform: Transform::from_xyz(level_pos.x * scale, level_pos.y * scale, 6.0),
+            text_anchor: Anchor::CenterRight,
+            ..default()
+        },
+        Renderable::new(level_pos, target_resolution).with_scale(false, false),
+        LevelText,
+        PacManEntity,
+    ));
+    let ready_pos = tile_to_world(FRUIT_TILE.as_vec2() + Vec2::new(0.5, 0.0));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "READY!",
+                TextStyle {
+                    font,
+                    font_size: 22.0,
+                    color: Color::YELLOW,
+                },
+            ),
+            transform: Transform::from_xyz(ready_pos.x * scale, ready_pos.y * scale, 6.0),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        Renderable::new(ready_pos, target_resolution).with_scale(false, false),
+        ReadyText,
+        PacManEntity,
+    ));
+
+    // Spawn reserve life icons, enough for the extra life on top of the starting ones
+    let life_mesh = meshes.add(shape::Circle::new(PACMAN_RADIUS).into());
+    let life_material = materials.add(ColorMaterial::from(Color::YELLOW));
+    for index in 0..PACMAN_START_LIVES {
+        let pos = Vec2::new(
+            -PLAY_AREA_WIDTH / 2.0 + (3.0 + index as f32 * 2.0) * TILE_SIZE,
+            -PLAY_AREA_HEIGHT / 2.0 + HUD_BOTTOM_ROWS as f32 * TILE_SIZE / 2.0,
+        );
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: life_mesh.clone().into(),
+                material: life_material.clone(),
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 6.0)
+                    .with_scale(scale_vec),
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_size(Vec2::splat(PACMAN_RADIUS * 2.0)),
+            LifeIcon { index },
+            PacManEntity,
+        ));
+    }
+
+    start_round_events.send_default();
+}
+
+fn spawn_pellets(
+    commands: &mut Commands,
+    maze: &Maze,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    scale: f32,
+) {
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale_vec = Vec3::new(scale, scale, 1.0);
+    let pellet_size = Vec2::splat(2.0);
+    let pellet_color = Color::rgb(1.0, 0.72, 0.68);
+    let power_pellet_mesh = meshes.add(shape::Circle::new(POWER_PELLET_RADIUS).into());
+    let power_pellet_material = materials.add(ColorMaterial::from(pellet_color));
+
+    for (tile, kind) in maze.tiles() {
+        let pos = tile_to_world(tile.as_vec2());
+        let transform =
+            Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0).with_scale(scale_vec);
+        match kind {
+            Tile::Pellet => {
+                commands.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: pellet_color,
+                            custom_size: Some(pellet_size),
+                            ..default()
+                        },
+                        transform,
+                        ..default()
+                    },
+                    Renderable::new(pos, target_resolution).with_size(pellet_size),
+                    PelletSprite { tile },
+                    PacManEntity,
+                ));
+            }
+            Tile::PowerPellet => {
+                commands.spawn((
+                    MaterialMesh2dBundle {
+                        mesh: power_pellet_mesh.clone().into(),
+                        material: power_pellet_material.clone(),
+                        transform,
+                        ..default()
+                    },
+                    Renderable::new(pos, target_resolution)
+                        .with_size(Vec2::splat(POWER_PELLET_RADIUS * 2.0)),
+                    PelletSprite { tile },
+                    PowerPelletSprite,
+                    PacManEntity,
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A wedge pointing right from the center, scaled vertically to open and close the mouth
+fn mouth_mesh(length: f32) -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_POSITION,
+        vec![
+            [0.0, 0.0, 0.0],
+            [length, -length, 0.0],
+            [length, length, 0.0],
+        ],
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 0.0, 1.0]; 3]);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; 3]);
+    mesh.set_indices(Some(Indices::U32(vec![0, 1, 2])));
+    mesh
+}
@@ -0,0 +1,52 @@
+use bevy::app::{App, Plugin};
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+
+pub struct EventsPlugin;
+
+impl Plugin for EventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PelletEatenEvent>()
+            .add_event::<GhostEatenEvent>()
+            .add_event::<FruitEatenEvent>()
+            .add_event::<PacManCaughtEvent>()
+            .add_event::<LevelClearedEvent>()
+            .add_event::<NextLevelEvent>()
+            .add_event::<StartRoundEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<RestartGameEvent>();
+    }
+}
+
+pub struct PelletEatenEvent {
+    pub tile: IVec2,
+    pub is_power_pellet: bool,
+}
+
+pub struct GhostEatenEvent {
+    pub entity: Entity,
+}
+
+pub struct FruitEatenEvent {
+    pub entity: Entity,
+}
+
+#[derive(Default)]
+pub struct PacManCaughtEvent;
+
+#[derive(Default)]
+pub struct LevelClearedEvent;
+
+/// Refills the maze once the "level cleared" pause is over
+#[derive(Default)]
+pub struct NextLevelEvent;
+
+/// Puts Pac-Man and the ghosts back to their starting positions
+#[derive(Default)]
+pub struct StartRoundEvent;
+
+#[derive(Default)]
+pub struct GameOverEvent;
+
+#[derive(Default)]
+pub struct RestartGameEvent;
@@ -0,0 +1,167 @@
+use bevy::math::IVec2;
+use bevy::prelude::Color;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::scenes::pacman::constants::{HOUSE_CENTER_TILE, HOUSE_EXIT_TILE};
+use crate::scenes::pacman::maze::{Maze, DIRECTIONS, LEFT, RIGHT, UP};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GhostKind {
+    Blinky,
+    Pinky,
+    Inky,
+    Clyde,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GhostState {
+    /// Waiting inside the ghost house for the release timer
+    InHouse,
+    LeavingHouse,
+    /// Roaming the maze, following the global scatter/chase mode
+    Active,
+    /// Eaten: only the eyes are left, heading back to the house
+    Eyes,
+    EnteringHouse,
+}
+
+impl GhostState {
+    pub fn can_use_door(self) -> bool {
+        matches!(
+            self,
+            GhostState::LeavingHouse | GhostState::Eyes | GhostState::EnteringHouse
+        )
+    }
+}
+
+impl GhostKind {
+    pub fn all() -> [GhostKind; 4] {
+        [
+            GhostKind::Blinky,
+            GhostKind::Pinky,
+            GhostKind::Inky,
+            GhostKind::Clyde,
+        ]
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            GhostKind::Blinky => Color::RED,
+            GhostKind::Pinky => Color::PINK,
+            GhostKind::Inky => Color::CYAN,
+            GhostKind::Clyde => Color::ORANGE,
+        }
+    }
+
+    pub fn start_tile(self) -> IVec2 {
+        match self {
+            GhostKind::Blinky => HOUSE_EXIT_TILE,
+            GhostKind::Pinky => HOUSE_CENTER_TILE,
+            GhostKind::Inky => HOUSE_CENTER_TILE + 2 * LEFT,
+            GhostKind::Clyde => HOUSE_CENTER_TILE + 2 * RIGHT,
+        }
+    }
+
+    pub fn start_state(self) -> GhostState {
+        match self {
+            GhostKind::Blinky => GhostState::Active,
+            _ => GhostState::InHouse,
+        }
+    }
+
+    /// Seconds after the round starts before the ghost leaves the house
+    pub fn release_delay(self) -> f32 {
+        match self {
+            GhostKind::Blinky | GhostKind::Pinky => 0.0,
+            GhostKind::Inky => 5.0,
+            GhostKind::Clyde => 10.0,
+        }
+    }
+
+    /// Each ghost retreats to its own corner during scatter, outside the maze so it circles it
+    pub fn scatter_target(self) -> IVec2 {
+        match self {
+            GhostKind::Blinky => IVec2::new(25, -4),
+            GhostKind::Pinky => IVec2::new(2, -4),
+            GhostKind::Inky => IVec2::new(27, 31),
+            GhostKind::Clyde => IVec2::new(0, 31),
+        }
+    }
+
+    pub fn chase_target(
+        self,
+        ghost_tile: IVec2,
+        pacman_tile: IVec2,
+        pacman_dir: IVec2,
+        blinky_tile: IVec2,
+    ) -> IVec2 {
+        match self {
+            // Blinky goes straight for Pac-Man
+            GhostKind::Blinky => pacman_tile,
+            // Pinky aims four tiles ahead of Pac-Man
+            GhostKind::Pinky => pacman_tile + ahead_of(pacman_dir, 4),
+            // Inky doubles the vector from Blinky to two tiles ahead of Pac-Man
+            GhostKind::Inky => {
+                let pivot = pacman_tile + ahead_of(pacman_dir, 2);
+                pivot + (pivot - blinky_tile)
+            }
+            // Clyde chases from afar but retreats to his corner when closer than eight tiles
+            GhostKind::Clyde => {
+                if ghost_tile.as_vec2().distance_squared(pacman_tile.as_vec2()) > 64.0 {
+                    pacman_tile
+                } else {
+                    self.scatter_target()
+                }
+            }
+        }
+    }
+}
+
+/// The tile offset in front of Pac-Man, keeping the arcade overflow bug that also shifts the
+/// target to the left when Pac-Man faces up
+fn ahead_of(dir: IVec2, tiles: i32) -> IVec2 {
+    if dir == UP {
+        (UP + LEFT) * tiles
+    } else {
+        dir * tiles
+    }
+}
+
+/// Ghosts never reverse on their own: at every tile they take the open direction closest to
+/// their target, breaking ties in the up, left, down, right order
+pub fn direction_towards(
+    maze: &Maze,
+    tile: IVec2,
+    current_dir: IVec2,
+    target: IVec2,
+    can_use_door: bool,
+) -> IVec2 {
+    candidate_directions(maze, tile, current_dir, can_use_door)
+        .min_by_key(|dir| {
+            let offset = tile + *dir - target;
+            offset.dot(offset)
+        })
+        .unwrap_or(-current_dir)
+}
+
+/// Frightened ghosts pick a random open direction at every tile
+pub fn random_direction(maze: &Maze, tile: IVec2, current_dir: IVec2) -> IVec2 {
+    let candidates: Vec<IVec2> = candidate_directions(maze, tile, current_dir, false).collect();
+    candidates
+        .choose(&mut thread_rng())
+        .copied()
+        .unwrap_or(-current_dir)
+}
+
+fn candidate_directions(
+    maze: &Maze,
+    tile: IVec2,
+    current_dir: IVec2,
+    can_use_door: bool,
+) -> impl Iterator<Item = IVec2> + '_ {
+    DIRECTIONS.into_iter().filter(move |dir| {
+        (current_dir == IVec2::ZERO || *dir != -current_dir)
+            && maze.is_walkable(tile + *dir, can_use_door)
+    })
+}
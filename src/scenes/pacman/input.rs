@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::actions::{Action, Actions};
+use crate::common::AppState;
+use crate::scenes::pacman::components::PacMan;
+use crate::scenes::pacman::events::RestartGameEvent;
+use crate::scenes::pacman::maze::{DOWN, LEFT, RIGHT, UP};
+use crate::scenes::pacman::resources::GameState;
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(pacman_input.in_set(OnUpdate(AppState::PacMan)))
+            .add_system(end_game_input.in_set(OnUpdate(AppState::PacMan)));
+    }
+}
+
+/// The last pressed direction is remembered, so a turn can be queued before reaching the corner
+pub fn pacman_input(actions: Res<Actions>, mut query: Query<&mut PacMan>) {
+    let desired_dir = if actions.just_pressed(Action::MoveUp) {
+        UP
+    } else if actions.just_pressed(Action::MoveLeft) {
+        LEFT
+    } else if actions.just_pressed(Action::MoveDown) {
+        DOWN
+    } else if actions.just_pressed(Action::MoveRight) {
+        RIGHT
+    } else { return; };
+
+    for mut pacman in query.iter_mut() {
+        pacman.desired_dir = desired_dir;
+    }
+}
+
+pub fn end_game_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+) {
+    if !game_state.has_game_ended {
+        return;
+    }
+
+    if actions.just_pressed(Action::Confirm) {
+        restart_events.send_default();
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::Color;
+
+/// Speeds are ratios of `BASE_SPEED`, durations are in seconds
+pub struct LevelSpec {
+    pub pacman_speed: f32,
+    pub pacman_frightened_speed: f32,
+    pub ghost_speed: f32,
+    pub ghost_frightened_speed: f32,
+    pub ghost_tunnel_speed: f32,
+    pub frightened_duration: f32,
+    pub fruit: FruitKind,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FruitKind {
+    Cherries,
+    Strawberry,
+    Peach,
+    Apple,
+    Grapes,
+    Galaxian,
+    Bell,
+    Key,
+}
+
+impl FruitKind {
+    pub fn score(self) -> i32 {
+        match self {
+            FruitKind::Cherries => 100,
+            FruitKind::Strawberry => 300,
+            FruitKind::Peach => 500,
+            FruitKind::Apple => 700,
+            FruitKind::Grapes => 1000,
+            FruitKind::Galaxian => 2000,
+            FruitKind::Bell => 3000,
+            FruitKind::Key => 5000,
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            FruitKind::Cherries => Color::RED,
+            FruitKind::Strawberry => Color::CRIMSON,
+            FruitKind::Peach => Color::ORANGE,
+            FruitKind::Apple => Color::ORANGE_RED,
+            FruitKind::Grapes => Color::LIME_GREEN,
+            FruitKind::Galaxian => Color::BLUE,
+            FruitKind::Bell => Color::GOLD,
+            FruitKind::Key => Color::SILVER,
+        }
+    }
+}
+
+static FRIGHTENED_DURATIONS: [f32; 19] = [
+    6.0, 5.0, 4.0, 3.0, 2.0, 5.0, 2.0, 2.0, 1.0, 5.0, 2.0, 1.0, 1.0, 3.0, 1.0, 1.0, 0.0, 1.0, 0.0,
+];
+
+pub fn level_spec(level: u32) -> LevelSpec {
+    let frightened_duration = FRIGHTENED_DURATIONS
+        .get(level as usize - 1)
+        .copied()
+        .unwrap_or(0.0);
+    let fruit = match level {
+        1 => FruitKind::Cherries,
+        2 => FruitKind::Strawberry,
+        3 | 4 => FruitKind::Peach,
+        5 | 6 => FruitKind::Apple,
+        7 | 8 => FruitKind::Grapes,
+        9 | 10 => FruitKind::Galaxian,
+        11 | 12 => FruitKind::Bell,
+        _ => FruitKind::Key,
+    };
+    match level {
+        1 => LevelSpec {
+            pacman_speed: 0.8,
+            pacman_frightened_speed: 0.9,
+            ghost_speed: 0.75,
+            ghost_frightened_speed: 0.5,
+            ghost_tunnel_speed: 0.4,
+            frightened_duration,
+            fruit,
+        },
+        2..=4 => LevelSpec {
+            pacman_speed: 0.9,
+            pacman_frightened_speed: 0.95,
+            ghost_speed: 0.85,
+            ghost_frightened_speed: 0.55,
+            ghost_tunnel_speed: 0.45,
+            frightened_duration,
+            fruit,
+        },
+        _ => LevelSpec {
+            pacman_speed: 1.0,
+            pacman_frightened_speed: 1.0,
+            ghost_speed: 0.95,
+            ghost_frightened_speed: 0.6,
+            ghost_tunnel_speed: 0.5,
+            frightened_duration,
+            fruit,
+        },
+    }
+}
+
+/// Alternating scatter and chase durations; the ghosts chase forever after the last one
+pub fn mode_schedule(level: u32) -> &'static [f32] {
+    match level {
+        1 => &[7.0, 20.0, 7.0, 20.0, 5.0, 20.0, 5.0],
+        2..=4 => &[7.0, 20.0, 7.0, 20.0, 5.0, 1033.0, 1.0 / 60.0],
+        _ => &[5.0, 20.0, 5.0, 20.0, 5.0, 1037.0, 1.0 / 60.0],
+    }
+}
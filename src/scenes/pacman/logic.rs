@@ -0,0 +1,450 @@
+use bevy::prelude::*;
+
+use crate::common::{AppState, Renderable};
+use crate::scenes::pacman::components::*;
+use crate::scenes::pacman::constants::*;
+use crate::scenes::pacman::events::*;
+use crate::scenes::pacman::ghost::{direction_towards, random_direction, GhostKind, GhostState};
+use crate::scenes::pacman::input::pacman_input;
+use crate::scenes::pacman::maze::{tile_to_world, Maze, LEFT};
+use crate::scenes::pacman::resources::{GameState, GhostMode, RoundState};
+
+pub struct LogicPlugin;
+
+const FRIGHTENED_COLOR: Color = Color::rgb(0.13, 0.13, 1.0);
+const FRIGHTENED_FLASH_COLOR: Color = Color::WHITE;
+
+impl Plugin for LogicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                round_state_system,
+                ghost_mode_system,
+                ghost_release_system,
+                move_pacman,
+                move_ghosts,
+                collision_system,
+                fruit_timer_system,
+            )
+                .chain()
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(pacman_input),
+        )
+        .add_systems(
+            (
+                actor_position_update,
+                pacman_mouth_animation,
+                ghost_appearance_update,
+                power_pellet_blink,
+                hud_update,
+            )
+                .in_set(OnUpdate(AppState::PacMan))
+                .after(fruit_timer_system),
+        );
+    }
+}
+
+fn round_state_system(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut ready_text_query: Query<&mut Visibility, With<ReadyText>>,
+    mut start_round_events: EventWriter<StartRoundEvent>,
+    mut next_level_events: EventWriter<NextLevelEvent>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if game_state.has_game_ended || game_state.round_state == RoundState::Playing {
+        return;
+    }
+
+    game_state.round_timer.tick(time.delta());
+    if !game_state.round_timer.just_finished() {
+        return;
+    }
+
+    match game_state.round_state {
+        RoundState::Ready => {
+            game_state.round_state = RoundState::Playing;
+            for mut visibility in ready_text_query.iter_mut() {
+                *visibility = Visibility::Hidden;
+            }
+        }
+        RoundState::Dying => {
+            game_state.lives -= 1;
+            if game_state.lives > 0 {
+                start_round_events.send_default();
+            } else {
+                game_over_events.send_default();
+            }
+        }
+        RoundState::LevelCleared => next_level_events.send_default(),
+        RoundState::Playing => {}
+    }
+}
+
+/// Alternates between scatter and chase, pausing the schedule while the ghosts are frightened
+fn ghost_mode_system(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut ghost_query: Query<(&mut Ghost, &mut GridMover)>,
+) {
+    if game_state.round_state != RoundState::Playing {
+        return;
+    }
+
+    if game_state.is_frightened() {
+        game_state.frightened_timer.tick(time.delta());
+        if game_state.frightened_timer.just_finished() {
+            for (mut ghost, _) in ghost_query.iter_mut() {
+                ghost.is_frightened = false;
+            }
+        }
+        return;
+    }
+
+    game_state.mode_timer.tick(time.delta());
+    if game_state.mode_timer.just_finished() && game_state.advance_ghost_mode() {
+        // ghosts turn around whenever the mode changes
+        for (ghost, mut mover) in ghost_query.iter_mut() {
+            if ghost.state == GhostState::Active {
+                mover.reverse();
+            }
+        }
+    }
+}
+
+fn ghost_release_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut ghost_query: Query<&mut Ghost>,
+) {
+    if game_state.round_state != RoundState::Playing {
+        return;
+    }
+
+    for mut ghost in ghost_query.iter_mut() {
+        if ghost.state != GhostState::InHouse {
+            continue;
+        }
+        ghost.release_timer.tick(time.delta());
+        if ghost.release_timer.finished() {
+            ghost.state = GhostState::LeavingHouse;
+        }
+    }
+}
+
+/// Moves along the grid, asking `choose_dir` for the way to go at every tile center
+fn advance(
+    mover: &mut GridMover,
+    maze: &Maze,
+    distance: f32,
+    mut choose_dir: impl FnMut(IVec2, IVec2) -> IVec2,
+) {
+    let mut remaining = distance;
+    loop {
+        if mover.dir == IVec2::ZERO {
+            mover.progress = 0.0;
+            mover.dir = choose_dir(mover.tile, IVec2::ZERO);
+            if mover.dir == IVec2::ZERO {
+                return;
+            }
+        }
+        let to_next_tile = 1.0 - mover.progress;
+        if remaining < to_next_tile {
+            mover.progress += remaining;
+            return;
+        }
+        remaining -= to_next_tile;
+        mover.tile = maze.wrap(mover.tile + mover.dir);
+        mover.progress = 0.0;
+        mover.dir = choose_dir(mover.tile, mover.dir);
+        if mover.dir == IVec2::ZERO {
+            return;
+        }
+    }
+}
+
+fn move_pacman(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    maze: Res<Maze>,
+    mut pacman_query: Query<(&mut PacMan, &mut GridMover)>,
+    mut pellet_events: EventWriter<PelletEatenEvent>,
+) {
+    if game_state.round_state != RoundState::Playing {
+        return;
+    }
+    let Ok((mut pacman, mut mover)) = pacman_query.get_single_mut() else { return; };
+
+    let spec = game_state.level_spec();
+    mover.speed = BASE_SPEED
+        * if game_state.is_frightened() {
+            spec.pacman_frightened_speed
+        } else {
+            spec.pacman_speed
+        };
+
+    let desired_dir = pacman.desired_dir;
+    if desired_dir != IVec2::ZERO && desired_dir == -mover.dir {
+        mover.reverse();
+    }
+    let distance = mover.speed * time.delta_seconds();
+    advance(&mut mover, &maze, distance, |tile, dir| {
+        if desired_dir != IVec2::ZERO && maze.is_walkable(tile + desired_dir, false) {
+            desired_dir
+        } else if dir != IVec2::ZERO && maze.is_walkable(tile + dir, false) {
+            dir
+        } else {
+            IVec2::ZERO
+        }
+    });
+    if mover.dir != IVec2::ZERO {
+        pacman.facing = mover.dir;
+    }
+
+    let tile = mover.current_tile();
+    if let Some(is_power_pellet) = maze.pellet_at(tile) {
+        pellet_events.send(PelletEatenEvent {
+            tile,
+            is_power_pellet,
+        });
+    }
+}
+
+fn move_ghosts(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    maze: Res<Maze>,
+    pacman_query: Query<(&PacMan, &GridMover), Without<Ghost>>,
+    mut ghost_query: Query<(&mut Ghost, &mut GridMover)>,
+) {
+    if game_state.round_state != RoundState::Playing {
+        return;
+    }
+    let Ok((pacman, pacman_mover)) = pacman_query.get_single() else { return; };
+    let pacman_tile = pacman_mover.current_tile();
+    let blinky_tile = ghost_query
+        .iter()
+        .find(|(ghost, _)| ghost.kind == GhostKind::Blinky)
+        .map_or(IVec2::ZERO, |(_, mover)| mover.current_tile());
+    let spec = game_state.level_spec();
+
+    for (mut ghost, mut mover) in ghost_query.iter_mut() {
+        if ghost.state == GhostState::InHouse {
+            continue;
+        }
+
+        let speed_ratio = match ghost.state {
+            GhostState::Eyes => EYES_SPEED_RATIO,
+            GhostState::LeavingHouse | GhostState::EnteringHouse => spec.ghost_frightened_speed,
+            _ if maze.is_tunnel(mover.current_tile()) => spec.ghost_tunnel_speed,
+            _ if ghost.is_frightened => spec.ghost_frightened_speed,
+            _ => spec.ghost_speed,
+        };
+        mover.speed = BASE_SPEED * speed_ratio;
+
+        let ghost_tile = mover.current_tile();
+        let target = match ghost.state {
+            GhostState::LeavingHouse | GhostState::Eyes => HOUSE_EXIT_TILE,
+            GhostState::EnteringHouse => HOUSE_CENTER_TILE,
+            _ => match game_state.ghost_mode {
+                GhostMode::Scatter => ghost.kind.scatter_target(),
+                GhostMode::Chase => {
+                    ghost
+                        .kind
+                        .chase_target(ghost_tile, pacman_tile, pacman.facing, blinky_tile)
+                }
+            },
+        };
+        let can_use_door = ghost.state.can_use_door();
+        let is_frightened = ghost.is_frightened && ghost.state == GhostState::Active;
+        let is_leaving_house = ghost.state == GhostState::LeavingHouse;
+
+        let distance = mover.speed * time.delta_seconds();
+        let mut reached_target = false;
+        advance(&mut mover, &maze, distance, |tile, dir| {
+            // the exit row is two tiles wide, either one counts as arriving at the door
+            if !reached_target
+                && tile.y == target.y
+                && (tile.x == target.x || tile.x == target.x + 1)
+            {
+                reached_target = true;
+                // freshly released ghosts always head left first
+                if is_leaving_house {
+                    return LEFT;
+                }
+            }
+            if is_frightened {
+                random_direction(&maze, tile, dir)
+            } else {
+                direction_towards(&maze, tile, dir, target, can_use_door)
+            }
+        });
+
+        if reached_target {
+            match ghost.state {
+                GhostState::LeavingHouse => ghost.state = GhostState::Active,
+                GhostState::Eyes => ghost.state = GhostState::EnteringHouse,
+                GhostState::EnteringHouse => ghost.state = GhostState::LeavingHouse,
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn collision_system(
+    game_state: Res<GameState>,
+    pacman_query: Query<&GridMover, With<PacMan>>,
+    ghost_query: Query<(Entity, &Ghost, &GridMover)>,
+    fruit_query: Query<Entity, With<Fruit>>,
+    mut ghost_eaten_events: EventWriter<GhostEatenEvent>,
+    mut fruit_eaten_events: EventWriter<FruitEatenEvent>,
+    mut caught_events: EventWriter<PacManCaughtEvent>,
+) {
+    if game_state.round_state != RoundState::Playing {
+        return;
+    }
+    let Ok(pacman_mover) = pacman_query.get_single() else { return; };
+    let pacman_pos = pacman_mover.position();
+
+    for (entity, ghost, mover) in ghost_query.iter() {
+        if ghost.state != GhostState::Active
+            || mover.position().distance(pacman_pos) > COLLISION_DISTANCE
+        {
+            continue;
+        }
+        if ghost.is_frightened {
+            ghost_eaten_events.send(GhostEatenEvent { entity });
+        } else {
+            caught_events.send_default();
+            return;
+        }
+    }
+
+    if pacman_pos.distance(FRUIT_TILE.as_vec2() + Vec2::new(0.5, 0.0)) <= COLLISION_DISTANCE {
+        for entity in fruit_query.iter() {
+            fruit_eaten_events.send(FruitEatenEvent { entity });
+        }
+    }
+}
+
+pub fn fruit_timer_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    fruit_query: Query<Entity, With<Fruit>>,
+) {
+    if fruit_query.is_empty() || game_state.round_state != RoundState::Playing {
+        return;
+    }
+
+    game_state.fruit_timer.tick(time.delta());
+    if game_state.fruit_timer.just_finished() {
+        for entity in fruit_query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn actor_position_update(mut query: Query<(&GridMover, &mut Renderable), Changed<GridMover>>) {
+    for (mover, mut renderable) in query.iter_mut() {
+        renderable.pos = tile_to_world(mover.position());
+    }
+}
+
+fn pacman_mouth_animation(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    pacman_query: Query<(&PacMan, &GridMover)>,
+    mut mouth_query: Query<&mut Transform, With<PacManMouth>>,
+) {
+    let Ok((pacman, mover)) = pacman_query.get_single() else { return; };
+    let is_moving = game_state.round_state == RoundState::Playing && mover.dir != IVec2::ZERO;
+
+    for mut transform in mouth_query.iter_mut() {
+        if is_moving {
+            let opening = (time.elapsed_seconds() * CHOMP_SPEED).sin().abs();
+            transform.scale = Vec3::new(1.0, opening, 1.0);
+        }
+        // screen y points up while the maze's y points down
+        let facing = pacman.facing.as_vec2() * Vec2::new(1.0, -1.0);
+        transform.rotation = Quat::from_rotation_z(facing.y.atan2(facing.x));
+    }
+}
+
+fn ghost_appearance_update(
+    game_state: Res<GameState>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    ghost_query: Query<(&Ghost, &GridMover, &Handle<ColorMaterial>, &Children)>,
+    mut eye_query: Query<(&GhostEye, &mut Transform)>,
+) {
+    let is_flashing = game_state.frightened_timer.remaining_secs() < FRIGHTENED_FLASH_DURATION
+        && (game_state.frightened_timer.remaining_secs() / BLINK_INTERVAL) as i32 % 2 == 0;
+
+    for (ghost, mover, material_handle, children) in ghost_query.iter() {
+        let Some(material) = materials.get_mut(material_handle) else { continue; };
+        material.color =
+            if ghost.state == GhostState::Eyes || ghost.state == GhostState::EnteringHouse {
+                Color::NONE
+            } else if ghost.is_frightened && is_flashing {
+                FRIGHTENED_FLASH_COLOR
+            } else if ghost.is_frightened {
+                FRIGHTENED_COLOR
+            } else {
+                ghost.kind.color()
+            };
+
+        let look_dir = mover.dir.as_vec2() * Vec2::new(1.0, -1.0);
+        for child in children.iter() {
+            if let Ok((eye, mut transform)) = eye_query.get_mut(*child) {
+                let pos = eye.offset + look_dir * 1.5;
+                transform.translation = Vec3::new(pos.x, pos.y, transform.translation.z);
+            }
+        }
+    }
+}
+
+fn power_pellet_blink(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut query: Query<&mut Visibility, With<PowerPelletSprite>>,
+) {
+    let is_visible = game_state.round_state != RoundState::Playing
+        || (time.elapsed_seconds() / BLINK_INTERVAL) as i32 % 2 == 0;
+    for mut visibility in query.iter_mut() {
+        *visibility = if is_visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn hud_update(
+    game_state: Res<GameState>,
+    mut score_query: Query<&mut Text, (With<ScoreText>, Without<HighScoreText>)>,
+    mut high_score_query: Query<&mut Text, (With<HighScoreText>, Without<LevelText>)>,
+    mut level_query: Query<&mut Text, (With<LevelText>, Without<ScoreText>)>,
+    mut life_query: Query<(&LifeIcon, &mut Visibility)>,
+) {
+    if !game_state.is_changed() {
+        return;
+    }
+
+    for mut text in score_query.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.score);
+    }
+    for mut text in high_score_query.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.high_score);
+    }
+    for mut text in level_query.iter_mut() {
+        text.sections[0].value = format!("LEVEL {}", game_state.level);
+    }
+    // the life in play is not shown
+    for (icon, mut visibility) in life_query.iter_mut() {
+        *visibility = if icon.index < game_state.lives - 1 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
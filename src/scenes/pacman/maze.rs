@@ -0,0 +1,169 @@
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::Resource;
+
+use crate::scenes::pacman::constants::*;
+
+/// The arcade maze: `#` wall, `.` pellet, `o` power pellet, `-` ghost house door
+static MAZE_LAYOUT: [&str; MAZE_HEIGHT as usize] = [
+    "############################",
+    "#............##............#",
+    "#.####.#####.##.#####.####.#",
+    "#o####.#####.##.#####.####o#",
+    "#.####.#####.##.#####.####.#",
+    "#..........................#",
+    "#.####.##.########.##.####.#",
+    "#.####.##.########.##.####.#",
+    "#......##....##....##......#",
+    "######.##### ## #####.######",
+    "     #.##### ## #####.#     ",
+    "     #.##          ##.#     ",
+    "     #.## ###--### ##.#     ",
+    "######.## #      # ##.######",
+    "      .   #      #   .      ",
+    "######.## #      # ##.######",
+    "     #.## ######## ##.#     ",
+    "     #.##          ##.#     ",
+    "     #.## ######## ##.#     ",
+    "######.## ######## ##.######",
+    "#............##............#",
+    "#.####.#####.##.#####.####.#",
+    "#.####.#####.##.#####.####.#",
+    "#o..##.......  .......##..o#",
+    "###.##.##.########.##.##.###",
+    "###.##.##.########.##.##.###",
+    "#......##....##....##......#",
+    "#.##########.##.##########.#",
+    "#.##########.##.##########.#",
+    "#..........................#",
+    "############################",
+];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Door,
+    Pellet,
+    PowerPellet,
+}
+
+pub const UP: IVec2 = IVec2::new(0, -1);
+pub const LEFT: IVec2 = IVec2::new(-1, 0);
+pub const DOWN: IVec2 = IVec2::new(0, 1);
+pub const RIGHT: IVec2 = IVec2::new(1, 0);
+/// Directions in the order the arcade ghosts prefer them when two paths are equally good
+pub const DIRECTIONS: [IVec2; 4] = [UP, LEFT, DOWN, RIGHT];
+
+#[derive(Resource)]
+pub struct Maze {
+    tiles: Vec<Tile>,
+    pub pellets_left: usize,
+}
+
+impl Default for Maze {
+    fn default() -> Self {
+        let tiles: Vec<Tile> = MAZE_LAYOUT
+            .iter()
+            .flat_map(|row| {
+                row.chars().map(|c| match c {
+                    '#' => Tile::Wall,
+                    '-' => Tile::Door,
+                    '.' => Tile::Pellet,
+                    'o' => Tile::PowerPellet,
+                    _ => Tile::Empty,
+                })
+            })
+            .collect();
+        let pellets_left = tiles
+            .iter()
+            .filter(|tile| matches!(tile, Tile::Pellet | Tile::PowerPellet))
+            .count();
+        Maze {
+            tiles,
+            pellets_left,
+        }
+    }
+}
+
+impl Maze {
+    pub fn reset(&mut self) {
+        *self = Maze::default();
+    }
+
+    /// Tiles outside the maze horizontally belong to the tunnel, everything else outside is wall
+    pub fn tile(&self, pos: IVec2) -> Tile {
+        if pos.y < 0 || pos.y >= MAZE_HEIGHT {
+            Tile::Wall
+        } else if pos.x < 0 || pos.x >= MAZE_WIDTH {
+            if pos.y == TUNNEL_ROW {
+                Tile::Empty
+            } else {
+                Tile::Wall
+            }
+        } else {
+            self.tiles[(pos.y * MAZE_WIDTH + pos.x) as usize]
+        }
+    }
+
+    pub fn is_walkable(&self, pos: IVec2, can_use_door: bool) -> bool {
+        match self.tile(pos) {
+            Tile::Wall => false,
+            Tile::Door => can_use_door,
+            _ => true,
+        }
+    }
+
+    pub fn is_tunnel(&self, pos: IVec2) -> bool {
+        pos.y == TUNNEL_ROW && (pos.x < TUNNEL_LENGTH || pos.x >= MAZE_WIDTH - TUNNEL_LENGTH)
+    }
+
+    /// Wraps a tile that left the maze through the tunnel to the other side; the tunnel
+    /// continues one tile past each edge so characters slide fully off screen before wrapping
+    pub fn wrap(&self, pos: IVec2) -> IVec2 {
+        if pos.x < -1 {
+            IVec2::new(MAZE_WIDTH, pos.y)
+        } else if pos.x > MAZE_WIDTH {
+            IVec2::new(-1, pos.y)
+        } else {
+            pos
+        }
+    }
+
+    /// Returns whether the pellet on the given tile is a power pellet, if there is one
+    pub fn pellet_at(&self, pos: IVec2) -> Option<bool> {
+        match self.tile(pos) {
+            Tile::Pellet => Some(false),
+            Tile::PowerPellet => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Removes the pellet on the given tile and returns what was eaten
+    pub fn eat(&mut self, pos: IVec2) -> Option<Tile> {
+        let tile = self.tile(pos);
+        if !matches!(tile, Tile::Pellet | Tile::PowerPellet) {
+            return None;
+        }
+        self.tiles[(pos.y * MAZE_WIDTH + pos.x) as usize] = Tile::Empty;
+        self.pellets_left -= 1;
+        Some(tile)
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = (IVec2, Tile)> + '_ {
+        self.tiles.iter().enumerate().map(|(index, tile)| {
+            let index = index as i32;
+            (IVec2::new(index % MAZE_WIDTH, index / MAZE_WIDTH), *tile)
+        })
+    }
+}
+
+/// Converts a position in tile units (y pointing down) to the center of it in the play area
+pub fn tile_to_world(pos: Vec2) -> Vec2 {
+    Vec2::new(
+        (pos.x - (MAZE_WIDTH as f32 - 1.0) / 2.0) * TILE_SIZE,
+        ((MAZE_HEIGHT + HUD_TOP_ROWS + HUD_BOTTOM_ROWS) as f32 / 2.0
+            - (pos.y + HUD_TOP_ROWS as f32)
+            - 0.5)
+            * TILE_SIZE,
+    )
+}
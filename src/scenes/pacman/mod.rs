@@ -0,0 +1,13 @@
+pub use pacman_scene_plugin::PacManScenePlugin;
+
+mod components;
+mod constants;
+mod event_handlers;
+mod events;
+mod ghost;
+mod input;
+mod levels;
+mod logic;
+mod maze;
+mod pacman_scene_plugin;
+mod resources;
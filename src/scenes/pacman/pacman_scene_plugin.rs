@@ -0,0 +1,34 @@
+use bevy::app::App;
+use bevy::prelude::*;
+
+use crate::common::AppState;
+use crate::scenes::pacman::components::PacManEntity;
+use crate::scenes::pacman::event_handlers::EventHandlerPlugin;
+use crate::scenes::pacman::events::{EventsPlugin, RestartGameEvent};
+use crate::scenes::pacman::input::InputPlugin;
+use crate::scenes::pacman::logic::LogicPlugin;
+use crate::scenes::pacman::resources::ResourcesPlugin;
+
+pub struct PacManScenePlugin;
+
+impl Plugin for PacManScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResourcesPlugin)
+            .add_plugin(EventsPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(EventHandlerPlugin)
+            .add_plugin(LogicPlugin)
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::PacMan)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::PacMan)));
+    }
+}
+
+fn setup_scene(mut restart_events: EventWriter<RestartGameEvent>) {
+    restart_events.send_default();
+}
+
+fn despawn_game(mut commands: Commands, entities: Query<Entity, With<PacManEntity>>) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use bevy::app::{App, Plugin};
+use bevy::prelude::{Resource, Timer, TimerMode};
+
+use crate::scenes::pacman::constants::*;
+use crate::scenes::pacman::levels::{level_spec, mode_schedule, LevelSpec};
+use crate::scenes::pacman::maze::Maze;
+
+pub struct ResourcesPlugin;
+
+impl Plugin for ResourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameState::default())
+            .insert_resource(Maze::default());
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GhostMode {
+    Scatter,
+    Chase,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RoundState {
+    /// Short pause with the "READY!" text before the characters start moving
+    Ready,
+    Playing,
+    Dying,
+    LevelCleared,
+}
+
+#[derive(Resource)]
+pub struct GameState {
+    pub score: i32,
+    pub high_score: i32,
+    pub lives: i32,
+    pub level: u32,
+    pub has_game_ended: bool,
+    pub round_state: RoundState,
+    pub round_timer: Timer,
+    pub ghost_mode: GhostMode,
+    pub mode_phase: usize,
+    pub mode_timer: Timer,
+    pub frightened_timer: Timer,
+    pub ghosts_eaten: u32,
+    pub pellets_eaten: usize,
+    pub fruit_timer: Timer,
+    pub extra_life_awarded: bool,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        let mut frightened_timer = Timer::from_seconds(0.0, TimerMode::Once);
+        frightened_timer.tick(Duration::ZERO);
+        GameState {
+            score: 0,
+            high_score: 0,
+            lives: PACMAN_START_LIVES,
+            level: 1,
+            has_game_ended: false,
+            round_state: RoundState::Ready,
+            round_timer: Timer::from_seconds(READY_DURATION, TimerMode::Once),
+            ghost_mode: GhostMode::Scatter,
+            mode_phase: 0,
+            mode_timer: Timer::from_seconds(mode_schedule(1)[0], TimerMode::Once),
+            frightened_timer,
+            ghosts_eaten: 0,
+            pellets_eaten: 0,
+            fruit_timer: Timer::from_seconds(FRUIT_DURATION, TimerMode::Once),
+            extra_life_awarded: false,
+        }
+    }
+}
+
+impl GameState {
+    pub fn reset(&mut self) {
+        let high_score = self.high_score;
+        *self = GameState::default();
+        self.high_score = high_score;
+    }
+
+    pub fn level_spec(&self) -> LevelSpec {
+        level_spec(self.level)
+    }
+
+    pub fn add_score(&mut self, score: i32) {
+        self.score += score;
+        self.high_score = self.high_score.max(self.score);
+        if !self.extra_life_awarded && self.score >= EXTRA_LIFE_SCORE {
+            self.extra_life_awarded = true;
+            self.lives += 1;
+        }
+    }
+
+    pub fn is_frightened(&self) -> bool {
+        !self.frightened_timer.finished()
+    }
+
+    pub fn set_round_state(&mut self, round_state: RoundState, duration: f32) {
+        self.round_state = round_state;
+        self.round_timer = Timer::from_seconds(duration, TimerMode::Once);
+    }
+
+    /// Restarts the scatter/chase schedule, used whenever a new round begins
+    pub fn reset_ghost_mode(&mut self) {
+        self.ghost_mode = GhostMode::Scatter;
+        self.mode_phase = 0;
+        self.mode_timer = Timer::from_seconds(mode_schedule(self.level)[0], TimerMode::Once);
+        self.frightened_timer = Timer::from_seconds(0.0, TimerMode::Once);
+        self.frightened_timer.tick(Duration::ZERO);
+    }
+
+    /// Moves on to the next scatter/chase phase; returns true if the mode changed
+    pub fn advance_ghost_mode(&mut self) -> bool {
+        let schedule = mode_schedule(self.level);
+        if self.mode_phase >= schedule.len() {
+            return false;
+        }
+        self.mode_phase += 1;
+        self.ghost_mode = if self.mode_phase.is_multiple_of(2) {
+            GhostMode::Scatter
+        } else {
+            GhostMode::Chase
+        };
+        let duration = schedule.get(self.mode_phase).copied().unwrap_or(f32::MAX);
+        self.mode_timer = Timer::from_seconds(duration, TimerMode::Once);
+        true
+    }
+}
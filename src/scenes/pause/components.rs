@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Root of the overlay, kept centered on the camera
+#[derive(Component)]
+pub struct PauseOverlay;
@@ -0,0 +1,5 @@
+pub use pause_scene_plugin::PauseScenePlugin;
+
+mod components;
+mod pause_scene_plugin;
+mod resources;
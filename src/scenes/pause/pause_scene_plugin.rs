@@ -0,0 +1,210 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::window::WindowFocused;
+
+use crate::actions::{Action, Actions};
+use crate::common::{AppState, Game, PauseState, Renderable, ViewportSize};
+use crate::constants::{FONT_FILE, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::scenes::pause::components::PauseOverlay;
+use crate::scenes::pause::resources::{PauseMenu, PauseMenuItem};
+
+pub struct PauseScenePlugin;
+
+const HIGHLIGHT_COLOR: Color = Color::YELLOW;
+const BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.7);
+const OVERLAY_Z: f32 = 50.0;
+const ITEM_HEIGHT: f32 = 60.0;
+
+impl Plugin for PauseScenePlugin {
+    fn build(&self, app: &mut App) {
+        // freezes every system of the games, and so the timers they tick
+        for game in Game::supported_games() {
+            app.configure_set(OnUpdate(game.app_state()).run_if(in_state(PauseState::Running)));
+        }
+
+        app.init_resource::<PauseMenu>()
+            .add_system(pause_input.run_if(in_state(PauseState::Running).and_then(is_in_game)))
+            .add_system(pause_on_focus_lost)
+            .add_system(reset_selection.in_schedule(OnEnter(PauseState::Running)))
+            .add_system(setup_overlay.in_schedule(OnEnter(PauseState::Paused)))
+            .add_system(despawn_overlay.in_schedule(OnExit(PauseState::Paused)))
+            .add_systems(
+                (
+                    pause_menu_input,
+                    redraw_overlay
+                        .run_if(
+                            resource_changed::<PauseMenu>()
+                                .or_else(resource_changed::<ViewportSize>()),
+                        )
+                        .after(pause_menu_input),
+                )
+                    .in_set(OnUpdate(PauseState::Paused)),
+            );
+    }
+}
+
+fn is_in_game(app_state: Res<State<AppState>>) -> bool {
+    app_state.0.game().is_some()
+}
+
+fn pause_input(actions: Res<Actions>, mut next_pause_state: ResMut<NextState<PauseState>>) {
+    if actions.just_pressed(Action::Pause) {
+        next_pause_state.set(PauseState::Paused);
+    }
+}
+
+fn pause_on_focus_lost(
+    mut focus_events: EventReader<WindowFocused>,
+    app_state: Res<State<AppState>>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    let has_lost_focus = focus_events.iter().any(|event| !event.focused);
+    if has_lost_focus && app_state.0.game().is_some() && pause_state.0 == PauseState::Running {
+        next_pause_state.set(PauseState::Paused);
+    }
+}
+
+/// Only once the game resumes, so that coming back from the settings keeps them selected
+fn reset_selection(mut menu: ResMut<PauseMenu>) {
+    menu.selected = 0;
+}
+
+fn setup_overlay(mut menu: ResMut<PauseMenu>) {
+    menu.set_changed();
+}
+
+fn despawn_overlay(mut commands: Commands, overlays: Query<Entity, With<PauseOverlay>>) {
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn_recursive();
+    }
+}
+
+fn pause_menu_input(
+    actions: Res<Actions>,
+    app_state: Res<State<AppState>>,
+    mut menu: ResMut<PauseMenu>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    if actions.just_pressed(Action::MoveUp) {
+        menu.move_selection(-1);
+    } else if actions.just_pressed(Action::MoveDown) {
+        menu.move_selection(1);
+    } else if actions.just_pressed(Action::Pause) || actions.just_pressed(Action::Back) {
+        next_pause_state.set(PauseState::Running);
+    } else if actions.just_pressed(Action::Confirm) {
+        match menu.selected_item() {
+            PauseMenuItem::Resume => {}
+            // entering the state again sets the scene up from scratch
+            PauseMenuItem::Restart => next_app_state.set(app_state.0.clone()),
+            PauseMenuItem::Settings => {
+                next_pause_state.set(PauseState::Settings);
+                return;
+            }
+            PauseMenuItem::Quit => next_app_state.set(AppState::Menu),
+        }
+        next_pause_state.set(PauseState::Running);
+    }
+}
+
+fn redraw_overlay(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    viewport_size: Res<ViewportSize>,
+    menu: Res<PauseMenu>,
+    overlays: Query<Entity, With<PauseOverlay>>,
+    camera_q: Query<&Transform, With<Camera>>,
+) {
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn_recursive();
+    }
+
+    // Asteroid's camera follows the ship around
+    let camera_pos = camera_q
+        .get_single()
+        .map(|transform| transform.translation.truncate())
+        .unwrap_or_default();
+    let font = assets.load(FONT_FILE.to_string());
+    let scale = viewport_size.height / WINDOW_HEIGHT;
+
+    commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(
+                camera_pos.x,
+                camera_pos.y,
+                OVERLAY_Z,
+            )),
+            PauseOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: BACKGROUND_COLOR,
+                    custom_size: Some(Vec2::new(viewport_size.width, viewport_size.height)),
+                    ..default()
+                },
+                ..default()
+            });
+            parent.spawn(text(
+                "Paused".to_string(),
+                font.clone(),
+                60.0,
+                Color::WHITE,
+                Vec2::new(0.0, 160.0),
+                scale,
+            ));
+            for (index, item) in PauseMenuItem::ALL.iter().enumerate() {
+                let is_selected = index == menu.selected;
+                let color = if is_selected {
+                    HIGHLIGHT_COLOR
+                } else {
+                    Color::WHITE
+                };
+                let prefix = if is_selected { "> " } else { "" };
+                parent.spawn(text(
+                    format!("{}{}", prefix, item.name()),
+                    font.clone(),
+                    36.0,
+                    color,
+                    Vec2::new(0.0, 40.0 - index as f32 * ITEM_HEIGHT),
+                    scale,
+                ));
+            }
+            parent.spawn(text(
+                "[Up/Down]: Select   [Enter]: Choose   [Esc]: Resume".to_string(),
+                font,
+                20.0,
+                Color::WHITE,
+                Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 + 30.0),
+                scale,
+            ));
+        });
+}
+
+fn text(
+    value: String,
+    font: Handle<Font>,
+    font_size: f32,
+    color: Color,
+    pos: Vec2,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT)).with_scale(false, false),
+    )
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::Resource;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PauseMenuItem {
+    Resume,
+    Restart,
+    Settings,
+    Quit,
+}
+
+impl PauseMenuItem {
+    pub const ALL: [PauseMenuItem; 4] = [
+        PauseMenuItem::Resume,
+        PauseMenuItem::Restart,
+        PauseMenuItem::Settings,
+        PauseMenuItem::Quit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PauseMenuItem::Resume => "Resume",
+            PauseMenuItem::Restart => "Restart",
+            PauseMenuItem::Settings => "Settings",
+            PauseMenuItem::Quit => "Quit to Menu",
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PauseMenu {
+    pub selected: usize,
+}
+
+impl PauseMenu {
+    pub fn move_selection(&mut self, delta: i32) {
+        let count = PauseMenuItem::ALL.len() as i32;
+        self.selected = (self.selected as i32 + delta).rem_euclid(count) as usize;
+    }
+
+    pub fn selected_item(&self) -> PauseMenuItem {
+        PauseMenuItem::ALL[self.selected]
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Root of the screen, kept centered on the camera to be drawn over a paused game
+#[derive(Component)]
+pub struct SettingsComponent;
@@ -0,0 +1,5 @@
+pub use settings_scene_plugin::SettingsScenePlugin;
+
+mod components;
+mod resources;
+mod settings_scene_plugin;
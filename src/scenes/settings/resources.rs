@@ -0,0 +1,99 @@
+use bevy::prelude::Resource;
+
+use crate::settings::{Settings, VOLUME_STEP};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SettingsItem {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    Fullscreen,
+    Vsync,
+    ShowFps,
+    ScreenShake,
+}
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 7] = [
+        SettingsItem::MasterVolume,
+        SettingsItem::MusicVolume,
+        SettingsItem::SfxVolume,
+        SettingsItem::Fullscreen,
+        SettingsItem::Vsync,
+        SettingsItem::ShowFps,
+        SettingsItem::ScreenShake,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SettingsItem::MasterVolume => "Master Volume",
+            SettingsItem::MusicVolume => "Music Volume",
+            SettingsItem::SfxVolume => "Sound Effects Volume",
+            SettingsItem::Fullscreen => "Fullscreen",
+            SettingsItem::Vsync => "Vsync",
+            SettingsItem::ShowFps => "Show FPS",
+            SettingsItem::ScreenShake => "Screen Shake",
+        }
+    }
+
+    pub fn value_label(self, settings: &Settings) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" }.to_string();
+        match self {
+            SettingsItem::MasterVolume => volume_label(settings.master_volume),
+            SettingsItem::MusicVolume => volume_label(settings.music_volume),
+            SettingsItem::SfxVolume => volume_label(settings.sfx_volume),
+            SettingsItem::Fullscreen => on_off(settings.is_fullscreen),
+            SettingsItem::Vsync => on_off(settings.is_vsync_enabled),
+            SettingsItem::ShowFps => on_off(settings.show_fps),
+            SettingsItem::ScreenShake => on_off(settings.is_screen_shake_enabled),
+        }
+    }
+
+    /// Steps volumes in the direction of `delta`, and flips toggles whatever the direction
+    pub fn change(self, settings: &mut Settings, delta: i32) {
+        match self {
+            SettingsItem::MasterVolume => step_volume(&mut settings.master_volume, delta),
+            SettingsItem::MusicVolume => step_volume(&mut settings.music_volume, delta),
+            SettingsItem::SfxVolume => step_volume(&mut settings.sfx_volume, delta),
+            SettingsItem::Fullscreen => settings.is_fullscreen = !settings.is_fullscreen,
+            SettingsItem::Vsync => settings.is_vsync_enabled = !settings.is_vsync_enabled,
+            SettingsItem::ShowFps => settings.show_fps = !settings.show_fps,
+            SettingsItem::ScreenShake => {
+                settings.is_screen_shake_enabled = !settings.is_screen_shake_enabled
+            }
+        }
+    }
+
+    pub fn is_toggle(self) -> bool {
+        !matches!(
+            self,
+            SettingsItem::MasterVolume | SettingsItem::MusicVolume | SettingsItem::SfxVolume
+        )
+    }
+}
+
+fn volume_label(volume: f32) -> String {
+    format!("{:.0}%", volume * 100.0)
+}
+
+/// Rounded to the step, so that repeated changes do not drift
+fn step_volume(volume: &mut f32, delta: i32) {
+    let steps = (*volume / VOLUME_STEP).round() as i32 + delta;
+    *volume = (steps as f32 * VOLUME_STEP).clamp(0.0, 1.0);
+}
+
+#[derive(Resource, Default)]
+pub struct SettingsScreen {
+    pub selected: usize,
+}
+
+impl SettingsScreen {
+    pub fn move_selection(&mut self, delta: i32) {
+        let count = SettingsItem::ALL.len() as i32;
+        self.selected = (self.selected as i32 + delta).rem_euclid(count) as usize;
+    }
+
+    pub fn selected_item(&self) -> SettingsItem {
+        SettingsItem::ALL[self.selected]
+    }
+}
@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::actions::{Action, Actions};
+use crate::common::{AppState, PauseState, Renderable, ViewportSize};
+use crate::constants::{FONT_FILE, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::scenes::settings::components::SettingsComponent;
+use crate::scenes::settings::resources::{SettingsItem, SettingsScreen};
+use crate::settings::Settings;
+
+pub struct SettingsScenePlugin;
+
+const HIGHLIGHT_COLOR: Color = Color::YELLOW;
+const BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.85);
+const SCREEN_Z: f32 = 60.0;
+const ROW_HEIGHT: f32 = 50.0;
+const NAME_COLUMN_X: f32 = -300.0;
+const VALUE_COLUMN_X: f32 = 300.0;
+
+impl Plugin for SettingsScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsScreen>()
+            .add_system(setup_screen.in_schedule(OnEnter(AppState::Settings)))
+            .add_system(setup_screen.in_schedule(OnEnter(PauseState::Settings)))
+            .add_system(despawn_screen.in_schedule(OnExit(AppState::Settings)))
+            .add_system(despawn_screen.in_schedule(OnExit(PauseState::Settings)))
+            .add_system(settings_input.run_if(is_open))
+            .add_system(
+                redraw_screen
+                    .run_if(is_open)
+                    .run_if(
+                        resource_changed::<SettingsScreen>()
+                            .or_else(resource_changed::<Settings>())
+                            .or_else(resource_changed::<ViewportSize>()),
+                    )
+                    .after(settings_input),
+            );
+    }
+}
+
+/// Opened from the menu, or from the pause overlay of a game
+fn is_open(app_state: Res<State<AppState>>, pause_state: Res<State<PauseState>>) -> bool {
+    app_state.0 == AppState::Settings || pause_state.0 == PauseState::Settings
+}
+
+fn setup_screen(mut screen: ResMut<SettingsScreen>) {
+    screen.selected = 0;
+}
+
+fn despawn_screen(mut commands: Commands, components: Query<Entity, With<SettingsComponent>>) {
+    for component in components.iter() {
+        commands.entity(component).despawn_recursive();
+    }
+}
+
+fn settings_input(
+    actions: Res<Actions>,
+    pause_state: Res<State<PauseState>>,
+    mut settings: ResMut<Settings>,
+    mut screen: ResMut<SettingsScreen>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    let item = screen.selected_item();
+    let delta = if actions.just_pressed(Action::MoveLeft) {
+        -1
+    } else if actions.just_pressed(Action::MoveRight)
+        || (actions.just_pressed(Action::Confirm) && item.is_toggle())
+    {
+        1
+    } else {
+        0
+    };
+    if delta != 0 {
+        item.change(&mut settings, delta);
+        settings.save();
+        return;
+    }
+
+    if actions.just_pressed(Action::MoveUp) {
+        screen.move_selection(-1);
+    } else if actions.just_pressed(Action::MoveDown) {
+        screen.move_selection(1);
+    } else if actions.just_pressed(Action::Back) {
+        if pause_state.0 == PauseState::Settings {
+            next_pause_state.set(PauseState::Paused);
+        } else {
+            next_app_state.set(AppState::Menu);
+        }
+    }
+}
+
+fn redraw_screen(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    viewport_size: Res<ViewportSize>,
+    settings: Res<Settings>,
+    screen: Res<SettingsScreen>,
+    components: Query<Entity, With<SettingsComponent>>,
+    camera_q: Query<&Transform, With<Camera>>,
+) {
+    for component in components.iter() {
+        commands.entity(component).despawn_recursive();
+    }
+
+    // Asteroid's camera follows the ship around
+    let camera_pos = camera_q
+        .get_single()
+        .map(|transform| transform.translation.truncate())
+        .unwrap_or_default();
+    let font = assets.load(FONT_FILE.to_string());
+    let scale = viewport_size.height / WINDOW_HEIGHT;
+
+    commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(
+                camera_pos.x,
+                camera_pos.y,
+                SCREEN_Z,
+            )),
+            SettingsComponent,
+        ))
+        .with_children(|parent| {
+            parent.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: BACKGROUND_COLOR,
+                    custom_size: Some(Vec2::new(viewport_size.width, viewport_size.height)),
+                    ..default()
+                },
+                ..default()
+            });
+            parent.spawn(text(
+                "Settings".to_string(),
+                font.clone(),
+                60.0,
+                Color::WHITE,
+                Vec2::new(0.0, WINDOW_HEIGHT / 2.0 - 20.0),
+                Anchor::TopCenter,
+                scale,
+            ));
+            for (row, item) in SettingsItem::ALL.iter().enumerate() {
+                let is_selected = row == screen.selected;
+                let color = if is_selected {
+                    HIGHLIGHT_COLOR
+                } else {
+                    Color::WHITE
+                };
+                let y = 200.0 - row as f32 * ROW_HEIGHT;
+                let prefix = if is_selected { "> " } else { "  " };
+                let value = if is_selected {
+                    format!("< {} >", item.value_label(&settings))
+                } else {
+                    item.value_label(&settings)
+                };
+                parent.spawn(text(
+                    format!("{}{}", prefix, item.name()),
+                    font.clone(),
+                    30.0,
+                    color,
+                    Vec2::new(NAME_COLUMN_X, y),
+                    Anchor::CenterLeft,
+                    scale,
+                ));
+                parent.spawn(text(
+                    value,
+                    font.clone(),
+                    30.0,
+                    color,
+                    Vec2::new(VALUE_COLUMN_X, y),
+                    Anchor::CenterRight,
+                    scale,
+                ));
+            }
+            parent.spawn(text(
+                "[Up/Down]: Select   [Left/Right]: Change   [Esc]: Back".to_string(),
+                font,
+                20.0,
+                Color::WHITE,
+                Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 + 10.0),
+                Anchor::BottomCenter,
+                scale,
+            ));
+        });
+}
+
+fn text(
+    value: String,
+    font: Handle<Font>,
+    font_size: f32,
+    color: Color,
+    pos: Vec2,
+    anchor: Anchor,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0),
+            text_anchor: anchor,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT)).with_scale(false, false),
+    )
+}
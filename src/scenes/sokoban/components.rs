@@ -0,0 +1,28 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct BoxSprite {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct PlayerSprite;
+
+#[derive(Component)]
+pub struct CounterText;
+
+#[derive(Component)]
+pub struct LevelSelectText;
+
+/// Everything spawned for the current level, cleared when another one is loaded
+#[derive(Component)]
+pub struct LevelEntity;
+
+#[derive(Component)]
+pub struct LevelSelectUIElement;
+
+#[derive(Component)]
+pub struct LevelCompleteUIElement;
+
+#[derive(Component)]
+pub struct SokobanEntity;
@@ -0,0 +1,27 @@
+use bevy::prelude::Color;
+
+pub const PLAY_AREA_WIDTH: f32 = 640.0;
+pub const PLAY_AREA_HEIGHT: f32 = 480.0;
+
+/// Folder under `assets/` holding the XSB level collections
+pub const LEVELS_FOLDER: &str = "levels/sokoban";
+pub const LEVEL_FILE_EXTENSION: &str = "xsb";
+
+pub const MAX_CELL_SIZE: f32 = 40.0;
+pub const BOARD_MAX_WIDTH: f32 = 600.0;
+pub const BOARD_MAX_HEIGHT: f32 = 380.0;
+pub const BOARD_OFFSET_Y: f32 = -10.0;
+
+/// Sizes relative to a board cell
+pub const GOAL_SIZE_RATIO: f32 = 0.4;
+pub const BOX_SIZE_RATIO: f32 = 0.8;
+pub const PLAYER_SIZE_RATIO: f32 = 0.6;
+
+pub const LEVEL_SELECT_VISIBLE_ROWS: usize = 12;
+
+pub const WALL_COLOR: Color = Color::rgb(0.45, 0.3, 0.2);
+pub const FLOOR_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
+pub const GOAL_COLOR: Color = Color::rgb(0.8, 0.2, 0.2);
+pub const BOX_COLOR: Color = Color::rgb(0.85, 0.55, 0.15);
+pub const BOX_ON_GOAL_COLOR: Color = Color::rgb(0.3, 0.75, 0.3);
+pub const PLAYER_COLOR: Color = Color::rgb(0.3, 0.7, 0.95);
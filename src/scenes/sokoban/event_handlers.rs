@@ -0,0 +1,404 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::scenes::sokoban::components::*;
+use crate::scenes::sokoban::constants::*;
+use crate::scenes::sokoban::events::*;
+use crate::scenes::sokoban::input::*;
+use crate::scenes::sokoban::resources::{Board, GameState, LevelCollection, Screen};
+
+pub struct EventHandlerPlugin;
+
+impl Plugin for EventHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                move_player_event_handler,
+                undo_event_handler,
+                redo_event_handler,
+                restart_level_event_handler,
+            )
+                .chain()
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(player_input),
+        )
+        .add_system(
+            level_completed_event_handler
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(restart_level_event_handler),
+        )
+        .add_system(
+            load_level_event_handler
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(level_completed_event_handler)
+                .after(level_select_input)
+                .after(level_complete_input),
+        )
+        .add_system(
+            show_level_select_event_handler
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(load_level_event_handler)
+                .after(player_input),
+        );
+    }
+}
+
+/// Everything drawn for a level or one of its overlays
+type ScreenEntitiesFilter = Or<(
+    With<LevelEntity>,
+    With<LevelSelectUIElement>,
+    With<LevelCompleteUIElement>,
+)>;
+
+fn tile_sprite(
+    color: Color,
+    pos: Vec2,
+    size: Vec2,
+    z: f32,
+    scale: f32,
+) -> (SpriteBundle, Renderable) {
+    (
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(size),
+                ..default()
+            },
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, z)
+                .with_scale(Vec3::new(scale, scale, 1.0)),
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_size(size),
+    )
+}
+
+fn text(
+    value: impl Into<String>,
+    font: Handle<Font>,
+    font_size: f32,
+    pos: Vec2,
+    anchor: Anchor,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 11.0),
+            text_anchor: anchor,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_scale(false, false),
+    )
+}
+
+pub fn move_player_event_handler(
+    mut events: EventReader<MovePlayerEvent>,
+    mut board: ResMut<Board>,
+) {
+    for event in events.iter() {
+        board.try_move(event.dir);
+    }
+}
+
+pub fn undo_event_handler(mut events: EventReader<UndoEvent>, mut board: ResMut<Board>) {
+    for _ in events.iter() {
+        board.undo();
+    }
+}
+
+pub fn redo_event_handler(mut events: EventReader<RedoEvent>, mut board: ResMut<Board>) {
+    for _ in events.iter() {
+        board.redo();
+    }
+}
+
+pub fn restart_level_event_handler(
+    mut events: EventReader<RestartLevelEvent>,
+    levels: Res<LevelCollection>,
+    mut board: ResMut<Board>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    let index = board.level_index;
+    board.load(index, &levels.0[index]);
+}
+
+pub fn level_completed_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<LevelCompletedEvent>,
+    asset_server: Res<AssetServer>,
+    levels: Res<LevelCollection>,
+    board: Res<Board>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    game_state.screen = Screen::LevelComplete;
+    game_state.solved.insert(board.level_index);
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.85),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        LevelCompleteUIElement,
+        SokobanEntity,
+        Renderable::new(Vec2::new(0.0, 0.0), target_resolution).with_size(overlay_size),
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let title_pos = Vec2::new(0.0, 40.0);
+    commands.spawn((
+        text(
+            "Level Complete",
+            font.clone(),
+            80.0,
+            title_pos,
+            Anchor::BottomCenter,
+            scale,
+        ),
+        LevelCompleteUIElement,
+        SokobanEntity,
+    ));
+    commands.spawn((
+        text(
+            format!(
+                "{}\nMoves: {}  Pushes: {}",
+                levels.0[board.level_index].name, board.moves, board.pushes
+            ),
+            font.clone(),
+            40.0,
+            title_pos,
+            Anchor::TopCenter,
+            scale,
+        ),
+        LevelCompleteUIElement,
+        SokobanEntity,
+    ));
+
+    let is_last_level = board.level_index + 1 >= levels.0.len();
+    let next_action = if is_last_level {
+        "Level Select"
+    } else {
+        "Next Level"
+    };
+    commands.spawn((
+        text(
+            format!("[Enter]: {}\n[L]: Level Select\n[Esc]: Pause", next_action),
+            font,
+            20.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        LevelCompleteUIElement,
+        SokobanEntity,
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn load_level_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<LoadLevelEvent>,
+    asset_server: Res<AssetServer>,
+    levels: Res<LevelCollection>,
+    mut board: ResMut<Board>,
+    mut game_state: ResMut<GameState>,
+    screen_entities_query: Query<Entity, ScreenEntitiesFilter>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let Some(index) = events.iter().last().map(|event| event.index) else { return; };
+
+    for entity in screen_entities_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    let level = &levels.0[index];
+    board.load(index, level);
+    game_state.screen = Screen::Playing;
+    game_state.selected = index;
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let cell_size = board.cell_size();
+    let cell = Vec2::splat(cell_size);
+
+    for pos in level.floor.iter() {
+        commands.spawn((
+            tile_sprite(FLOOR_COLOR, board.world_pos(*pos), cell, 0.0, scale),
+            LevelEntity,
+            SokobanEntity,
+        ));
+        if board.is_goal(*pos) {
+            commands.spawn((
+                tile_sprite(
+                    GOAL_COLOR,
+                    board.world_pos(*pos),
+                    cell * GOAL_SIZE_RATIO,
+                    0.5,
+                    scale,
+                ),
+                LevelEntity,
+                SokobanEntity,
+            ));
+        }
+    }
+    for pos in level.walls.iter() {
+        commands.spawn((
+            tile_sprite(WALL_COLOR, board.world_pos(*pos), cell, 0.0, scale),
+            LevelEntity,
+            SokobanEntity,
+        ));
+    }
+    for (box_index, pos) in board.boxes.iter().enumerate() {
+        commands.spawn((
+            tile_sprite(
+                BOX_COLOR,
+                board.world_pos(*pos),
+                cell * BOX_SIZE_RATIO,
+                1.0,
+                scale,
+            ),
+            BoxSprite { index: box_index },
+            LevelEntity,
+            SokobanEntity,
+        ));
+    }
+    commands.spawn((
+        tile_sprite(
+            PLAYER_COLOR,
+            board.world_pos(board.player),
+            cell * PLAYER_SIZE_RATIO,
+            1.0,
+            scale,
+        ),
+        PlayerSprite,
+        LevelEntity,
+        SokobanEntity,
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        text(
+            format!("{}: {}", index + 1, level.name),
+            font.clone(),
+            24.0,
+            Vec2::new(-PLAY_AREA_WIDTH / 2.0 + 10.0, PLAY_AREA_HEIGHT / 2.0 - 5.0),
+            Anchor::TopLeft,
+            scale,
+        ),
+        LevelEntity,
+        SokobanEntity,
+    ));
+    commands.spawn((
+        text(
+            "",
+            font.clone(),
+            24.0,
+            Vec2::new(PLAY_AREA_WIDTH / 2.0 - 10.0, PLAY_AREA_HEIGHT / 2.0 - 5.0),
+            Anchor::TopRight,
+            scale,
+        ),
+        CounterText,
+        LevelEntity,
+        SokobanEntity,
+    ));
+    commands.spawn((
+        text(
+            "[Arrows]: Move  [Z]: Undo  [Y]: Redo  [R]: Restart  [L]: Levels  [Esc]: Pause",
+            font,
+            16.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 5.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        LevelEntity,
+        SokobanEntity,
+    ));
+}
+
+pub fn show_level_select_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<ShowLevelSelectEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    screen_entities_query: Query<Entity, ScreenEntitiesFilter>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    for entity in screen_entities_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    game_state.screen = Screen::LevelSelect;
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        text(
+            "Select Level",
+            font.clone(),
+            48.0,
+            Vec2::new(0.0, PLAY_AREA_HEIGHT / 2.0 - 10.0),
+            Anchor::TopCenter,
+            scale,
+        ),
+        LevelSelectUIElement,
+        SokobanEntity,
+    ));
+    // the list itself is filled in by `level_select_update`
+    commands.spawn((
+        text(
+            "",
+            font.clone(),
+            24.0,
+            Vec2::new(
+                -PLAY_AREA_WIDTH / 2.0 + 120.0,
+                PLAY_AREA_HEIGHT / 2.0 - 80.0,
+            ),
+            Anchor::TopLeft,
+            scale,
+        ),
+        LevelSelectText,
+        LevelSelectUIElement,
+        SokobanEntity,
+    ));
+    commands.spawn((
+        text(
+            "[Up/Down]: Choose\n[Enter]: Play\n[Esc]: Pause",
+            font,
+            20.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        LevelSelectUIElement,
+        SokobanEntity,
+    ));
+}
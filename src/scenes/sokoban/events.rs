@@ -0,0 +1,39 @@
+use bevy::app::{App, Plugin};
+use bevy::math::IVec2;
+
+pub struct EventsPlugin;
+
+impl Plugin for EventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MovePlayerEvent>()
+            .add_event::<UndoEvent>()
+            .add_event::<RedoEvent>()
+            .add_event::<RestartLevelEvent>()
+            .add_event::<LevelCompletedEvent>()
+            .add_event::<LoadLevelEvent>()
+            .add_event::<ShowLevelSelectEvent>();
+    }
+}
+
+pub struct MovePlayerEvent {
+    pub dir: IVec2,
+}
+
+#[derive(Default)]
+pub struct UndoEvent;
+
+#[derive(Default)]
+pub struct RedoEvent;
+
+#[derive(Default)]
+pub struct RestartLevelEvent;
+
+#[derive(Default)]
+pub struct LevelCompletedEvent;
+
+pub struct LoadLevelEvent {
+    pub index: usize,
+}
+
+#[derive(Default)]
+pub struct ShowLevelSelectEvent;
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+
+use crate::actions::{Action, Actions};
+use crate::common::AppState;
+use crate::scenes::sokoban::events::*;
+use crate::scenes::sokoban::resources::{GameState, LevelCollection, Screen};
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(player_input.in_set(OnUpdate(AppState::Sokoban)))
+            .add_system(level_select_input.in_set(OnUpdate(AppState::Sokoban)))
+            .add_system(level_complete_input.in_set(OnUpdate(AppState::Sokoban)));
+    }
+}
+
+pub fn player_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut move_events: EventWriter<MovePlayerEvent>,
+    mut undo_events: EventWriter<UndoEvent>,
+    mut redo_events: EventWriter<RedoEvent>,
+    mut restart_events: EventWriter<RestartLevelEvent>,
+    mut show_level_select_events: EventWriter<ShowLevelSelectEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    // one step per press, like the original
+    let dir = if actions.just_pressed(Action::MoveUp) {
+        Some(IVec2::NEG_Y)
+    } else if actions.just_pressed(Action::MoveDown) {
+        Some(IVec2::Y)
+    } else if actions.just_pressed(Action::MoveLeft) {
+        Some(IVec2::NEG_X)
+    } else if actions.just_pressed(Action::MoveRight) {
+        Some(IVec2::X)
+    } else {
+        None
+    };
+    if let Some(dir) = dir {
+        move_events.send(MovePlayerEvent { dir });
+    }
+
+    if actions.just_pressed(Action::Undo) {
+        undo_events.send_default();
+    }
+    if actions.just_pressed(Action::Redo) {
+        redo_events.send_default();
+    }
+    if actions.just_pressed(Action::Restart) {
+        restart_events.send_default();
+    }
+    if actions.just_pressed(Action::LevelSelect) {
+        show_level_select_events.send_default();
+    }
+}
+
+pub fn level_select_input(
+    actions: Res<Actions>,
+    levels: Res<LevelCollection>,
+    mut game_state: ResMut<GameState>,
+    mut load_level_events: EventWriter<LoadLevelEvent>,
+) {
+    if game_state.screen != Screen::LevelSelect || levels.0.is_empty() {
+        return;
+    }
+
+    if actions.just_pressed(Action::MoveUp) && game_state.selected > 0 {
+        game_state.selected -= 1;
+    }
+    if actions.just_pressed(Action::MoveDown) && game_state.selected + 1 < levels.0.len() {
+        game_state.selected += 1;
+    }
+    if actions.just_pressed(Action::Confirm) {
+        load_level_events.send(LoadLevelEvent {
+            index: game_state.selected,
+        });
+    }
+}
+
+pub fn level_complete_input(
+    actions: Res<Actions>,
+    levels: Res<LevelCollection>,
+    game_state: Res<GameState>,
+    mut load_level_events: EventWriter<LoadLevelEvent>,
+    mut show_level_select_events: EventWriter<ShowLevelSelectEvent>,
+) {
+    if game_state.screen != Screen::LevelComplete {
+        return;
+    }
+
+    if actions.just_pressed(Action::Confirm) {
+        let next = game_state.selected + 1;
+        if next < levels.0.len() {
+            load_level_events.send(LoadLevelEvent { index: next });
+        } else {
+            show_level_select_events.send_default();
+        }
+    } else if actions.just_pressed(Action::LevelSelect) {
+        show_level_select_events.send_default();
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs;
+
+use bevy::math::IVec2;
+use bevy::prelude::warn;
+use bevy::utils::HashSet;
+
+use crate::scenes::sokoban::constants::{LEVELS_FOLDER, LEVEL_FILE_EXTENSION};
+
+/// A level as read from an XSB file
+#[derive(Clone, Debug, Default)]
+pub struct Level {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub walls: HashSet<IVec2>,
+    /// Tiles reachable from the start, the only ones drawn as floor
+    pub floor: HashSet<IVec2>,
+    pub goals: HashSet<IVec2>,
+    pub boxes: Vec<IVec2>,
+    pub player: IVec2,
+}
+
+/// Reads every `.xsb` file of the levels folder, in file name order, skipping the unreadable ones
+pub fn load_levels() -> Vec<Level> {
+    let Ok(entries) = fs::read_dir(format!("assets/{}", LEVELS_FOLDER)) else {
+        warn!("Could not read the levels folder assets/{}", LEVELS_FOLDER);
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == LEVEL_FILE_EXTENSION)
+        })
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| match fs::read_to_string(path) {
+            Ok(content) => Some(content),
+            Err(err) => {
+                warn!("Ignoring unreadable level file {:?}: {}", path, err);
+                None
+            }
+        })
+        .flat_map(|content| parse_xsb(&content))
+        .collect()
+}
+
+fn is_board_line(line: &str) -> bool {
+    line.contains('#')
+        && line
+            .chars()
+            .all(|c| matches!(c, '#' | '@' | '+' | '$' | '*' | '.' | ' ' | '-' | '_'))
+}
+
+/// Parses a collection in the standard XSB format. Levels are separated by lines that are not
+/// part of a board; the last `;` comment or `Title:` line before a board names it.
+pub fn parse_xsb(source: &str) -> Vec<Level> {
+    let mut levels = Vec::new();
+    let mut name: Option<String> = None;
+    let mut rows: Vec<&str> = Vec::new();
+    for line in source.lines().chain(std::iter::once("")) {
+        let line = line.trim_end();
+        if is_board_line(line) {
+            rows.push(line);
+            continue;
+        }
+        if !rows.is_empty() {
+            let level_name = name
+                .take()
+                .unwrap_or_else(|| format!("Level {}", levels.len() + 1));
+            if let Some(level) = parse_board(level_name, &rows) {
+                levels.push(level);
+            }
+            rows.clear();
+        }
+        if let Some(comment) = line.strip_prefix(';') {
+            name = Some(comment.trim().to_string());
+        } else if let Some(title) = line.strip_prefix("Title:") {
+            name = Some(title.trim().to_string());
+        }
+    }
+    levels
+}
+
+fn parse_board(name: String, rows: &[&str]) -> Option<Level> {
+    let mut level = Level {
+        name,
+        height: rows.len() as i32,
+        ..Default::default()
+    };
+    let mut player = None;
+    for (y, row) in rows.iter().enumerate() {
+        level.width = level.width.max(row.len() as i32);
+        for (x, c) in row.chars().enumerate() {
+            let pos = IVec2::new(x as i32, y as i32);
+            match c {
+                '#' => {
+                    level.walls.insert(pos);
+                }
+                '$' => level.boxes.push(pos),
+                '*' => {
+                    level.boxes.push(pos);
+                    level.goals.insert(pos);
+                }
+                '.' => {
+                    level.goals.insert(pos);
+                }
+                '@' => player = Some(pos),
+                '+' => {
+                    player = Some(pos);
+                    level.goals.insert(pos);
+                }
+                _ => {}
+            }
+        }
+    }
+    // a board without a player, or that can never be solved, is skipped
+    level.player = player?;
+    if level.boxes.is_empty() || level.boxes.len() != level.goals.len() {
+        return None;
+    }
+    level.floor = flood_fill(&level);
+    Some(level)
+}
+
+fn flood_fill(level: &Level) -> HashSet<IVec2> {
+    let mut floor = HashSet::new();
+    let mut stack = vec![level.player];
+    while let Some(pos) = stack.pop() {
+        let is_inside = (0..level.width).contains(&pos.x) && (0..level.height).contains(&pos.y);
+        if !is_inside || level.walls.contains(&pos) || !floor.insert(pos) {
+            continue;
+        }
+        stack.extend([IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y].map(|dir| pos + dir));
+    }
+    floor
+}
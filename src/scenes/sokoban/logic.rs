@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+
+use crate::common::{AppState, Renderable};
+use crate::scenes::sokoban::components::*;
+use crate::scenes::sokoban::constants::*;
+use crate::scenes::sokoban::event_handlers::{
+    load_level_event_handler, restart_level_event_handler, show_level_select_event_handler,
+};
+use crate::scenes::sokoban::events::LevelCompletedEvent;
+use crate::scenes::sokoban::resources::{Board, GameState, LevelCollection, Screen};
+
+pub struct LogicPlugin;
+
+impl Plugin for LogicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            level_completion
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(restart_level_event_handler)
+                .before(load_level_event_handler),
+        )
+        .add_system(
+            board_update
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(show_level_select_event_handler),
+        )
+        .add_system(
+            level_select_update
+                .in_set(OnUpdate(AppState::Sokoban))
+                .after(show_level_select_event_handler),
+        );
+    }
+}
+
+pub fn level_completion(
+    board: Res<Board>,
+    game_state: Res<GameState>,
+    mut level_completed_events: EventWriter<LevelCompletedEvent>,
+) {
+    if game_state.screen == Screen::Playing && board.is_changed() && board.is_solved() {
+        level_completed_events.send_default();
+    }
+}
+
+/// Moves the box and player sprites to where the board says they are
+#[allow(clippy::type_complexity)]
+pub fn board_update(
+    board: Res<Board>,
+    mut box_query: Query<(&BoxSprite, &mut Sprite, &mut Renderable), Without<PlayerSprite>>,
+    mut player_query: Query<&mut Renderable, (With<PlayerSprite>, Without<BoxSprite>)>,
+    mut counter_query: Query<&mut Text, With<CounterText>>,
+) {
+    for (box_sprite, mut sprite, mut renderable) in box_query.iter_mut() {
+        let pos = board.boxes[box_sprite.index];
+        renderable.pos = board.world_pos(pos);
+        sprite.color = if board.is_goal(pos) {
+            BOX_ON_GOAL_COLOR
+        } else {
+            BOX_COLOR
+        };
+    }
+    for mut renderable in player_query.iter_mut() {
+        renderable.pos = board.world_pos(board.player);
+    }
+    for mut text in counter_query.iter_mut() {
+        text.sections[0].value = format!("Moves: {}  Pushes: {}", board.moves, board.pushes);
+    }
+}
+
+/// Shows a window of the level list that follows the highlighted entry
+pub fn level_select_update(
+    levels: Res<LevelCollection>,
+    game_state: Res<GameState>,
+    mut text_query: Query<&mut Text, With<LevelSelectText>>,
+) {
+    let first = game_state
+        .selected
+        .saturating_sub(LEVEL_SELECT_VISIBLE_ROWS / 2)
+        .min(levels.0.len().saturating_sub(LEVEL_SELECT_VISIBLE_ROWS));
+    let rows: Vec<String> = levels
+        .0
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(LEVEL_SELECT_VISIBLE_ROWS)
+        .map(|(index, level)| {
+            let marker = if index == game_state.selected {
+                ">"
+            } else {
+                " "
+            };
+            let solved = if game_state.solved.contains(&index) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            format!("{} {} {:>2}. {}", marker, solved, index + 1, level.name)
+        })
+        .collect();
+    let value = if rows.is_empty() {
+        format!("No levels found in assets/{}", LEVELS_FOLDER)
+    } else {
+        rows.join("\n")
+    };
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = value.clone();
+    }
+}
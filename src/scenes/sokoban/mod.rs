@@ -0,0 +1,11 @@
+pub use sokoban_scene_plugin::SokobanScenePlugin;
+
+mod components;
+mod constants;
+mod event_handlers;
+mod events;
+mod input;
+mod level;
+mod logic;
+mod resources;
+mod sokoban_scene_plugin;
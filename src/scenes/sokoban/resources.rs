@@ -0,0 +1,166 @@
+use bevy::app::{App, Plugin};
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::Resource;
+use bevy::utils::HashSet;
+
+use crate::scenes::sokoban::constants::*;
+use crate::scenes::sokoban::level::{load_levels, Level};
+
+pub struct ResourcesPlugin;
+
+impl Plugin for ResourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LevelCollection::default())
+            .insert_resource(Board::default())
+            .insert_resource(GameState::default());
+    }
+}
+
+#[derive(Resource)]
+pub struct LevelCollection(pub Vec<Level>);
+
+impl Default for LevelCollection {
+    fn default() -> Self {
+        LevelCollection(load_levels())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Screen {
+    #[default]
+    LevelSelect,
+    Playing,
+    LevelComplete,
+}
+
+#[derive(Resource, Default)]
+pub struct GameState {
+    pub screen: Screen,
+    /// Highlighted entry of the level select list
+    pub selected: usize,
+    pub solved: HashSet<usize>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Move {
+    dir: IVec2,
+    pushed_box: Option<usize>,
+}
+
+/// The level being played, with its full move history for undo and redo
+#[derive(Resource, Default)]
+pub struct Board {
+    pub level_index: usize,
+    pub width: i32,
+    pub height: i32,
+    walls: HashSet<IVec2>,
+    goals: HashSet<IVec2>,
+    pub boxes: Vec<IVec2>,
+    pub player: IVec2,
+    pub moves: u32,
+    pub pushes: u32,
+    history: Vec<Move>,
+    redo_stack: Vec<Move>,
+}
+
+impl Board {
+    pub fn load(&mut self, level_index: usize, level: &Level) {
+        *self = Board {
+            level_index,
+            width: level.width,
+            height: level.height,
+            walls: level.walls.clone(),
+            goals: level.goals.clone(),
+            boxes: level.boxes.clone(),
+            player: level.player,
+            ..Default::default()
+        };
+    }
+
+    pub fn is_goal(&self, pos: IVec2) -> bool {
+        self.goals.contains(&pos)
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.boxes.iter().all(|pos| self.goals.contains(pos))
+    }
+
+    fn box_at(&self, pos: IVec2) -> Option<usize> {
+        self.boxes.iter().position(|box_pos| *box_pos == pos)
+    }
+
+    fn is_blocked(&self, pos: IVec2) -> bool {
+        self.walls.contains(&pos) || self.box_at(pos).is_some()
+    }
+
+    fn apply(&mut self, dir: IVec2) -> Option<Move> {
+        let target = self.player + dir;
+        if self.walls.contains(&target) {
+            return None;
+        }
+        let pushed_box = self.box_at(target);
+        if let Some(index) = pushed_box {
+            if self.is_blocked(target + dir) {
+                return None;
+            }
+            self.boxes[index] = target + dir;
+            self.pushes += 1;
+        }
+        self.player = target;
+        self.moves += 1;
+        Some(Move { dir, pushed_box })
+    }
+
+    /// Walks or pushes in the given direction; a new move discards the redo history
+    pub fn try_move(&mut self, dir: IVec2) -> bool {
+        let Some(applied) = self.apply(dir) else {
+            return false;
+        };
+        self.history.push(applied);
+        self.redo_stack.clear();
+        true
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(last) = self.history.pop() else {
+            return false;
+        };
+        if let Some(index) = last.pushed_box {
+            self.boxes[index] = self.player;
+            self.pushes -= 1;
+        }
+        self.player -= last.dir;
+        self.moves -= 1;
+        self.redo_stack.push(last);
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo_stack.pop() else {
+            return false;
+        };
+        match self.apply(next.dir) {
+            Some(applied) => {
+                self.history.push(applied);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cell size fitting the whole level in the play area
+    pub fn cell_size(&self) -> f32 {
+        MAX_CELL_SIZE
+            .min(BOARD_MAX_WIDTH / self.width as f32)
+            .min(BOARD_MAX_HEIGHT / self.height as f32)
+    }
+
+    /// Center of a cell in play area units; board rows go down while y goes up
+    pub fn world_pos(&self, pos: IVec2) -> Vec2 {
+        let cell_size = self.cell_size();
+        Vec2::new(
+            (pos.x as f32 - (self.width - 1) as f32 / 2.0) * cell_size,
+            -(pos.y as f32 - (self.height - 1) as f32 / 2.0) * cell_size + BOARD_OFFSET_Y,
+        )
+    }
+}
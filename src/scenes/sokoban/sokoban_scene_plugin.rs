@@ -0,0 +1,34 @@
+use bevy::app::App;
+use bevy::prelude::*;
+
+use crate::common::AppState;
+use crate::scenes::sokoban::components::SokobanEntity;
+use crate::scenes::sokoban::event_handlers::EventHandlerPlugin;
+use crate::scenes::sokoban::events::{EventsPlugin, ShowLevelSelectEvent};
+use crate::scenes::sokoban::input::InputPlugin;
+use crate::scenes::sokoban::logic::LogicPlugin;
+use crate::scenes::sokoban::resources::ResourcesPlugin;
+
+pub struct SokobanScenePlugin;
+
+impl Plugin for SokobanScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResourcesPlugin)
+            .add_plugin(EventsPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(EventHandlerPlugin)
+            .add_plugin(LogicPlugin)
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::Sokoban)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::Sokoban)));
+    }
+}
+
+fn setup_scene(mut show_level_select_events: EventWriter<ShowLevelSelectEvent>) {
+    show_level_select_events.send_default();
+}
+
+fn despawn_game(mut commands: Commands, entities: Query<Entity, With<SokobanEntity>>) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct Player;
+
+#[derive(Component)]
+pub struct PlayerShot;
+
+#[derive(Component)]
+pub struct Invader {
+    pub column: i32,
+    pub score: i32,
+}
+
+#[derive(Component)]
+pub struct Bomb;
+
+#[derive(Component)]
+pub struct BunkerCell;
+
+#[derive(Component)]
+pub struct Ufo {
+    /// Signed horizontal speed, the mystery ship crosses from either side
+    pub speed: f32,
+    pub score: i32,
+}
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct LivesText;
+
+#[derive(Component)]
+pub struct WaveText;
+
+#[derive(Component)]
+pub struct EndGameUIElement;
+
+#[derive(Component)]
+pub struct SpaceInvadersEntity;
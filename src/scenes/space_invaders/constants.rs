@@ -0,0 +1,83 @@
+pub const PLAY_AREA_WIDTH: f32 = 640.0;
+pub const PLAY_AREA_HEIGHT: f32 = 480.0;
+
+pub const PLAYER_WIDTH: f32 = 40.0;
+pub const PLAYER_HEIGHT: f32 = 30.0;
+pub const PLAYER_POS_Y: f32 = -205.0;
+pub const PLAYER_SPEED: f32 = 220.0;
+pub const PLAYER_START_LIVES: i32 = 3;
+pub const PLAYER_RESPAWN_DELAY: f32 = 1.5;
+pub const PLAYER_SPRITE: &str = "playerShip1_green.png";
+
+pub const SHOT_WIDTH: f32 = 4.0;
+pub const SHOT_HEIGHT: f32 = 16.0;
+pub const SHOT_SPEED: f32 = 450.0;
+pub const SHOT_SPRITE: &str = "Lasers/laserBlue01.png";
+
+pub const INVADER_COLUMNS: i32 = 11;
+pub const INVADER_ROWS: i32 = 5;
+pub const INVADER_WIDTH: f32 = 30.0;
+pub const INVADER_HEIGHT: f32 = 24.0;
+pub const INVADER_SPACING_X: f32 = 42.0;
+pub const INVADER_SPACING_Y: f32 = 32.0;
+/// Sprite and score of every formation row, from the top one down
+pub static INVADER_ROWS_SPEC: [(&str, i32); INVADER_ROWS as usize] = [
+    ("Enemies/enemyRed1.png", 30),
+    ("Enemies/enemyBlue2.png", 20),
+    ("Enemies/enemyBlue2.png", 20),
+    ("Enemies/enemyGreen3.png", 10),
+    ("Enemies/enemyGreen3.png", 10),
+];
+
+pub const FORMATION_TOP_Y: f32 = 150.0;
+pub const FORMATION_STEP_X: f32 = 8.0;
+pub const FORMATION_STEP_DOWN: f32 = 14.0;
+/// Later waves start this many steps lower, up to `FORMATION_MAX_WAVE_DROPS`
+pub const FORMATION_MAX_WAVE_DROPS: u32 = 5;
+pub const FORMATION_MAX_STEP_INTERVAL: f32 = 0.8;
+pub const FORMATION_MIN_STEP_INTERVAL: f32 = 0.02;
+/// Tempo of the music once a single invader is left, rising as the formation thins
+pub const MAX_MUSIC_TEMPO: f32 = 1.6;
+pub const NEXT_WAVE_DELAY: f32 = 1.5;
+
+pub const BOMB_WIDTH: f32 = 4.0;
+pub const BOMB_HEIGHT: f32 = 14.0;
+pub const BOMB_SPEED: f32 = 180.0;
+pub const BOMB_INTERVAL: f32 = 1.0;
+pub const BOMB_INTERVAL_DECREASE_PER_WAVE: f32 = 0.1;
+pub const BOMB_MIN_INTERVAL: f32 = 0.4;
+pub const MAX_BOMBS: usize = 3;
+pub const BOMB_SPRITE: &str = "Lasers/laserRed01.png";
+
+pub const BUNKER_COUNT: i32 = 4;
+pub const BUNKER_POS_Y: f32 = -140.0;
+pub const BUNKER_CELL_SIZE: f32 = 4.0;
+/// How much of a bunker a shot or a bomb blows away
+pub const BUNKER_DAMAGE_RADIUS: f32 = 5.0;
+pub static BUNKER_SHAPE: [&str; 12] = [
+    "    ########    ",
+    "   ##########   ",
+    "  ############  ",
+    " ############## ",
+    "################",
+    "################",
+    "################",
+    "################",
+    "################",
+    "#####      #####",
+    "####        ####",
+    "####        ####",
+];
+
+pub const UFO_SIZE: f32 = 32.0;
+pub const UFO_POS_Y: f32 = 195.0;
+pub const UFO_SPEED: f32 = 120.0;
+pub const UFO_MIN_INTERVAL: f32 = 15.0;
+pub const UFO_MAX_INTERVAL: f32 = 25.0;
+/// Mystery ship variants with the score each one is worth
+pub static UFO_SPRITES: [(&str, i32); 4] = [
+    ("ufoBlue.png", 50),
+    ("ufoGreen.png", 100),
+    ("ufoYellow.png", 150),
+    ("ufoRed.png", 300),
+];
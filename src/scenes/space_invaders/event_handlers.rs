@@ -0,0 +1,563 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::utils::HashSet;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Game, Renderable, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::high_scores::SubmitScoreEvent;
+use crate::music::{DuckMusicEvent, SetMusicTempoEvent};
+use crate::scenes::space_invaders::components::*;
+use crate::scenes::space_invaders::constants::*;
+use crate::scenes::space_invaders::events::*;
+use crate::scenes::space_invaders::input::end_game_input;
+use crate::scenes::space_invaders::logic::wave_progression;
+use crate::scenes::space_invaders::resources::{Formation, GameState, SpaceShooterSpriteSheet};
+
+pub struct EventHandlerPlugin;
+
+impl Plugin for EventHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                fire_shot_event_handler,
+                drop_bomb_event_handler,
+                spawn_ufo_event_handler,
+                invader_destroyed_event_handler,
+                ufo_destroyed_event_handler,
+                bunker_hit_event_handler,
+                player_hit_event_handler,
+            )
+                .in_set(OnUpdate(AppState::SpaceInvaders))
+                .after(wave_progression),
+        )
+        .add_system(
+            game_over_event_handler
+                .in_set(OnUpdate(AppState::SpaceInvaders))
+                .after(player_hit_event_handler)
+                .after(end_game_input),
+        )
+        .add_system(
+            restart_game_event_handler
+                .in_set(OnUpdate(AppState::SpaceInvaders))
+                .after(game_over_event_handler),
+        )
+        .add_system(
+            start_wave_event_handler
+                .in_set(OnUpdate(AppState::SpaceInvaders))
+                .after(restart_game_event_handler),
+        );
+    }
+}
+
+/// Sprite from the space shooter sheet stretched to `size`, positioned in play area units
+fn sheet_sprite(
+    sprite_sheet: &SpaceShooterSpriteSheet,
+    sprite_name: &str,
+    pos: Vec2,
+    size: Vec2,
+    z: f32,
+    scale: f32,
+) -> (SpriteSheetBundle, Renderable) {
+    (
+        SpriteSheetBundle {
+            texture_atlas: sprite_sheet.atlas_handle().unwrap(),
+            sprite: TextureAtlasSprite {
+                index: sprite_sheet.index_of(sprite_name).unwrap(),
+                custom_size: Some(size),
+                ..default()
+            },
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, z)
+                .with_scale(Vec3::new(scale, scale, 1.0)),
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_size(size),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn fire_shot_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<FireShotEvent>,
+    game_state: Res<GameState>,
+    sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    player_query: Query<&Renderable, With<Player>>,
+    shot_query: Query<(), With<PlayerShot>>,
+    viewport_size: Res<ViewportSize>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    // only one shot on screen at a time, like the original
+    if !game_state.is_player_alive || !shot_query.is_empty() {
+        return;
+    }
+    let Ok(player) = player_query.get_single() else { return; };
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let pos = Vec2::new(player.pos.x, player.top() + SHOT_HEIGHT / 2.0);
+    commands.spawn((
+        sheet_sprite(
+            &sprite_sheet,
+            SHOT_SPRITE,
+            pos,
+            Vec2::new(SHOT_WIDTH, SHOT_HEIGHT),
+            1.0,
+            scale,
+        ),
+        PlayerShot,
+        SpaceInvadersEntity,
+    ));
+    play_sound_events.send(PlaySoundEvent(Sound::SpaceInvadersShot));
+}
+
+pub fn drop_bomb_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<DropBombEvent>,
+    sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for event in events.iter() {
+        commands.spawn((
+            sheet_sprite(
+                &sprite_sheet,
+                BOMB_SPRITE,
+                event.pos,
+                Vec2::new(BOMB_WIDTH, BOMB_HEIGHT),
+                1.0,
+                scale,
+            ),
+            Bomb,
+            SpaceInvadersEntity,
+        ));
+    }
+}
+
+pub fn spawn_ufo_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<SpawnUfoEvent>,
+    mut game_state: ResMut<GameState>,
+    sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    game_state.reset_ufo_timer();
+
+    let (sprite_name, score) = *UFO_SPRITES.choose(&mut thread_rng()).unwrap();
+    let dir = if thread_rng().gen_bool(0.5) {
+        1.0
+    } else {
+        -1.0
+    };
+    let pos = Vec2::new(-dir * (PLAY_AREA_WIDTH + UFO_SIZE) / 2.0, UFO_POS_Y);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    commands.spawn((
+        sheet_sprite(
+            &sprite_sheet,
+            sprite_name,
+            pos,
+            Vec2::splat(UFO_SIZE),
+            1.0,
+            scale,
+        ),
+        Ufo {
+            speed: dir * UFO_SPEED,
+            score,
+        },
+        SpaceInvadersEntity,
+    ));
+}
+
+pub fn invader_destroyed_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<InvaderDestroyedEvent>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in events.iter() {
+        commands.entity(event.entity).despawn();
+        game_state.score += event.score;
+    }
+}
+
+pub fn ufo_destroyed_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<UfoDestroyedEvent>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in events.iter() {
+        commands.entity(event.entity).despawn();
+        game_state.score += event.score;
+    }
+}
+
+pub fn bunker_hit_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<BunkerHitEvent>,
+    bunker_query: Query<(Entity, &Renderable), With<BunkerCell>>,
+) {
+    let mut destroyed = HashSet::new();
+    for event in events.iter() {
+        for (entity, cell) in bunker_query.iter() {
+            let distance = cell.pos.distance(event.pos);
+            // the blast leaves a ragged hole rather than a clean circle
+            let is_destroyed = distance <= event.radius
+                || (distance <= event.radius * 2.0 && thread_rng().gen_bool(0.5));
+            if is_destroyed {
+                destroyed.insert(entity);
+            }
+        }
+    }
+    for entity in destroyed {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn player_hit_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<PlayerHitEvent>,
+    mut game_state: ResMut<GameState>,
+    mut player_query: Query<&mut Visibility, With<Player>>,
+    bomb_query: Query<Entity, With<Bomb>>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    if !game_state.is_player_alive {
+        return;
+    }
+
+    game_state.lives -= 1;
+    game_state.is_player_alive = false;
+    game_state.respawn_timer.reset();
+    for mut visibility in player_query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+    for entity in bomb_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    if game_state.lives <= 0 {
+        game_over_events.send_default();
+    }
+}
+
+/// Projectiles and the mystery ship left over from the previous wave
+type WaveLeftoversFilter = Or<(With<PlayerShot>, With<Bomb>, With<Ufo>)>;
+
+pub fn start_wave_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<StartWaveEvent>,
+    mut game_state: ResMut<GameState>,
+    mut formation: ResMut<Formation>,
+    sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    leftovers_query: Query<Entity, WaveLeftoversFilter>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    for entity in leftovers_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    start_wave(
+        &mut commands,
+        &mut game_state,
+        &mut formation,
+        &sprite_sheet,
+        scale,
+    );
+}
+
+fn start_wave(
+    commands: &mut Commands,
+    game_state: &mut GameState,
+    formation: &mut Formation,
+    sprite_sheet: &SpaceShooterSpriteSheet,
+    scale: f32,
+) {
+    formation.reset();
+    game_state.next_wave_timer.reset();
+    game_state.bomb_timer = Timer::from_seconds(game_state.bomb_interval(), TimerMode::Repeating);
+
+    // every wave starts a little lower than the previous one
+    let drops = (game_state.wave - 1).min(FORMATION_MAX_WAVE_DROPS);
+    let top_y = FORMATION_TOP_Y - drops as f32 * FORMATION_STEP_DOWN;
+    let left_x = -(INVADER_COLUMNS - 1) as f32 * INVADER_SPACING_X / 2.0;
+    for (row, (sprite_name, score)) in INVADER_ROWS_SPEC.iter().enumerate() {
+        for column in 0..INVADER_COLUMNS {
+            let pos = Vec2::new(
+                left_x + column as f32 * INVADER_SPACING_X,
+                top_y - row as f32 * INVADER_SPACING_Y,
+            );
+            commands.spawn((
+                sheet_sprite(
+                    sprite_sheet,
+                    sprite_name,
+                    pos,
+                    Vec2::new(INVADER_WIDTH, INVADER_HEIGHT),
+                    1.0,
+                    scale,
+                ),
+                Invader {
+                    column,
+                    score: *score,
+                },
+                SpaceInvadersEntity,
+            ));
+        }
+    }
+}
+
+pub fn game_over_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<GameOverEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut submit_score_events: EventWriter<SubmitScoreEvent>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
+    game_state.has_game_ended = true;
+    submit_score_events.send(SubmitScoreEvent {
+        game: Game::SpaceInvaders,
+        score: game_state.score,
+        stats: vec![("Wave".to_string(), game_state.wave.to_string())],
+    });
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.85),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        EndGameUIElement,
+        SpaceInvadersEntity,
+        Renderable::new(Vec2::new(0.0, 0.0), target_resolution).with_size(overlay_size),
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let game_over_pos = Vec2::new(0.0, 40.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "Game Over",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 100.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(game_over_pos.x * scale, game_over_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        SpaceInvadersEntity,
+        Renderable::new(game_over_pos, target_resolution).with_scale(false, false),
+    ));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                format!("Score: {}\nWave: {}", game_state.score, game_state.wave),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(game_over_pos.x * scale, game_over_pos.y * scale, 11.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        SpaceInvadersEntity,
+        Renderable::new(game_over_pos, target_resolution).with_scale(false, false),
+    ));
+
+    let buttons_pos = Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "[Space]: New Game\n[Esc]: Pause",
+                TextStyle {
+                    font,
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(buttons_pos.x * scale, buttons_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        SpaceInvadersEntity,
+        Renderable::new(buttons_pos, target_resolution).with_scale(false, false),
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn restart_game_event_handler(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut restart_events: EventReader<RestartGameEvent>,
+    entities_to_clear: Query<Entity, With<SpaceInvadersEntity>>,
+    mut game_state: ResMut<GameState>,
+    mut formation: ResMut<Formation>,
+    sprite_sheet: Res<SpaceShooterSpriteSheet>,
+    viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+    mut music_tempo_events: EventWriter<SetMusicTempoEvent>,
+) {
+    if restart_events.is_empty() {
+        return;
+    }
+    restart_events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
+    music_tempo_events.send(SetMusicTempoEvent(1.0));
+
+    game_state.reset();
+    for entity in entities_to_clear.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let scale_vec = Vec3::new(scale, scale, 1.0);
+
+    // Spawn player
+    commands.spawn((
+        sheet_sprite(
+            &sprite_sheet,
+            PLAYER_SPRITE,
+            Vec2::new(0.0, PLAYER_POS_Y),
+            Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT),
+            2.0,
+            scale,
+        ),
+        Player,
+        SpaceInvadersEntity,
+    ));
+    // Spawn ground line
+    let ground_pos = Vec2::new(0.0, PLAYER_POS_Y - PLAYER_HEIGHT / 2.0 - 4.0);
+    let ground_size = Vec2::new(PLAY_AREA_WIDTH, 2.0);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::GREEN,
+                custom_size: Some(ground_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(ground_pos.x * scale, ground_pos.y * scale, 0.0)
+                .with_scale(scale_vec),
+            ..default()
+        },
+        Renderable::new(ground_pos, target_resolution).with_size(ground_size),
+        SpaceInvadersEntity,
+    ));
+
+    // Spawn bunkers, one small sprite per cell so they can be eroded
+    let cell_size = Vec2::splat(BUNKER_CELL_SIZE);
+    let bunker_width = BUNKER_SHAPE[0].len() as f32 * BUNKER_CELL_SIZE;
+    let bunker_spacing = PLAY_AREA_WIDTH / BUNKER_COUNT as f32;
+    for bunker in 0..BUNKER_COUNT {
+        let bunker_left =
+            -PLAY_AREA_WIDTH / 2.0 + (bunker as f32 + 0.5) * bunker_spacing - bunker_width / 2.0;
+        for (row, line) in BUNKER_SHAPE.iter().enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                if c != '#' {
+                    continue;
+                }
+                let pos = Vec2::new(
+                    bunker_left + (column as f32 + 0.5) * BUNKER_CELL_SIZE,
+                    BUNKER_POS_Y - row as f32 * BUNKER_CELL_SIZE,
+                );
+                commands.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::GREEN,
+                            custom_size: Some(cell_size),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 0.0)
+                            .with_scale(scale_vec),
+                        ..default()
+                    },
+                    Renderable::new(pos, target_resolution).with_size(cell_size),
+                    BunkerCell,
+                    SpaceInvadersEntity,
+                ));
+            }
+        }
+    }
+
+    // Spawn HUD
+    let font = asset_server.load(FONT_FILE.to_string());
+    let text_style = TextStyle {
+        font,
+        font_size: 20.0,
+        color: Color::WHITE,
+    };
+    let hud_y = PLAY_AREA_HEIGHT / 2.0 - 5.0;
+    let hud_items = [
+        ("Score: ", -PLAY_AREA_WIDTH / 2.0 + 10.0, Anchor::TopLeft),
+        ("Wave ", 0.0, Anchor::TopCenter),
+        ("Lives: ", PLAY_AREA_WIDTH / 2.0 - 10.0, Anchor::TopRight),
+    ];
+    for (index, (label, pos_x, anchor)) in hud_items.into_iter().enumerate() {
+        let pos = Vec2::new(pos_x, hud_y);
+        let mut entity = commands.spawn((
+            Text2dBundle {
+                text: Text::from_sections([
+                    TextSection::new(label, text_style.clone()),
+                    TextSection::new("", text_style.clone()),
+                ]),
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 2.0),
+                text_anchor: anchor,
+                ..default()
+            },
+            Renderable::new(pos, target_resolution).with_scale(false, false),
+            SpaceInvadersEntity,
+        ));
+        match index {
+            0 => entity.insert(ScoreText),
+            1 => entity.insert(WaveText),
+            _ => entity.insert(LivesText),
+        };
+    }
+
+    start_wave(
+        &mut commands,
+        &mut game_state,
+        &mut formation,
+        &sprite_sheet,
+        scale,
+    );
+}
@@ -0,0 +1,58 @@
+use bevy::app::{App, Plugin};
+use bevy::math::Vec2;
+use bevy::prelude::Entity;
+
+pub struct EventsPlugin;
+
+impl Plugin for EventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<FireShotEvent>()
+            .add_event::<DropBombEvent>()
+            .add_event::<SpawnUfoEvent>()
+            .add_event::<InvaderDestroyedEvent>()
+            .add_event::<UfoDestroyedEvent>()
+            .add_event::<BunkerHitEvent>()
+            .add_event::<PlayerHitEvent>()
+            .add_event::<StartWaveEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<RestartGameEvent>();
+    }
+}
+
+#[derive(Default)]
+pub struct FireShotEvent;
+
+pub struct DropBombEvent {
+    pub pos: Vec2,
+}
+
+#[derive(Default)]
+pub struct SpawnUfoEvent;
+
+pub struct InvaderDestroyedEvent {
+    pub entity: Entity,
+    pub score: i32,
+}
+
+pub struct UfoDestroyedEvent {
+    pub entity: Entity,
+    pub score: i32,
+}
+
+/// Erodes the bunker cells within `radius` of `pos`, with a ragged edge up to twice as far
+pub struct BunkerHitEvent {
+    pub pos: Vec2,
+    pub radius: f32,
+}
+
+#[derive(Default)]
+pub struct PlayerHitEvent;
+
+#[derive(Default)]
+pub struct StartWaveEvent;
+
+#[derive(Default)]
+pub struct GameOverEvent;
+
+#[derive(Default)]
+pub struct RestartGameEvent;
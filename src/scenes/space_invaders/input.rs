@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::actions::{Action, Actions};
+use crate::common::{AppState, Renderable};
+use crate::scenes::space_invaders::components::Player;
+use crate::scenes::space_invaders::constants::PLAYER_SPEED;
+use crate::scenes::space_invaders::events::{FireShotEvent, RestartGameEvent};
+use crate::scenes::space_invaders::resources::GameState;
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(player_input.in_set(OnUpdate(AppState::SpaceInvaders)))
+            .add_system(end_game_input.in_set(OnUpdate(AppState::SpaceInvaders)));
+    }
+}
+
+pub fn player_input(
+    time: Res<Time>,
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut query: Query<&mut Renderable, With<Player>>,
+    mut fire_events: EventWriter<FireShotEvent>,
+) {
+    if game_state.has_game_ended || !game_state.is_player_alive {
+        return;
+    }
+
+    for mut renderable in query.iter_mut() {
+        let dir = actions.axis(Action::MoveLeft, Action::MoveRight);
+        if dir != 0.0 {
+            let new_pos = renderable.pos.x + dir * PLAYER_SPEED * time.delta_seconds();
+            renderable.pos.x = new_pos.clamp(renderable.min_x(), renderable.max_x());
+        }
+    }
+
+    if actions.any_just_pressed([Action::Fire, Action::MoveUp]) {
+        fire_events.send_default();
+    }
+}
+
+pub fn end_game_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+) {
+    if !game_state.has_game_ended {
+        return;
+    }
+
+    if actions.just_pressed(Action::Confirm) {
+        restart_events.send_default();
+    }
+}
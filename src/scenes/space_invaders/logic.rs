@@ -0,0 +1,348 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use rand::seq::IteratorRandom;
+use rand::thread_rng;
+
+use crate::common::{AppState, Renderable};
+use crate::music::SetMusicTempoEvent;
+use crate::scenes::space_invaders::components::*;
+use crate::scenes::space_invaders::constants::*;
+use crate::scenes::space_invaders::events::*;
+use crate::scenes::space_invaders::input::player_input;
+use crate::scenes::space_invaders::resources::{Formation, GameState};
+
+pub struct LogicPlugin;
+
+impl Plugin for LogicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                formation_march,
+                invader_landing,
+                drop_bombs,
+                move_projectiles,
+                move_ufo,
+                player_respawn,
+                wave_progression,
+            )
+                .chain()
+                .in_set(OnUpdate(AppState::SpaceInvaders))
+                .after(player_input),
+        )
+        .add_system(
+            hud_update
+                .in_set(OnUpdate(AppState::SpaceInvaders))
+                .after(wave_progression),
+        );
+    }
+}
+
+fn overlaps(a: &Renderable, b: &Renderable) -> bool {
+    a.left() < b.right() && a.right() > b.left() && a.bottom() < b.top() && a.top() > b.bottom()
+}
+
+fn is_frozen(game_state: &GameState) -> bool {
+    game_state.has_game_ended || !game_state.is_player_alive
+}
+
+pub fn formation_march(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut formation: ResMut<Formation>,
+    mut invader_query: Query<&mut Renderable, With<Invader>>,
+    mut music_tempo_events: EventWriter<SetMusicTempoEvent>,
+) {
+    if is_frozen(&game_state) || invader_query.is_empty() {
+        return;
+    }
+
+    formation.step_timer.tick(time.delta());
+    if !formation.step_timer.just_finished() {
+        return;
+    }
+
+    let (min_left, max_right) =
+        invader_query
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(min_left, max_right), renderable| {
+                (
+                    min_left.min(renderable.left()),
+                    max_right.max(renderable.right()),
+                )
+            });
+    let edge = PLAY_AREA_WIDTH / 2.0 - FORMATION_STEP_X;
+    let step = if (formation.dir > 0.0 && max_right + FORMATION_STEP_X > edge)
+        || (formation.dir < 0.0 && min_left - FORMATION_STEP_X < -edge)
+    {
+        formation.dir = -formation.dir;
+        Vec2::new(0.0, -FORMATION_STEP_DOWN)
+    } else {
+        Vec2::new(formation.dir * FORMATION_STEP_X, 0.0)
+    };
+
+    for mut renderable in invader_query.iter_mut() {
+        renderable.pos += step;
+    }
+
+    let alive = invader_query.iter().len();
+    let interval = formation.step_interval(alive);
+    formation.step_timer = Timer::from_seconds(interval, TimerMode::Once);
+    music_tempo_events.send(SetMusicTempoEvent(formation.music_tempo(alive)));
+}
+
+/// Invaders crush the bunkers they walk through, and the game is lost once they land
+pub fn invader_landing(
+    game_state: Res<GameState>,
+    invader_query: Query<&Renderable, With<Invader>>,
+    bunker_query: Query<&Renderable, With<BunkerCell>>,
+    mut bunker_hit_events: EventWriter<BunkerHitEvent>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if game_state.has_game_ended {
+        return;
+    }
+
+    let landing_y = PLAYER_POS_Y + PLAYER_HEIGHT / 2.0;
+    for invader in invader_query.iter() {
+        if invader.bottom() <= landing_y {
+            game_over_events.send_default();
+            return;
+        }
+        for cell in bunker_query.iter() {
+            if overlaps(invader, cell) {
+                bunker_hit_events.send(BunkerHitEvent {
+                    pos: cell.pos,
+                    radius: 0.0,
+                });
+            }
+        }
+    }
+}
+
+/// A random column fires from its lowest invader
+pub fn drop_bombs(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    invader_query: Query<(&Invader, &Renderable)>,
+    bomb_query: Query<(), With<Bomb>>,
+    mut drop_bomb_events: EventWriter<DropBombEvent>,
+) {
+    if is_frozen(&game_state) {
+        return;
+    }
+
+    game_state.bomb_timer.tick(time.delta());
+    if !game_state.bomb_timer.just_finished() || bomb_query.iter().len() >= MAX_BOMBS {
+        return;
+    }
+
+    let Some(column) = invader_query
+        .iter()
+        .map(|(invader, _)| invader.column)
+        .collect::<HashSet<i32>>()
+        .into_iter()
+        .choose(&mut thread_rng())
+    else { return; };
+    let lowest = invader_query
+        .iter()
+        .filter(|(invader, _)| invader.column == column)
+        .map(|(_, renderable)| renderable)
+        .min_by(|a, b| a.pos.y.total_cmp(&b.pos.y));
+    if let Some(renderable) = lowest {
+        drop_bomb_events.send(DropBombEvent {
+            pos: Vec2::new(renderable.pos.x, renderable.bottom() - BOMB_HEIGHT / 2.0),
+        });
+    }
+}
+
+/// Moves shots and bombs, resolving everything they can hit
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn move_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut shot_query: Query<(Entity, &mut Renderable), (With<PlayerShot>, Without<Bomb>)>,
+    mut bomb_query: Query<(Entity, &mut Renderable), (With<Bomb>, Without<PlayerShot>)>,
+    target_query: Query<
+        (Entity, &Renderable, Option<&Invader>, Option<&Ufo>),
+        (
+            Or<(With<Invader>, With<Ufo>)>,
+            Without<PlayerShot>,
+            Without<Bomb>,
+        ),
+    >,
+    bunker_query: Query<&Renderable, (With<BunkerCell>, Without<PlayerShot>, Without<Bomb>)>,
+    player_query: Query<&Renderable, (With<Player>, Without<PlayerShot>, Without<Bomb>)>,
+    mut events: (
+        EventWriter<InvaderDestroyedEvent>,
+        EventWriter<UfoDestroyedEvent>,
+        EventWriter<BunkerHitEvent>,
+        EventWriter<PlayerHitEvent>,
+    ),
+) {
+    if game_state.has_game_ended {
+        return;
+    }
+    let (invader_events, ufo_events, bunker_hit_events, player_hit_events) = &mut events;
+    let mut despawned = HashSet::new();
+    let half_height = PLAY_AREA_HEIGHT / 2.0;
+
+    for (shot_entity, mut shot) in shot_query.iter_mut() {
+        shot.pos.y += SHOT_SPEED * time.delta_seconds();
+        let is_spent = if shot.bottom() > half_height {
+            true
+        } else if let Some((entity, _, invader, ufo)) = target_query
+            .iter()
+            .find(|(_, renderable, _, _)| overlaps(&shot, renderable))
+        {
+            if let Some(invader) = invader {
+                invader_events.send(InvaderDestroyedEvent {
+                    entity,
+                    score: invader.score,
+                });
+            } else if let Some(ufo) = ufo {
+                ufo_events.send(UfoDestroyedEvent {
+                    entity,
+                    score: ufo.score,
+                });
+            }
+            true
+        } else if let Some((bomb_entity, _)) =
+            bomb_query.iter().find(|(_, bomb)| overlaps(&shot, bomb))
+        {
+            // shots and bombs cancel each other out
+            despawned.insert(bomb_entity);
+            true
+        } else if let Some(cell) = bunker_query.iter().find(|cell| overlaps(&shot, cell)) {
+            bunker_hit_events.send(BunkerHitEvent {
+                pos: cell.pos,
+                radius: BUNKER_DAMAGE_RADIUS,
+            });
+            true
+        } else {
+            false
+        };
+        if is_spent {
+            despawned.insert(shot_entity);
+        }
+    }
+
+    let player = player_query.get_single().ok();
+    for (bomb_entity, mut bomb) in bomb_query.iter_mut() {
+        if despawned.contains(&bomb_entity) {
+            continue;
+        }
+        bomb.pos.y -= BOMB_SPEED * time.delta_seconds();
+        let is_spent = if bomb.top() < -half_height {
+            true
+        } else if let Some(cell) = bunker_query.iter().find(|cell| overlaps(&bomb, cell)) {
+            bunker_hit_events.send(BunkerHitEvent {
+                pos: cell.pos,
+                radius: BUNKER_DAMAGE_RADIUS,
+            });
+            true
+        } else if game_state.is_player_alive && player.is_some_and(|p| overlaps(&bomb, p)) {
+            // the hit clears every bomb, this one included
+            player_hit_events.send_default();
+            false
+        } else {
+            false
+        };
+        if is_spent {
+            despawned.insert(bomb_entity);
+        }
+    }
+
+    for entity in despawned {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn move_ufo(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut ufo_query: Query<(Entity, &Ufo, &mut Renderable)>,
+    mut spawn_ufo_events: EventWriter<SpawnUfoEvent>,
+) {
+    if game_state.has_game_ended {
+        return;
+    }
+
+    if ufo_query.is_empty() {
+        if !game_state.is_player_alive {
+            return;
+        }
+        game_state.ufo_timer.tick(time.delta());
+        if game_state.ufo_timer.just_finished() {
+            spawn_ufo_events.send_default();
+        }
+        return;
+    }
+
+    for (entity, ufo, mut renderable) in ufo_query.iter_mut() {
+        renderable.pos.x += ufo.speed * time.delta_seconds();
+        if renderable.pos.x.abs() > (PLAY_AREA_WIDTH + UFO_SIZE) / 2.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn player_respawn(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut player_query: Query<(&mut Visibility, &mut Renderable), With<Player>>,
+) {
+    if game_state.has_game_ended || game_state.is_player_alive {
+        return;
+    }
+
+    game_state.respawn_timer.tick(time.delta());
+    if !game_state.respawn_timer.just_finished() {
+        return;
+    }
+
+    game_state.is_player_alive = true;
+    for (mut visibility, mut renderable) in player_query.iter_mut() {
+        *visibility = Visibility::Inherited;
+        renderable.pos.x = 0.0;
+    }
+}
+
+pub fn wave_progression(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    invader_query: Query<(), With<Invader>>,
+    mut start_wave_events: EventWriter<StartWaveEvent>,
+) {
+    if game_state.has_game_ended || !invader_query.is_empty() {
+        return;
+    }
+
+    game_state.next_wave_timer.tick(time.delta());
+    if game_state.next_wave_timer.just_finished() {
+        game_state.wave += 1;
+        start_wave_events.send_default();
+    }
+}
+
+pub fn hud_update(
+    game_state: Res<GameState>,
+    mut score_query: Query<&mut Text, (With<ScoreText>, Without<LivesText>)>,
+    mut lives_query: Query<&mut Text, (With<LivesText>, Without<WaveText>)>,
+    mut wave_query: Query<&mut Text, (With<WaveText>, Without<ScoreText>)>,
+) {
+    if !game_state.is_changed() {
+        return;
+    }
+
+    for mut text in score_query.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.score);
+    }
+    for mut text in lives_query.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.lives);
+    }
+    for mut text in wave_query.iter_mut() {
+        text.sections[1].value = format!("{}", game_state.wave);
+    }
+}
@@ -0,0 +1,10 @@
+pub use space_invaders_scene_plugin::SpaceInvadersScenePlugin;
+
+mod components;
+mod constants;
+mod event_handlers;
+mod events;
+mod input;
+mod logic;
+mod resources;
+mod space_invaders_scene_plugin;
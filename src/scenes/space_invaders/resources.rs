@@ -0,0 +1,143 @@
+use bevy::app::{App, Plugin};
+use bevy::asset::Handle;
+use bevy::prelude::*;
+use bevy::sprite::TextureAtlas;
+use rand::{thread_rng, Rng};
+
+use crate::scenes::space_invaders::constants::*;
+use crate::texture_atlas_loader::TextureAtlasLoader;
+
+pub struct ResourcesPlugin;
+
+impl Plugin for ResourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SpaceShooterSpriteSheet::default())
+            .insert_resource(GameState::default())
+            .insert_resource(Formation::default());
+    }
+}
+
+#[derive(Resource)]
+pub struct SpaceShooterSpriteSheet(TextureAtlasLoader);
+
+impl SpaceShooterSpriteSheet {
+    pub fn atlas_handle(&self) -> Option<Handle<TextureAtlas>> {
+        self.0.texture_atlas_handle.clone()
+    }
+
+    pub fn index_of(&self, sprite_name: &str) -> Option<usize> {
+        self.0.index_of(sprite_name)
+    }
+
+    pub fn load(
+        &mut self,
+        asset_server: &Res<AssetServer>,
+        texture_atlases: ResMut<Assets<TextureAtlas>>,
+    ) {
+        self.0.load(asset_server, texture_atlases);
+    }
+}
+
+impl Default for SpaceShooterSpriteSheet {
+    fn default() -> Self {
+        SpaceShooterSpriteSheet(TextureAtlasLoader::from_sheet_xml(
+            "sprites/asteroid",
+            "space_shooter_sheet",
+        ))
+    }
+}
+
+#[derive(Resource)]
+pub struct GameState {
+    pub score: i32,
+    pub lives: i32,
+    pub wave: u32,
+    pub has_game_ended: bool,
+    pub is_player_alive: bool,
+    pub respawn_timer: Timer,
+    pub bomb_timer: Timer,
+    pub ufo_timer: Timer,
+    pub next_wave_timer: Timer,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        GameState {
+            score: 0,
+            lives: PLAYER_START_LIVES,
+            wave: 1,
+            has_game_ended: false,
+            is_player_alive: true,
+            respawn_timer: Timer::from_seconds(PLAYER_RESPAWN_DELAY, TimerMode::Once),
+            bomb_timer: Timer::from_seconds(BOMB_INTERVAL, TimerMode::Repeating),
+            ufo_timer: random_ufo_timer(),
+            next_wave_timer: Timer::from_seconds(NEXT_WAVE_DELAY, TimerMode::Once),
+        }
+    }
+}
+
+impl GameState {
+    pub fn reset(&mut self) {
+        *self = GameState::default();
+    }
+
+    /// Invaders drop bombs more often in later waves
+    pub fn bomb_interval(&self) -> f32 {
+        (BOMB_INTERVAL - (self.wave - 1) as f32 * BOMB_INTERVAL_DECREASE_PER_WAVE)
+            .max(BOMB_MIN_INTERVAL)
+    }
+
+    pub fn reset_ufo_timer(&mut self) {
+        self.ufo_timer = random_ufo_timer();
+    }
+}
+
+fn random_ufo_timer() -> Timer {
+    Timer::from_seconds(
+        thread_rng().gen_range(UFO_MIN_INTERVAL..UFO_MAX_INTERVAL),
+        TimerMode::Once,
+    )
+}
+
+/// The invaders march together: one step sideways per tick, down and back at the edges
+#[derive(Resource)]
+pub struct Formation {
+    pub dir: f32,
+    pub step_timer: Timer,
+    pub total: usize,
+}
+
+impl Default for Formation {
+    fn default() -> Self {
+        Formation {
+            dir: 1.0,
+            step_timer: Timer::from_seconds(FORMATION_MAX_STEP_INTERVAL, TimerMode::Once),
+            total: (INVADER_COLUMNS * INVADER_ROWS) as usize,
+        }
+    }
+}
+
+impl Formation {
+    pub fn reset(&mut self) {
+        *self = Formation::default();
+    }
+
+    /// The fewer invaders are left, the faster the formation marches
+    pub fn step_interval(&self, alive: usize) -> f32 {
+        FORMATION_MIN_STEP_INTERVAL
+            + (FORMATION_MAX_STEP_INTERVAL - FORMATION_MIN_STEP_INTERVAL) * self.alive_ratio(alive)
+    }
+
+    /// The music speeds up along with the march
+    pub fn music_tempo(&self, alive: usize) -> f32 {
+        MAX_MUSIC_TEMPO - (MAX_MUSIC_TEMPO - 1.0) * self.alive_ratio(alive)
+    }
+
+    /// From 1.0 with the whole formation alive, down to 0.0 with a single invader left
+    fn alive_ratio(&self, alive: usize) -> f32 {
+        if self.total <= 1 {
+            return 0.0;
+        }
+        alive.saturating_sub(1) as f32 / (self.total - 1) as f32
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::app::App;
+use bevy::prelude::*;
+
+use crate::common::AppState;
+use crate::scenes::space_invaders::components::SpaceInvadersEntity;
+use crate::scenes::space_invaders::event_handlers::EventHandlerPlugin;
+use crate::scenes::space_invaders::events::{EventsPlugin, RestartGameEvent};
+use crate::scenes::space_invaders::input::InputPlugin;
+use crate::scenes::space_invaders::logic::LogicPlugin;
+use crate::scenes::space_invaders::resources::{ResourcesPlugin, SpaceShooterSpriteSheet};
+
+pub struct SpaceInvadersScenePlugin;
+
+impl Plugin for SpaceInvadersScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResourcesPlugin)
+            .add_plugin(EventsPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(EventHandlerPlugin)
+            .add_plugin(LogicPlugin)
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::SpaceInvaders)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::SpaceInvaders)));
+    }
+}
+
+fn setup_scene(
+    mut restart_events: EventWriter<RestartGameEvent>,
+    asset_server: Res<AssetServer>,
+    texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut space_shooter_sprite_sheet: ResMut<SpaceShooterSpriteSheet>,
+) {
+    if space_shooter_sprite_sheet.atlas_handle().is_none() {
+        space_shooter_sprite_sheet.load(&asset_server, texture_atlases);
+    }
+    restart_events.send_default();
+}
+
+fn despawn_game(mut commands: Commands, entities: Query<Entity, With<SpaceInvadersEntity>>) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn();
+    }
+}
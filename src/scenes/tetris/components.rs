@@ -0,0 +1,31 @@
+use bevy::math::Vec2;
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct WellCell {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PreviewSlot {
+    Hold,
+    Next(usize),
+}
+
+/// One of the four blocks drawing the piece shown in a hold or next-queue slot
+#[derive(Component)]
+pub struct PreviewCell {
+    pub slot: PreviewSlot,
+    pub index: usize,
+    pub center: Vec2,
+}
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct EndGameUIElement;
+
+#[derive(Component)]
+pub struct TetrisEntity;
@@ -0,0 +1,26 @@
+pub const PLAY_AREA_WIDTH: f32 = 640.0;
+pub const PLAY_AREA_HEIGHT: f32 = 480.0;
+
+pub const WELL_WIDTH: i32 = 10;
+pub const WELL_HEIGHT: i32 = 20;
+/// Rows above the visible well where pieces spawn and rotate into
+pub const WELL_BUFFER_HEIGHT: i32 = 20;
+pub const CELL_SIZE: f32 = 20.0;
+pub const PREVIEW_CELL_SIZE: f32 = 12.0;
+pub const NEXT_QUEUE_SIZE: usize = 5;
+
+pub const SPAWN_ROW: i32 = WELL_HEIGHT;
+
+pub const LOCK_DELAY: f32 = 0.5;
+pub const LOCK_DELAY_MAX_RESETS: u32 = 15;
+pub const SOFT_DROP_FACTOR: f32 = 20.0;
+pub const AUTO_SHIFT_DELAY: f32 = 0.17;
+pub const AUTO_SHIFT_INTERVAL: f32 = 0.05;
+
+pub const LINES_PER_LEVEL: u32 = 10;
+pub const MAX_LEVEL: u32 = 20;
+/// Tempo of the music on `MAX_LEVEL`, rising with each level before it
+pub const MAX_MUSIC_TEMPO: f32 = 1.5;
+pub const LINE_CLEAR_SCORES: [i32; 5] = [0, 100, 300, 500, 800];
+pub const SOFT_DROP_SCORE: i32 = 1;
+pub const HARD_DROP_SCORE: i32 = 2;
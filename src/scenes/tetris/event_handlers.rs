@@ -0,0 +1,461 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::audio::{PlaySoundEvent, Sound};
+use crate::common::{AppState, Game, Renderable, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::high_scores::SubmitScoreEvent;
+use crate::music::{DuckMusicEvent, SetMusicTempoEvent};
+use crate::scenes::tetris::components::*;
+use crate::scenes::tetris::constants::*;
+use crate::scenes::tetris::events::*;
+use crate::scenes::tetris::input::{end_game_input, piece_input};
+use crate::scenes::tetris::resources::{GameState, PieceQueue, Well};
+
+pub struct EventHandlerPlugin;
+
+impl Plugin for EventHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            move_piece_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(piece_input),
+        )
+        .add_system(
+            rotate_piece_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(move_piece_event_handler),
+        )
+        .add_system(
+            hold_piece_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(rotate_piece_event_handler),
+        )
+        .add_system(
+            hard_drop_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(hold_piece_event_handler),
+        )
+        .add_system(
+            piece_locked_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(hard_drop_event_handler),
+        )
+        .add_system(
+            lines_cleared_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(piece_locked_event_handler),
+        )
+        .add_system(
+            game_over_event_handler
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(piece_locked_event_handler)
+                .after(end_game_input),
+        )
+        .add_system(restart_game_event_handler.in_set(OnUpdate(AppState::Tetris)));
+    }
+}
+
+pub fn move_piece_event_handler(
+    mut events: EventReader<MovePieceEvent>,
+    mut well: ResMut<Well>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in events.iter() {
+        let Some(piece) = well.active_piece else { return; };
+        let moved = piece.moved(IVec2::new(event.dx, 0));
+        if well.fits(&moved) {
+            well.active_piece = Some(moved);
+            game_state.reset_lock_delay();
+        }
+    }
+}
+
+pub fn rotate_piece_event_handler(
+    mut events: EventReader<RotatePieceEvent>,
+    mut well: ResMut<Well>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in events.iter() {
+        let Some(piece) = well.active_piece else { return; };
+        let rotated = piece.rotated(event.0);
+        let kicked = piece
+            .kind
+            .wall_kicks(piece.rotation, event.0)
+            .iter()
+            .map(|kick| rotated.moved(*kick))
+            .find(|candidate| well.fits(candidate));
+        if let Some(kicked) = kicked {
+            well.active_piece = Some(kicked);
+            game_state.reset_lock_delay();
+        }
+    }
+}
+
+pub fn hold_piece_event_handler(
+    mut events: EventReader<HoldPieceEvent>,
+    mut well: ResMut<Well>,
+    mut piece_queue: ResMut<PieceQueue>,
+    mut game_state: ResMut<GameState>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    let Some(piece) = well.active_piece else { return; };
+    if !piece_queue.can_hold {
+        return;
+    }
+
+    let next_kind = match piece_queue.hold.replace(piece.kind) {
+        Some(held_kind) => held_kind,
+        None => piece_queue.pop(),
+    };
+    piece_queue.can_hold = false;
+    game_state.on_piece_spawned();
+    if !well.spawn(next_kind) {
+        game_over_events.send_default();
+    }
+}
+
+pub fn hard_drop_event_handler(
+    mut events: EventReader<HardDropEvent>,
+    mut well: ResMut<Well>,
+    mut game_state: ResMut<GameState>,
+    mut locked_events: EventWriter<PieceLockedEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    let Some(piece) = well.active_piece else { return; };
+    let dropped = well.drop_position(&piece);
+    game_state.score += (piece.pos.y - dropped.pos.y) * HARD_DROP_SCORE;
+    well.active_piece = Some(dropped);
+    locked_events.send_default();
+}
+
+pub fn piece_locked_event_handler(
+    mut events: EventReader<PieceLockedEvent>,
+    mut well: ResMut<Well>,
+    mut piece_queue: ResMut<PieceQueue>,
+    mut game_state: ResMut<GameState>,
+    mut lines_cleared_events: EventWriter<LinesClearedEvent>,
+    mut game_over_events: EventWriter<GameOverEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    let Some(piece) = well.active_piece.take() else { return; };
+    well.lock(&piece);
+
+    // lock out: the whole piece came to rest above the visible well
+    if piece.cells().iter().all(|cell| cell.y >= WELL_HEIGHT) {
+        game_over_events.send_default();
+        return;
+    }
+
+    let cleared = well.clear_full_rows();
+    if cleared > 0 {
+        lines_cleared_events.send(LinesClearedEvent { count: cleared });
+    }
+
+    let next_kind = piece_queue.pop();
+    game_state.on_piece_spawned();
+    if !well.spawn(next_kind) {
+        game_over_events.send_default();
+    }
+}
+
+pub fn lines_cleared_event_handler(
+    mut events: EventReader<LinesClearedEvent>,
+    mut game_state: ResMut<GameState>,
+    mut music_tempo_events: EventWriter<SetMusicTempoEvent>,
+    mut play_sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for event in events.iter() {
+        play_sound_events.send(PlaySoundEvent(Sound::TetrisLineClear));
+        let index = (event.count as usize).min(LINE_CLEAR_SCORES.len() - 1);
+        game_state.score += LINE_CLEAR_SCORES[index] * game_state.level as i32;
+        let level = game_state.level;
+        game_state.add_lines(event.count);
+        if game_state.level != level {
+            let progress = (game_state.level - 1) as f32 / (MAX_LEVEL - 1) as f32;
+            music_tempo_events.send(SetMusicTempoEvent(1.0 + (MAX_MUSIC_TEMPO - 1.0) * progress));
+        }
+    }
+}
+
+pub fn game_over_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<GameOverEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut submit_score_events: EventWriter<SubmitScoreEvent>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
+    game_state.has_game_ended = true;
+    submit_score_events.send(SubmitScoreEvent {
+        game: Game::Tetris,
+        score: game_state.score,
+        stats: vec![
+            ("Level".to_string(), game_state.level.to_string()),
+            ("Lines".to_string(), game_state.lines.to_string()),
+        ],
+    });
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, 0.85),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        EndGameUIElement,
+        TetrisEntity,
+        Renderable::new(Vec2::new(0.0, 0.0), target_resolution).with_size(overlay_size),
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let game_over_pos = Vec2::new(0.0, 40.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "Game Over",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 100.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(game_over_pos.x * scale, game_over_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        TetrisEntity,
+        Renderable::new(game_over_pos, target_resolution).with_scale(false, false),
+    ));
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                format!(
+                    "Score: {}\nLevel: {}  Lines: {}",
+                    game_state.score, game_state.level, game_state.lines
+                ),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(game_over_pos.x * scale, game_over_pos.y * scale, 11.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        TetrisEntity,
+        Renderable::new(game_over_pos, target_resolution).with_scale(false, false),
+    ));
+
+    let buttons_pos = Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "[Space]: New Game\n[Esc]: Pause",
+                TextStyle {
+                    font,
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(buttons_pos.x * scale, buttons_pos.y * scale, 11.0),
+            text_anchor: Anchor::BottomCenter,
+            ..default()
+        },
+        EndGameUIElement,
+        TetrisEntity,
+        Renderable::new(buttons_pos, target_resolution).with_scale(false, false),
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn restart_game_event_handler(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut restart_events: EventReader<RestartGameEvent>,
+    entities_to_clear: Query<Entity, With<TetrisEntity>>,
+    mut game_state: ResMut<GameState>,
+    mut well: ResMut<Well>,
+    mut piece_queue: ResMut<PieceQueue>,
+    viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+    mut music_tempo_events: EventWriter<SetMusicTempoEvent>,
+) {
+    if restart_events.is_empty() {
+        return;
+    }
+    restart_events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
+    music_tempo_events.send(SetMusicTempoEvent(1.0));
+
+    game_state.reset();
+    well.reset();
+    piece_queue.reset();
+    let first_kind = piece_queue.pop();
+    well.spawn(first_kind);
+    for entity in entities_to_clear.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let target_resolution = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let scale_vec = Vec3::new(scale, scale, 1.0);
+
+    // Spawn well background, visible between the cells as grid lines
+    let well_size = Vec2::new(
+        WELL_WIDTH as f32 * CELL_SIZE + 2.0,
+        WELL_HEIGHT as f32 * CELL_SIZE + 2.0,
+    );
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::DARK_GRAY,
+                custom_size: Some(well_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 0.0).with_scale(scale_vec),
+            ..default()
+        },
+        Renderable::new(Vec2::ZERO, target_resolution).with_size(well_size),
+        TetrisEntity,
+    ));
+    // Spawn well cells
+    let cell_size = Vec2::new(CELL_SIZE - 1.0, CELL_SIZE - 1.0);
+    let well_origin = Vec2::new(
+        -(WELL_WIDTH as f32 - 1.0) * CELL_SIZE / 2.0,
+        -(WELL_HEIGHT as f32 - 1.0) * CELL_SIZE / 2.0,
+    );
+    for y in 0..WELL_HEIGHT {
+        for x in 0..WELL_WIDTH {
+            let pos = well_origin + Vec2::new(x as f32, y as f32) * CELL_SIZE;
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(cell_size),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 1.0)
+                        .with_scale(scale_vec),
+                    ..default()
+                },
+                Renderable::new(pos, target_resolution).with_size(cell_size),
+                WellCell { x, y },
+                TetrisEntity,
+            ));
+        }
+    }
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let label_style = TextStyle {
+        font: font.clone(),
+        font_size: 20.0,
+        color: Color::WHITE,
+    };
+    let side_panel_x = (WELL_WIDTH as f32 * CELL_SIZE + PLAY_AREA_WIDTH / 2.0) / 2.0 - 40.0;
+    let preview_top_y = WELL_HEIGHT as f32 * CELL_SIZE / 2.0;
+
+    // Spawn hold and next queue previews
+    let mut slots = vec![(
+        PreviewSlot::Hold,
+        Vec2::new(-side_panel_x, preview_top_y - 50.0),
+    )];
+    for i in 0..NEXT_QUEUE_SIZE {
+        let slot_y = preview_top_y - 50.0 - i as f32 * 3.5 * PREVIEW_CELL_SIZE;
+        slots.push((PreviewSlot::Next(i), Vec2::new(side_panel_x, slot_y)));
+    }
+    let preview_cell_size = Vec2::new(PREVIEW_CELL_SIZE - 1.0, PREVIEW_CELL_SIZE - 1.0);
+    for (slot, center) in slots {
+        for index in 0..4 {
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(preview_cell_size),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(center.x * scale, center.y * scale, 1.0)
+                        .with_scale(scale_vec),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                Renderable::new(center, target_resolution).with_size(preview_cell_size),
+                PreviewCell {
+                    slot,
+                    index,
+                    center,
+                },
+                TetrisEntity,
+            ));
+        }
+    }
+    for (label, pos_x) in [("Hold", -side_panel_x), ("Next", side_panel_x)] {
+        let label_pos = Vec2::new(pos_x, preview_top_y);
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(label, label_style.clone()),
+                transform: Transform::from_xyz(label_pos.x * scale, label_pos.y * scale, 2.0),
+                text_anchor: Anchor::TopCenter,
+                ..default()
+            },
+            Renderable::new(label_pos, target_resolution).with_scale(false, false),
+            TetrisEntity,
+        ));
+    }
+
+    // Spawn score text
+    let value_style = TextStyle {
+        font,
+        font_size: 30.0,
+        color: Color::WHITE,
+    };
+    let score_pos = Vec2::new(-side_panel_x, 20.0);
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_sections([
+                TextSection::new("Score\n", label_style.clone()),
+                TextSection::new("0", value_style.clone()),
+                TextSection::new("\n\nLevel\n", label_style.clone()),
+                TextSection::new("1", value_style.clone()),
+                TextSection::new("\n\nLines\n", label_style),
+                TextSection::new("0", value_style),
+            ])
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(score_pos.x * scale, score_pos.y * scale, 2.0),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        Renderable::new(score_pos, target_resolution).with_scale(false, false),
+        ScoreText,
+        TetrisEntity,
+    ));
+}
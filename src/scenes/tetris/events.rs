@@ -0,0 +1,43 @@
+use bevy::app::{App, Plugin};
+
+use crate::scenes::tetris::tetromino::RotationDirection;
+
+pub struct EventsPlugin;
+
+impl Plugin for EventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MovePieceEvent>()
+            .add_event::<RotatePieceEvent>()
+            .add_event::<HardDropEvent>()
+            .add_event::<HoldPieceEvent>()
+            .add_event::<PieceLockedEvent>()
+            .add_event::<LinesClearedEvent>()
+            .add_event::<GameOverEvent>()
+            .add_event::<RestartGameEvent>();
+    }
+}
+
+pub struct MovePieceEvent {
+    pub dx: i32,
+}
+
+pub struct RotatePieceEvent(pub RotationDirection);
+
+#[derive(Default)]
+pub struct HardDropEvent;
+
+#[derive(Default)]
+pub struct HoldPieceEvent;
+
+#[derive(Default)]
+pub struct PieceLockedEvent;
+
+pub struct LinesClearedEvent {
+    pub count: u32,
+}
+
+#[derive(Default)]
+pub struct GameOverEvent;
+
+#[derive(Default)]
+pub struct RestartGameEvent;
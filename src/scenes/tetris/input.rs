@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::actions::{Action, Actions};
+use crate::common::AppState;
+use crate::scenes::tetris::constants::{AUTO_SHIFT_DELAY, AUTO_SHIFT_INTERVAL};
+use crate::scenes::tetris::events::*;
+use crate::scenes::tetris::resources::GameState;
+use crate::scenes::tetris::tetromino::RotationDirection;
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(piece_input.in_set(OnUpdate(AppState::Tetris)))
+            .add_system(end_game_input.in_set(OnUpdate(AppState::Tetris)));
+    }
+}
+
+/// Delayed auto shift: holding a direction moves once, then repeats after a delay
+#[derive(Default)]
+pub struct AutoShift {
+    dx: i32,
+    timer: Timer,
+}
+
+impl AutoShift {
+    fn start(&mut self, dx: i32) {
+        self.dx = dx;
+        self.timer = Timer::from_seconds(AUTO_SHIFT_DELAY, TimerMode::Once);
+    }
+
+    fn stop(&mut self) {
+        self.dx = 0;
+    }
+
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.timer.tick(delta);
+        if !self.timer.just_finished() {
+            return false;
+        }
+        if self.timer.mode() == TimerMode::Once {
+            self.timer = Timer::from_seconds(AUTO_SHIFT_INTERVAL, TimerMode::Repeating);
+        }
+        true
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn piece_input(
+    time: Res<Time>,
+    actions: Res<Actions>,
+    mut game_state: ResMut<GameState>,
+    mut auto_shift: Local<AutoShift>,
+    mut move_events: EventWriter<MovePieceEvent>,
+    mut rotate_events: EventWriter<RotatePieceEvent>,
+    mut hard_drop_events: EventWriter<HardDropEvent>,
+    mut hold_events: EventWriter<HoldPieceEvent>,
+) {
+    if game_state.has_game_ended {
+        return;
+    }
+
+    let is_held = |dx: i32| {
+        if dx < 0 {
+            actions.pressed(Action::MoveLeft)
+        } else {
+            actions.pressed(Action::MoveRight)
+        }
+    };
+    if actions.just_pressed(Action::MoveLeft) {
+        auto_shift.start(-1);
+        move_events.send(MovePieceEvent { dx: -1 });
+    } else if actions.just_pressed(Action::MoveRight) {
+        auto_shift.start(1);
+        move_events.send(MovePieceEvent { dx: 1 });
+    } else if auto_shift.dx != 0 && is_held(auto_shift.dx) {
+        if auto_shift.tick(time.delta()) {
+            move_events.send(MovePieceEvent { dx: auto_shift.dx });
+        }
+    } else if auto_shift.dx != 0 && is_held(-auto_shift.dx) {
+        // released the most recent direction while still holding the other one
+        let dx = -auto_shift.dx;
+        auto_shift.start(dx);
+        move_events.send(MovePieceEvent { dx });
+    } else {
+        auto_shift.stop();
+    }
+
+    if actions.just_pressed(Action::RotateClockwise) {
+        rotate_events.send(RotatePieceEvent(RotationDirection::Clockwise));
+    } else if actions.just_pressed(Action::RotateCounterClockwise) {
+        rotate_events.send(RotatePieceEvent(RotationDirection::CounterClockwise));
+    }
+
+    if actions.just_pressed(Action::Hold) {
+        hold_events.send_default();
+    }
+
+    if actions.just_pressed(Action::HardDrop) {
+        hard_drop_events.send_default();
+    }
+
+    let is_soft_dropping = actions.pressed(Action::MoveDown);
+    if game_state.is_soft_dropping != is_soft_dropping {
+        game_state.is_soft_dropping = is_soft_dropping;
+    }
+}
+
+pub fn end_game_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+) {
+    if !game_state.has_game_ended {
+        return;
+    }
+
+    if actions.just_pressed(Action::Confirm) {
+        restart_events.send_default();
+    }
+}
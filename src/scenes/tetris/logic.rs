@@ -0,0 +1,168 @@
+use bevy::math::IVec2;
+use bevy::prelude::*;
+
+use crate::common::{AppState, Renderable};
+use crate::scenes::tetris::components::{PreviewCell, PreviewSlot, ScoreText, WellCell};
+use crate::scenes::tetris::constants::{PREVIEW_CELL_SIZE, SOFT_DROP_FACTOR, SOFT_DROP_SCORE};
+use crate::scenes::tetris::event_handlers::{
+    hold_piece_event_handler, lines_cleared_event_handler, piece_locked_event_handler,
+};
+use crate::scenes::tetris::events::PieceLockedEvent;
+use crate::scenes::tetris::resources::{GameState, PieceQueue, Well};
+
+pub struct LogicPlugin;
+
+const EMPTY_CELL_COLOR: Color = Color::rgb(0.08, 0.08, 0.08);
+const GHOST_ALPHA: f32 = 0.25;
+
+impl Plugin for LogicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            gravity
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(hold_piece_event_handler)
+                .before(piece_locked_event_handler),
+        )
+        .add_system(
+            well_render
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(piece_locked_event_handler),
+        )
+        .add_system(
+            preview_render
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(piece_locked_event_handler),
+        )
+        .add_system(
+            score_change
+                .in_set(OnUpdate(AppState::Tetris))
+                .after(lines_cleared_event_handler),
+        );
+    }
+}
+
+pub fn gravity(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut well: ResMut<Well>,
+    mut locked_events: EventWriter<PieceLockedEvent>,
+) {
+    if game_state.has_game_ended {
+        return;
+    }
+    let Some(mut piece) = well.active_piece else { return; };
+
+    let delta = if game_state.is_soft_dropping {
+        time.delta().mul_f32(SOFT_DROP_FACTOR)
+    } else {
+        time.delta()
+    };
+    game_state.gravity_timer.tick(delta);
+    let mut moved = false;
+    for _ in 0..game_state.gravity_timer.times_finished_this_tick() {
+        if well.is_grounded(&piece) {
+            break;
+        }
+        piece = piece.moved(IVec2::NEG_Y);
+        moved = true;
+        if game_state.is_soft_dropping {
+            game_state.score += SOFT_DROP_SCORE;
+        }
+    }
+
+    if moved {
+        well.active_piece = Some(piece);
+        // reaching a new row gives the piece a fresh lock delay
+        game_state.lock_timer.reset();
+        game_state.lock_resets = 0;
+    }
+
+    if well.is_grounded(&piece) {
+        game_state.lock_timer.tick(time.delta());
+        if game_state.lock_timer.just_finished() {
+            locked_events.send_default();
+        }
+    }
+}
+
+pub fn well_render(
+    well: Res<Well>,
+    mut cell_query: Query<(&WellCell, &mut Sprite)>,
+    new_cells: Query<(), Added<WellCell>>,
+) {
+    if !well.is_changed() && new_cells.is_empty() {
+        return;
+    }
+
+    let active_cells = well.active_piece.map(|piece| piece.cells());
+    let ghost_cells = well
+        .active_piece
+        .map(|piece| well.drop_position(&piece).cells());
+    let active_color = well.active_piece.map(|piece| piece.kind.color());
+
+    for (cell, mut sprite) in cell_query.iter_mut() {
+        let pos = IVec2::new(cell.x, cell.y);
+        sprite.color = if let Some(kind) = well.cell(cell.x, cell.y) {
+            kind.color()
+        } else if active_cells.is_some_and(|cells| cells.contains(&pos)) {
+            active_color.unwrap()
+        } else if ghost_cells.is_some_and(|cells| cells.contains(&pos)) {
+            active_color.unwrap().with_a(GHOST_ALPHA)
+        } else {
+            EMPTY_CELL_COLOR
+        };
+    }
+}
+
+pub fn preview_render(
+    piece_queue: Res<PieceQueue>,
+    mut cell_query: Query<(&PreviewCell, &mut Sprite, &mut Renderable, &mut Visibility)>,
+    new_cells: Query<(), Added<PreviewCell>>,
+) {
+    if !piece_queue.is_changed() && new_cells.is_empty() {
+        return;
+    }
+
+    for (cell, mut sprite, mut renderable, mut visibility) in cell_query.iter_mut() {
+        let kind = match cell.slot {
+            PreviewSlot::Hold => piece_queue.hold,
+            PreviewSlot::Next(index) => piece_queue.next.get(index).copied(),
+        };
+        let Some(kind) = kind else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let cells = kind.cells(0);
+        let min = cells
+            .iter()
+            .fold(IVec2::splat(i32::MAX), |min, cell| min.min(*cell));
+        let max = cells
+            .iter()
+            .fold(IVec2::splat(i32::MIN), |max, cell| max.max(*cell));
+        let piece_center = (min + max).as_vec2() / 2.0;
+        let offset = (cells[cell.index].as_vec2() - piece_center) * PREVIEW_CELL_SIZE;
+
+        sprite.color = if piece_queue.can_hold || cell.slot != PreviewSlot::Hold {
+            kind.color()
+        } else {
+            Color::GRAY
+        };
+        renderable.pos = cell.center + offset;
+        *visibility = Visibility::Inherited;
+    }
+}
+
+pub fn score_change(
+    game_state: Res<GameState>,
+    mut score_query: Query<&mut Text, With<ScoreText>>,
+) {
+    if !game_state.is_changed() {
+        return;
+    }
+    for mut score_label in score_query.iter_mut() {
+        score_label.sections[1].value = format!("{}", game_state.score);
+        score_label.sections[3].value = format!("{}", game_state.level);
+        score_label.sections[5].value = format!("{}", game_state.lines);
+    }
+}
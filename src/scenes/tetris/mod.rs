@@ -0,0 +1,11 @@
+pub use tetris_scene_plugin::TetrisScenePlugin;
+
+mod components;
+mod constants;
+mod event_handlers;
+mod events;
+mod input;
+mod logic;
+mod resources;
+mod tetris_scene_plugin;
+mod tetromino;
@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::app::{App, Plugin};
+use bevy::math::IVec2;
+use bevy::prelude::{Resource, Timer, TimerMode};
+
+use crate::scenes::tetris::constants::*;
+use crate::scenes::tetris::tetromino::{Piece, SevenBag, Tetromino};
+
+pub struct ResourcesPlugin;
+
+impl Plugin for ResourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameState::default())
+            .insert_resource(Well::default())
+            .insert_resource(PieceQueue::default());
+    }
+}
+
+#[derive(Resource)]
+pub struct GameState {
+    pub score: i32,
+    pub level: u32,
+    pub lines: u32,
+    pub has_game_ended: bool,
+    pub is_soft_dropping: bool,
+    pub gravity_timer: Timer,
+    pub lock_timer: Timer,
+    pub lock_resets: u32,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        GameState {
+            score: 0,
+            level: 1,
+            lines: 0,
+            has_game_ended: false,
+            is_soft_dropping: false,
+            gravity_timer: Timer::from_seconds(gravity_interval(1), TimerMode::Repeating),
+            lock_timer: Timer::from_seconds(LOCK_DELAY, TimerMode::Once),
+            lock_resets: 0,
+        }
+    }
+}
+
+impl GameState {
+    pub fn reset(&mut self) {
+        *self = GameState::default();
+    }
+
+    pub fn add_lines(&mut self, count: u32) {
+        self.lines += count;
+        let level = (self.lines / LINES_PER_LEVEL + 1).min(MAX_LEVEL);
+        if level != self.level {
+            self.level = level;
+            self.gravity_timer
+                .set_duration(Duration::from_secs_f32(gravity_interval(level)));
+        }
+    }
+
+    /// Restarts the lock delay, unless the piece already used up its resets
+    pub fn reset_lock_delay(&mut self) {
+        if self.lock_resets < LOCK_DELAY_MAX_RESETS {
+            self.lock_resets += 1;
+            self.lock_timer.reset();
+        }
+    }
+
+    pub fn on_piece_spawned(&mut self) {
+        self.gravity_timer.reset();
+        self.lock_timer.reset();
+        self.lock_resets = 0;
+    }
+}
+
+/// Seconds it takes for a piece to fall one row on the given level
+pub fn gravity_interval(level: u32) -> f32 {
+    let level = level as f32;
+    (0.8 - (level - 1.0) * 0.007).powf(level - 1.0)
+}
+
+#[derive(Resource)]
+pub struct Well {
+    cells: Vec<Option<Tetromino>>,
+    pub active_piece: Option<Piece>,
+}
+
+impl Default for Well {
+    fn default() -> Self {
+        Well {
+            cells: vec![None; (WELL_WIDTH * (WELL_HEIGHT + WELL_BUFFER_HEIGHT)) as usize],
+            active_piece: None,
+        }
+    }
+}
+
+impl Well {
+    pub fn reset(&mut self) {
+        *self = Well::default();
+    }
+
+    fn index(x: i32, y: i32) -> Option<usize> {
+        if !(0..WELL_WIDTH).contains(&x) || !(0..WELL_HEIGHT + WELL_BUFFER_HEIGHT).contains(&y) {
+            None
+        } else {
+            Some((y * WELL_WIDTH + x) as usize)
+        }
+    }
+
+    pub fn cell(&self, x: i32, y: i32) -> Option<Tetromino> {
+        Well::index(x, y).and_then(|index| self.cells[index])
+    }
+
+    pub fn is_free(&self, cell: IVec2) -> bool {
+        Well::index(cell.x, cell.y).is_some_and(|index| self.cells[index].is_none())
+    }
+
+    pub fn fits(&self, piece: &Piece) -> bool {
+        piece.cells().iter().all(|cell| self.is_free(*cell))
+    }
+
+    pub fn is_grounded(&self, piece: &Piece) -> bool {
+        !self.fits(&piece.moved(IVec2::NEG_Y))
+    }
+
+    /// The position the piece would land on if hard dropped
+    pub fn drop_position(&self, piece: &Piece) -> Piece {
+        let mut dropped = *piece;
+        while self.fits(&dropped.moved(IVec2::NEG_Y)) {
+            dropped = dropped.moved(IVec2::NEG_Y);
+        }
+        dropped
+    }
+
+    /// Places a new piece at the top of the well; returns false if the spawn position is blocked
+    pub fn spawn(&mut self, kind: Tetromino) -> bool {
+        let min_y = kind.cells(0).iter().map(|cell| cell.y).min().unwrap();
+        let spawn_pos = IVec2::new((WELL_WIDTH - kind.box_size()) / 2, SPAWN_ROW - min_y);
+        let mut piece = Piece::new(kind, spawn_pos);
+        let fits = self.fits(&piece);
+        if fits && self.fits(&piece.moved(IVec2::NEG_Y)) {
+            // drop into the visible well right away
+            piece = piece.moved(IVec2::NEG_Y);
+        }
+        self.active_piece = Some(piece);
+        fits
+    }
+
+    pub fn lock(&mut self, piece: &Piece) {
+        for cell in piece.cells() {
+            if let Some(index) = Well::index(cell.x, cell.y) {
+                self.cells[index] = Some(piece.kind);
+            }
+        }
+    }
+
+    /// Removes every full row, moving the rows above down; returns the number of cleared rows
+    pub fn clear_full_rows(&mut self) -> u32 {
+        let width = WELL_WIDTH as usize;
+        let mut rows: Vec<&[Option<Tetromino>]> = self
+            .cells
+            .chunks(width)
+            .filter(|row| row.iter().any(|cell| cell.is_none()))
+            .collect();
+        let cleared = (WELL_HEIGHT + WELL_BUFFER_HEIGHT) as usize - rows.len();
+        if cleared == 0 {
+            return 0;
+        }
+        let empty_row = vec![None; width];
+        for _ in 0..cleared {
+            rows.push(&empty_row);
+        }
+        self.cells = rows.concat();
+        cleared as u32
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PieceQueue {
+    bag: SevenBag,
+    pub next: VecDeque<Tetromino>,
+    pub hold: Option<Tetromino>,
+    pub can_hold: bool,
+}
+
+impl PieceQueue {
+    pub fn reset(&mut self) {
+        *self = PieceQueue::default();
+        for _ in 0..NEXT_QUEUE_SIZE {
+            let kind = self.bag.next();
+            self.next.push_back(kind);
+        }
+    }
+
+    pub fn pop(&mut self) -> Tetromino {
+        let kind = self.bag.next();
+        self.next.push_back(kind);
+        self.can_hold = true;
+        self.next.pop_front().unwrap()
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::app::App;
+use bevy::prelude::*;
+
+use crate::common::AppState;
+use crate::scenes::tetris::components::TetrisEntity;
+use crate::scenes::tetris::event_handlers::EventHandlerPlugin;
+use crate::scenes::tetris::events::{EventsPlugin, RestartGameEvent};
+use crate::scenes::tetris::input::InputPlugin;
+use crate::scenes::tetris::logic::LogicPlugin;
+use crate::scenes::tetris::resources::ResourcesPlugin;
+
+pub struct TetrisScenePlugin;
+
+impl Plugin for TetrisScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResourcesPlugin)
+            .add_plugin(EventsPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(EventHandlerPlugin)
+            .add_plugin(LogicPlugin)
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::Tetris)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::Tetris)));
+    }
+}
+
+fn setup_scene(mut restart_events: EventWriter<RestartGameEvent>) {
+    restart_events.send_default();
+}
+
+fn despawn_game(mut commands: Commands, entities: Query<Entity, With<TetrisEntity>>) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn();
+    }
+}
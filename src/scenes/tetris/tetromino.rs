@@ -0,0 +1,181 @@
+use bevy::math::IVec2;
+use bevy::prelude::Color;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::scenes::tetris::tetromino::Tetromino::*;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Tetromino {
+    I,
+    O,
+    T,
+    S,
+    Z,
+    J,
+    L,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RotationDirection {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Tetromino {
+    pub fn all() -> [Tetromino; 7] {
+        [I, O, T, S, Z, J, L]
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            I => Color::CYAN,
+            O => Color::YELLOW,
+            T => Color::PURPLE,
+            S => Color::GREEN,
+            Z => Color::RED,
+            J => Color::BLUE,
+            L => Color::ORANGE,
+        }
+    }
+
+    /// Size of the square bounding box the piece rotates in
+    pub fn box_size(self) -> i32 {
+        match self {
+            I => 4,
+            O => 2,
+            _ => 3,
+        }
+    }
+
+    /// Cells of the spawn orientation inside the bounding box, y pointing up
+    fn spawn_cells(self) -> [IVec2; 4] {
+        let cells = match self {
+            I => [(0, 2), (1, 2), (2, 2), (3, 2)],
+            O => [(0, 0), (1, 0), (0, 1), (1, 1)],
+            T => [(0, 1), (1, 1), (2, 1), (1, 2)],
+            S => [(0, 1), (1, 1), (1, 2), (2, 2)],
+            Z => [(0, 2), (1, 2), (1, 1), (2, 1)],
+            J => [(0, 2), (0, 1), (1, 1), (2, 1)],
+            L => [(0, 1), (1, 1), (2, 1), (2, 2)],
+        };
+        cells.map(|(x, y)| IVec2::new(x, y))
+    }
+
+    /// Cells of the piece inside its bounding box for the given rotation state (0, R, 2, L)
+    pub fn cells(self, rotation: usize) -> [IVec2; 4] {
+        let size = self.box_size();
+        let mut cells = self.spawn_cells();
+        for _ in 0..rotation % 4 {
+            cells = cells.map(|cell| IVec2::new(cell.y, size - 1 - cell.x));
+        }
+        cells
+    }
+
+    /// SRS wall kick offsets to try when rotating from the given state
+    pub fn wall_kicks(self, rotation: usize, direction: RotationDirection) -> &'static [IVec2; 5] {
+        let table = match (self, direction) {
+            (O, _) => return &NO_KICKS,
+            (I, RotationDirection::Clockwise) => &I_KICKS_CLOCKWISE,
+            (I, RotationDirection::CounterClockwise) => &I_KICKS_COUNTER_CLOCKWISE,
+            (_, RotationDirection::Clockwise) => &JLSTZ_KICKS_CLOCKWISE,
+            (_, RotationDirection::CounterClockwise) => &JLSTZ_KICKS_COUNTER_CLOCKWISE,
+        };
+        &table[rotation % 4]
+    }
+}
+
+const fn kicks(offsets: [(i32, i32); 5]) -> [IVec2; 5] {
+    [
+        IVec2::new(offsets[0].0, offsets[0].1),
+        IVec2::new(offsets[1].0, offsets[1].1),
+        IVec2::new(offsets[2].0, offsets[2].1),
+        IVec2::new(offsets[3].0, offsets[3].1),
+        IVec2::new(offsets[4].0, offsets[4].1),
+    ]
+}
+
+static NO_KICKS: [IVec2; 5] = [IVec2::ZERO; 5];
+
+// Indexed by the starting rotation state: 0->R, R->2, 2->L, L->0
+static JLSTZ_KICKS_CLOCKWISE: [[IVec2; 5]; 4] = [
+    kicks([(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)]),
+    kicks([(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)]),
+    kicks([(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)]),
+    kicks([(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)]),
+];
+
+// Indexed by the starting rotation state: 0->L, R->0, 2->R, L->2
+static JLSTZ_KICKS_COUNTER_CLOCKWISE: [[IVec2; 5]; 4] = [
+    kicks([(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)]),
+    kicks([(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)]),
+    kicks([(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)]),
+    kicks([(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)]),
+];
+
+static I_KICKS_CLOCKWISE: [[IVec2; 5]; 4] = [
+    kicks([(0, 0), (-2, 0), (1, 0), (-2, -1), (1, 2)]),
+    kicks([(0, 0), (-1, 0), (2, 0), (-1, 2), (2, -1)]),
+    kicks([(0, 0), (2, 0), (-1, 0), (2, 1), (-1, -2)]),
+    kicks([(0, 0), (1, 0), (-2, 0), (1, -2), (-2, 1)]),
+];
+
+static I_KICKS_COUNTER_CLOCKWISE: [[IVec2; 5]; 4] = [
+    kicks([(0, 0), (-1, 0), (2, 0), (-1, 2), (2, -1)]),
+    kicks([(0, 0), (2, 0), (-1, 0), (2, 1), (-1, -2)]),
+    kicks([(0, 0), (1, 0), (-2, 0), (1, -2), (-2, 1)]),
+    kicks([(0, 0), (-2, 0), (1, 0), (-2, -1), (1, 2)]),
+];
+
+#[derive(Copy, Clone, Debug)]
+pub struct Piece {
+    pub kind: Tetromino,
+    pub rotation: usize,
+    /// Position of the bottom left corner of the bounding box in the well
+    pub pos: IVec2,
+}
+
+impl Piece {
+    pub fn new(kind: Tetromino, pos: IVec2) -> Self {
+        Piece {
+            kind,
+            rotation: 0,
+            pos,
+        }
+    }
+
+    pub fn cells(&self) -> [IVec2; 4] {
+        self.kind.cells(self.rotation).map(|cell| cell + self.pos)
+    }
+
+    pub fn moved(&self, offset: IVec2) -> Self {
+        Piece {
+            pos: self.pos + offset,
+            ..*self
+        }
+    }
+
+    pub fn rotated(&self, direction: RotationDirection) -> Self {
+        let rotation = match direction {
+            RotationDirection::Clockwise => (self.rotation + 1) % 4,
+            RotationDirection::CounterClockwise => (self.rotation + 3) % 4,
+        };
+        Piece { rotation, ..*self }
+    }
+}
+
+/// 7-bag randomizer: every tetromino appears exactly once in each group of seven
+#[derive(Default)]
+pub struct SevenBag {
+    bag: Vec<Tetromino>,
+}
+
+impl SevenBag {
+    pub fn next(&mut self) -> Tetromino {
+        if self.bag.is_empty() {
+            self.bag = Tetromino::all().to_vec();
+            self.bag.shuffle(&mut thread_rng());
+        }
+        self.bag.pop().unwrap()
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct CycleHead {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct TrailCell;
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct RoundBannerText;
+
+#[derive(Component)]
+pub struct MatchSetupText;
+
+#[derive(Component)]
+pub struct MatchSetupUIElement;
+
+#[derive(Component)]
+pub struct RoundOverUIElement;
+
+#[derive(Component)]
+pub struct EndGameUIElement;
+
+#[derive(Component)]
+pub struct TronEntity;
@@ -0,0 +1,50 @@
+use bevy::math::IVec2;
+use bevy::prelude::Color;
+
+pub const PLAY_AREA_WIDTH: f32 = 640.0;
+pub const PLAY_AREA_HEIGHT: f32 = 480.0;
+
+pub const ARENA_WIDTH: i32 = 76;
+pub const ARENA_HEIGHT: i32 = 52;
+pub const CELL_SIZE: f32 = 8.0;
+/// The arena sits below the score line
+pub const ARENA_OFFSET_Y: f32 = -16.0;
+pub const WALL_THICKNESS: f32 = 4.0;
+
+pub const MIN_CYCLES: usize = 2;
+pub const MAX_CYCLES: usize = 4;
+pub const MAX_HUMAN_PLAYERS: usize = 2;
+pub const ROUNDS_TO_WIN_OPTIONS: [u32; 4] = [1, 3, 5, 7];
+
+/// Seconds between two steps of the light cycles
+pub const STEP_INTERVAL: f32 = 0.05;
+pub const ROUND_START_DELAY: f32 = 1.5;
+pub const ROUND_OVER_DELAY: f32 = 2.0;
+/// Turns that can be queued ahead of the cycle, so quick double-taps are not lost
+pub const MAX_QUEUED_TURNS: usize = 2;
+
+/// Cells the CPU flood fills when weighing the space behind a turn
+pub const CPU_LOOKAHEAD_CELLS: usize = 300;
+/// Chance the CPU turns for no reason while going straight is still safe
+pub const CPU_RANDOM_TURN_CHANCE: f64 = 0.03;
+
+/// Start cell and heading of each cycle, in spawn order
+pub const START_POSITIONS: [(IVec2, IVec2); MAX_CYCLES] = [
+    (IVec2::new(8, ARENA_HEIGHT / 2), IVec2::X),
+    (
+        IVec2::new(ARENA_WIDTH - 9, ARENA_HEIGHT / 2 - 1),
+        IVec2::NEG_X,
+    ),
+    (IVec2::new(ARENA_WIDTH / 2 - 1, 6), IVec2::Y),
+    (IVec2::new(ARENA_WIDTH / 2, ARENA_HEIGHT - 7), IVec2::NEG_Y),
+];
+pub const CYCLE_COLORS: [Color; MAX_CYCLES] = [
+    Color::rgb(0.2, 0.8, 1.0),
+    Color::rgb(1.0, 0.55, 0.1),
+    Color::rgb(0.9, 0.3, 0.9),
+    Color::rgb(0.6, 1.0, 0.2),
+];
+pub const WALL_COLOR: Color = Color::rgb(0.3, 0.8, 0.3);
+pub const ARENA_COLOR: Color = Color::rgb(0.02, 0.05, 0.08);
+pub const CRASHED_COLOR: Color = Color::GRAY;
+pub const CRASH_COLOR: Color = Color::rgba(1.0, 0.2, 0.1, 0.8);
@@ -0,0 +1,514 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::constants::FONT_FILE;
+use crate::music::DuckMusicEvent;
+use crate::scenes::tron::components::*;
+use crate::scenes::tron::constants::*;
+use crate::scenes::tron::events::*;
+use crate::scenes::tron::input::*;
+use crate::scenes::tron::logic::{cycle_step, round_over_countdown, round_progression};
+use crate::scenes::tron::resources::{cell_pos, Arena, Cycle, GameState, MatchSettings, Screen};
+
+pub struct EventHandlerPlugin;
+
+impl Plugin for EventHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            turn_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(player_input),
+        )
+        .add_system(
+            cycle_crashed_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(cycle_step),
+        )
+        .add_system(
+            round_over_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(round_progression)
+                .before(round_over_countdown),
+        )
+        .add_system(
+            match_over_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(round_over_event_handler),
+        )
+        .add_system(
+            start_match_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(match_over_event_handler)
+                .after(match_setup_input)
+                .after(end_game_input),
+        )
+        .add_system(
+            start_round_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(round_over_countdown)
+                .after(start_match_event_handler),
+        )
+        .add_system(
+            show_match_setup_event_handler
+                .in_set(OnUpdate(AppState::Tron))
+                .after(start_round_event_handler),
+        );
+    }
+}
+
+/// Trails, heads and banners of the round being played
+type RoundEntitiesFilter = Or<(
+    With<TrailCell>,
+    With<CycleHead>,
+    With<RoundBannerText>,
+    With<RoundOverUIElement>,
+)>;
+
+/// Screens shown between matches
+type MatchUIFilter = Or<(With<MatchSetupUIElement>, With<EndGameUIElement>)>;
+
+fn cell_sprite(
+    color: Color,
+    pos: IVec2,
+    size: f32,
+    z: f32,
+    scale: f32,
+) -> (SpriteBundle, Renderable) {
+    let pos = cell_pos(pos);
+    (
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, z)
+                .with_scale(Vec3::new(scale, scale, 1.0)),
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT))
+            .with_size(Vec2::splat(size)),
+    )
+}
+
+fn text(
+    value: impl Into<String>,
+    font: Handle<Font>,
+    font_size: f32,
+    pos: Vec2,
+    anchor: Anchor,
+    scale: f32,
+) -> (Text2dBundle, Renderable) {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 11.0),
+            text_anchor: anchor,
+            ..default()
+        },
+        Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_scale(false, false),
+    )
+}
+
+fn overlay(viewport_size: &ViewportSize, alpha: f32) -> (SpriteBundle, Renderable) {
+    let overlay_size = Vec2::new(viewport_size.width, viewport_size.height);
+    (
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.0, 0.0, 0.0, alpha),
+                custom_size: Some(overlay_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 10.0),
+            ..default()
+        },
+        Renderable::new(Vec2::ZERO, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT))
+            .with_size(overlay_size),
+    )
+}
+
+pub fn spawn_trail_cell(commands: &mut Commands, pos: IVec2, color: Color, scale: f32) {
+    commands.spawn((
+        cell_sprite(color, pos, CELL_SIZE, 1.0, scale),
+        TrailCell,
+        TronEntity,
+    ));
+}
+
+/// Walls around the arena and the score line, which stay for the whole scene
+pub fn spawn_arena(commands: &mut Commands, asset_server: &AssetServer, scale: f32) {
+    let arena_size = Vec2::new(ARENA_WIDTH as f32, ARENA_HEIGHT as f32) * CELL_SIZE;
+    let center = Vec2::new(0.0, ARENA_OFFSET_Y);
+    let walls = [
+        (
+            Vec2::new(0.0, (arena_size.y + WALL_THICKNESS) / 2.0),
+            Vec2::new(arena_size.x + WALL_THICKNESS * 2.0, WALL_THICKNESS),
+        ),
+        (
+            Vec2::new(0.0, -(arena_size.y + WALL_THICKNESS) / 2.0),
+            Vec2::new(arena_size.x + WALL_THICKNESS * 2.0, WALL_THICKNESS),
+        ),
+        (
+            Vec2::new(-(arena_size.x + WALL_THICKNESS) / 2.0, 0.0),
+            Vec2::new(WALL_THICKNESS, arena_size.y),
+        ),
+        (
+            Vec2::new((arena_size.x + WALL_THICKNESS) / 2.0, 0.0),
+            Vec2::new(WALL_THICKNESS, arena_size.y),
+        ),
+        (Vec2::ZERO, arena_size),
+    ];
+    for (index, (offset, size)) in walls.into_iter().enumerate() {
+        let pos = center + offset;
+        let color = if index == walls.len() - 1 {
+            ARENA_COLOR
+        } else {
+            WALL_COLOR
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x * scale, pos.y * scale, 0.0)
+                    .with_scale(Vec3::new(scale, scale, 1.0)),
+                ..default()
+            },
+            TronEntity,
+            Renderable::new(pos, Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT)).with_size(size),
+        ));
+    }
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        text(
+            "",
+            font,
+            24.0,
+            Vec2::new(0.0, PLAY_AREA_HEIGHT / 2.0 - 2.0),
+            Anchor::TopCenter,
+            scale,
+        ),
+        ScoreText,
+        TronEntity,
+    ));
+}
+
+pub fn turn_event_handler(mut events: EventReader<TurnEvent>, mut arena: ResMut<Arena>) {
+    for event in events.iter() {
+        let Some(cycle) = arena.cycles.get_mut(event.cycle) else { continue; };
+        if cycle.is_alive && !cycle.is_cpu {
+            cycle.queue_turn(event.dir);
+        }
+    }
+}
+
+/// Marks where a cycle went down
+pub fn cycle_crashed_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<CycleCrashedEvent>,
+    arena: Res<Arena>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for event in events.iter() {
+        let cycle = &arena.cycles[event.cycle];
+        commands.spawn((
+            cell_sprite(
+                CRASH_COLOR,
+                cycle.pos + cycle.dir,
+                CELL_SIZE * 2.0,
+                3.0,
+                scale,
+            ),
+            TrailCell,
+            TronEntity,
+        ));
+    }
+}
+
+pub fn round_over_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<RoundOverEvent>,
+    asset_server: Res<AssetServer>,
+    settings: Res<MatchSettings>,
+    mut game_state: ResMut<GameState>,
+    mut match_over_events: EventWriter<MatchOverEvent>,
+    viewport_size: Res<ViewportSize>,
+) {
+    let Some(winner) = events.iter().last().map(|event| event.winner) else { return; };
+
+    game_state.screen = Screen::RoundOver;
+    game_state.round_over_timer.reset();
+    if let Some(winner) = winner {
+        game_state.scores[winner] += 1;
+        if game_state.scores[winner] >= settings.rounds_to_win() {
+            match_over_events.send(MatchOverEvent { winner });
+            return;
+        }
+    }
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    let (message, color) = match winner {
+        Some(winner) => (
+            format!("{} wins the round", settings.cycle_name(winner)),
+            CYCLE_COLORS[winner],
+        ),
+        None => ("Draw".to_string(), Color::WHITE),
+    };
+    let font = asset_server.load(FONT_FILE.to_string());
+    let mut banner = text(message, font, 48.0, Vec2::ZERO, Anchor::Center, scale);
+    banner.0.text.sections[0].style.color = color;
+    commands.spawn((banner, RoundOverUIElement, TronEntity));
+}
+
+pub fn match_over_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<MatchOverEvent>,
+    asset_server: Res<AssetServer>,
+    settings: Res<MatchSettings>,
+    mut game_state: ResMut<GameState>,
+    viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    let Some(winner) = events.iter().last().map(|event| event.winner) else { return; };
+    duck_music_events.send(DuckMusicEvent { is_ducked: true });
+    game_state.screen = Screen::MatchOver;
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    commands.spawn((overlay(&viewport_size, 0.85), EndGameUIElement, TronEntity));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    let title_pos = Vec2::new(0.0, 40.0);
+    let mut title = text(
+        format!("{} Wins", settings.cycle_name(winner)),
+        font.clone(),
+        80.0,
+        title_pos,
+        Anchor::BottomCenter,
+        scale,
+    );
+    title.0.text.sections[0].style.color = CYCLE_COLORS[winner];
+    commands.spawn((title, EndGameUIElement, TronEntity));
+
+    let standings = game_state
+        .scores
+        .iter()
+        .enumerate()
+        .map(|(index, score)| format!("{}: {}", settings.cycle_name(index), score))
+        .collect::<Vec<_>>()
+        .join("\n");
+    commands.spawn((
+        text(
+            format!("Rounds: {}\n{}", game_state.round, standings),
+            font.clone(),
+            32.0,
+            title_pos,
+            Anchor::TopCenter,
+            scale,
+        ),
+        EndGameUIElement,
+        TronEntity,
+    ));
+    commands.spawn((
+        text(
+            "[Space]: Rematch\n[Enter]: Match Setup\n[Esc]: Pause",
+            font,
+            20.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        EndGameUIElement,
+        TronEntity,
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_match_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<StartMatchEvent>,
+    asset_server: Res<AssetServer>,
+    settings: Res<MatchSettings>,
+    mut game_state: ResMut<GameState>,
+    mut arena: ResMut<Arena>,
+    entities_query: Query<Entity, Or<(RoundEntitiesFilter, MatchUIFilter)>>,
+    viewport_size: Res<ViewportSize>,
+    mut duck_music_events: EventWriter<DuckMusicEvent>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+    duck_music_events.send(DuckMusicEvent { is_ducked: false });
+
+    for entity in entities_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    game_state.round = 0;
+    game_state.scores = vec![0; settings.cycle_count()];
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    start_round(
+        &mut commands,
+        &asset_server,
+        &settings,
+        &mut game_state,
+        &mut arena,
+        scale,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_round_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<StartRoundEvent>,
+    asset_server: Res<AssetServer>,
+    settings: Res<MatchSettings>,
+    mut game_state: ResMut<GameState>,
+    mut arena: ResMut<Arena>,
+    round_entities_query: Query<Entity, RoundEntitiesFilter>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    for entity in round_entities_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    start_round(
+        &mut commands,
+        &asset_server,
+        &settings,
+        &mut game_state,
+        &mut arena,
+        scale,
+    );
+}
+
+fn start_round(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    settings: &MatchSettings,
+    game_state: &mut GameState,
+    arena: &mut Arena,
+    scale: f32,
+) {
+    game_state.round += 1;
+    game_state.screen = Screen::Playing;
+    game_state.step_timer.reset();
+    game_state.round_start_timer.reset();
+
+    arena.occupied.clear();
+    arena.cycles = START_POSITIONS
+        .iter()
+        .take(settings.cycle_count())
+        .enumerate()
+        .map(|(index, (pos, dir))| Cycle::new(*pos, *dir, index >= settings.human_players))
+        .collect();
+    for (index, cycle) in arena.cycles.iter().enumerate() {
+        commands.spawn((
+            cell_sprite(Color::WHITE, cycle.pos, CELL_SIZE * 1.5, 2.0, scale),
+            CycleHead { index },
+            TronEntity,
+        ));
+        spawn_trail_cell(commands, cycle.pos, CYCLE_COLORS[index], scale);
+    }
+    let starts: Vec<IVec2> = arena.cycles.iter().map(|cycle| cycle.pos).collect();
+    arena.occupied.extend(starts);
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        text(
+            format!("Round {}", game_state.round),
+            font,
+            48.0,
+            Vec2::new(0.0, 60.0),
+            Anchor::Center,
+            scale,
+        ),
+        RoundBannerText,
+        TronEntity,
+    ));
+}
+
+pub fn show_match_setup_event_handler(
+    mut commands: Commands,
+    mut events: EventReader<ShowMatchSetupEvent>,
+    asset_server: Res<AssetServer>,
+    mut game_state: ResMut<GameState>,
+    mut arena: ResMut<Arena>,
+    entities_query: Query<Entity, Or<(RoundEntitiesFilter, MatchUIFilter)>>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    for entity in entities_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    game_state.screen = Screen::MatchSetup;
+    game_state.scores.clear();
+    arena.cycles.clear();
+    arena.occupied.clear();
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    commands.spawn((
+        overlay(&viewport_size, 0.6),
+        MatchSetupUIElement,
+        TronEntity,
+    ));
+
+    let font = asset_server.load(FONT_FILE.to_string());
+    commands.spawn((
+        text(
+            "Light Cycles",
+            font.clone(),
+            64.0,
+            Vec2::new(0.0, 120.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        MatchSetupUIElement,
+        TronEntity,
+    ));
+    // the options are filled in by `match_setup_update`
+    let mut options = text(
+        "",
+        font.clone(),
+        32.0,
+        Vec2::new(0.0, 80.0),
+        Anchor::TopCenter,
+        scale,
+    );
+    options.0.text.alignment = TextAlignment::Left;
+    commands.spawn((options, MatchSetupText, MatchSetupUIElement, TronEntity));
+    commands.spawn((
+        text(
+            "[Up/Down]: Choose  [Left/Right]: Change
+[Enter]: Start  [Esc]: Pause",
+            font,
+            20.0,
+            Vec2::new(0.0, -PLAY_AREA_HEIGHT / 2.0 + 10.0),
+            Anchor::BottomCenter,
+            scale,
+        ),
+        MatchSetupUIElement,
+        TronEntity,
+    ));
+}
@@ -0,0 +1,43 @@
+use bevy::app::{App, Plugin};
+use bevy::math::IVec2;
+
+pub struct EventsPlugin;
+
+impl Plugin for EventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TurnEvent>()
+            .add_event::<CycleCrashedEvent>()
+            .add_event::<RoundOverEvent>()
+            .add_event::<StartRoundEvent>()
+            .add_event::<StartMatchEvent>()
+            .add_event::<MatchOverEvent>()
+            .add_event::<ShowMatchSetupEvent>();
+    }
+}
+
+pub struct TurnEvent {
+    pub cycle: usize,
+    pub dir: IVec2,
+}
+
+pub struct CycleCrashedEvent {
+    pub cycle: usize,
+}
+
+pub struct RoundOverEvent {
+    /// `None` when the last cycles crashed together
+    pub winner: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct StartRoundEvent;
+
+#[derive(Default)]
+pub struct StartMatchEvent;
+
+pub struct MatchOverEvent {
+    pub winner: usize,
+}
+
+#[derive(Default)]
+pub struct ShowMatchSetupEvent;
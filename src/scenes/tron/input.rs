@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+
+use crate::actions::{Action, ActionBindings, Actions, Binding};
+use crate::common::{AppState, Game};
+use crate::scenes::tron::events::*;
+use crate::scenes::tron::resources::{GameState, MatchSettings, Screen, SetupOption};
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(player_input.in_set(OnUpdate(AppState::Tron)))
+            .add_system(match_setup_input.in_set(OnUpdate(AppState::Tron)))
+            .add_system(end_game_input.in_set(OnUpdate(AppState::Tron)));
+    }
+}
+
+const ARROW_KEYS: [(KeyCode, IVec2); 4] = [
+    (KeyCode::Up, IVec2::Y),
+    (KeyCode::Down, IVec2::NEG_Y),
+    (KeyCode::Left, IVec2::NEG_X),
+    (KeyCode::Right, IVec2::X),
+];
+const DIRECTION_ACTIONS: [(Action, IVec2); 4] = [
+    (Action::MoveUp, IVec2::Y),
+    (Action::MoveDown, IVec2::NEG_Y),
+    (Action::MoveLeft, IVec2::NEG_X),
+    (Action::MoveRight, IVec2::X),
+];
+
+/// Bindings player one steers up, down, left and right with, without the arrows when they steer
+/// player two
+pub fn player_one_bindings(action_bindings: &ActionBindings, human_players: usize) -> [Binding; 4] {
+    DIRECTION_ACTIONS.map(|(action, _)| {
+        let mut binding = action_bindings.binding(Some(Game::Tron), action);
+        if human_players > 1 {
+            binding
+                .keys
+                .retain(|key| ARROW_KEYS.iter().all(|(arrow, _)| arrow != key));
+        }
+        binding
+    })
+}
+
+/// Player one steers with their rebindable keys, WASD and the arrows by default, and player two
+/// with the arrows, which player one then no longer uses. The first gamepads steer the cycles of
+/// the human players in order.
+pub fn player_input(
+    keys: Res<Input<KeyCode>>,
+    actions: Res<Actions>,
+    action_bindings: Res<ActionBindings>,
+    game_state: Res<GameState>,
+    settings: Res<MatchSettings>,
+    mut turn_events: EventWriter<TurnEvent>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    let player_one = player_one_bindings(&action_bindings, settings.human_players);
+    // every press of the frame is forwarded, in order, so none of them gets lost
+    for (&(_, dir), binding) in DIRECTION_ACTIONS.iter().zip(player_one) {
+        if keys.any_just_pressed(binding.keys) {
+            turn_events.send(TurnEvent { cycle: 0, dir });
+        }
+    }
+    if settings.human_players > 1 {
+        for (key, dir) in ARROW_KEYS {
+            if keys.just_pressed(key) {
+                turn_events.send(TurnEvent { cycle: 1, dir });
+            }
+        }
+    }
+    for cycle in 0..settings.human_players {
+        let Some(gamepad) = actions.gamepad(cycle) else { break; };
+        for (action, dir) in DIRECTION_ACTIONS {
+            if gamepad.just_pressed(action) {
+                turn_events.send(TurnEvent { cycle, dir });
+            }
+        }
+    }
+}
+
+pub fn match_setup_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut settings: ResMut<MatchSettings>,
+    mut start_match_events: EventWriter<StartMatchEvent>,
+) {
+    if game_state.screen != Screen::MatchSetup {
+        return;
+    }
+
+    let option_count = SetupOption::ALL.len();
+    if actions.just_pressed(Action::MoveUp) {
+        settings.selected = (settings.selected + option_count - 1) % option_count;
+    }
+    if actions.just_pressed(Action::MoveDown) {
+        settings.selected = (settings.selected + 1) % option_count;
+    }
+    if actions.just_pressed(Action::MoveLeft) {
+        settings.adjust(-1);
+    }
+    if actions.just_pressed(Action::MoveRight) {
+        settings.adjust(1);
+    }
+    if actions.just_pressed(Action::Confirm) {
+        start_match_events.send_default();
+    }
+}
+
+pub fn end_game_input(
+    actions: Res<Actions>,
+    game_state: Res<GameState>,
+    mut start_match_events: EventWriter<StartMatchEvent>,
+    mut show_match_setup_events: EventWriter<ShowMatchSetupEvent>,
+) {
+    if game_state.screen != Screen::MatchOver {
+        return;
+    }
+
+    // Space and the south button fire as well as confirm, so they are checked first
+    if actions.just_pressed(Action::Fire) {
+        start_match_events.send_default();
+    } else if actions.just_pressed(Action::Confirm) {
+        show_match_setup_events.send_default();
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+
+use crate::actions::{ActionBindings, Binding};
+use crate::common::{AppState, Renderable, ViewportSize};
+use crate::scenes::tron::components::*;
+use crate::scenes::tron::constants::*;
+use crate::scenes::tron::event_handlers::{spawn_trail_cell, turn_event_handler};
+use crate::scenes::tron::events::*;
+use crate::scenes::tron::input::player_one_bindings;
+use crate::scenes::tron::resources::{
+    cell_pos, Arena, GameState, MatchSettings, Screen, SetupOption,
+};
+
+pub struct LogicPlugin;
+
+impl Plugin for LogicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (cycle_step, round_progression, round_over_countdown)
+                .chain()
+                .in_set(OnUpdate(AppState::Tron))
+                .after(turn_event_handler),
+        )
+        .add_system(
+            hud_update
+                .in_set(OnUpdate(AppState::Tron))
+                .after(round_progression),
+        )
+        .add_system(match_setup_update.in_set(OnUpdate(AppState::Tron)));
+    }
+}
+
+/// Picks the heading leaving the most room, preferring to keep going straight
+fn steer_cpu(arena: &Arena, index: usize) -> IVec2 {
+    let cycle = &arena.cycles[index];
+    let left = cycle.dir.perp();
+    let right = -left;
+    let space = |dir: IVec2| arena.free_space(cycle.pos + dir, CPU_LOOKAHEAD_CELLS);
+
+    let straight_space = space(cycle.dir);
+    let mut best = (cycle.dir, straight_space);
+    let mut turns = [left, right];
+    if thread_rng().gen_bool(0.5) {
+        turns.reverse();
+    }
+    let wants_random_turn = thread_rng().gen_bool(CPU_RANDOM_TURN_CHANCE);
+    for dir in turns {
+        let dir_space = space(dir);
+        let is_better = dir_space > best.1
+            || (wants_random_turn && dir_space > 0 && dir_space >= straight_space);
+        if is_better {
+            best = (dir, dir_space);
+        }
+    }
+    best.0
+}
+
+/// Advances every cycle one cell per step; cycles that hit a wall, a trail or another head crash
+pub fn cycle_step(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut arena: ResMut<Arena>,
+    mut crashed_events: EventWriter<CycleCrashedEvent>,
+    viewport_size: Res<ViewportSize>,
+) {
+    if game_state.screen != Screen::Playing {
+        return;
+    }
+
+    game_state.round_start_timer.tick(time.delta());
+    if !game_state.round_start_timer.finished() {
+        return;
+    }
+    game_state.step_timer.tick(time.delta());
+
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    for _ in 0..game_state.step_timer.times_finished_this_tick() {
+        for index in 0..arena.cycles.len() {
+            if !arena.cycles[index].is_alive {
+                continue;
+            }
+            if arena.cycles[index].is_cpu {
+                arena.cycles[index].dir = steer_cpu(&arena, index);
+            } else {
+                arena.cycles[index].apply_next_turn();
+            }
+        }
+
+        let targets: Vec<Option<IVec2>> = arena
+            .cycles
+            .iter()
+            .map(|cycle| cycle.is_alive.then_some(cycle.pos + cycle.dir))
+            .collect();
+        for (index, target) in targets.iter().enumerate() {
+            let Some(target) = *target else { continue; };
+            let is_head_on = targets
+                .iter()
+                .enumerate()
+                .any(|(other, other_target)| other != index && *other_target == Some(target));
+            if is_head_on || !arena.is_free(target) {
+                arena.cycles[index].is_alive = false;
+                crashed_events.send(CycleCrashedEvent { cycle: index });
+            }
+        }
+        for (index, target) in targets.iter().enumerate() {
+            let Some(target) = *target else { continue; };
+            if !arena.cycles[index].is_alive {
+                continue;
+            }
+            arena.cycles[index].pos = target;
+            arena.occupied.insert(target);
+            spawn_trail_cell(&mut commands, target, CYCLE_COLORS[index], scale);
+        }
+
+        if arena.alive_count() <= 1 {
+            break;
+        }
+    }
+}
+
+pub fn round_progression(
+    game_state: Res<GameState>,
+    arena: Res<Arena>,
+    mut round_over_events: EventWriter<RoundOverEvent>,
+) {
+    if game_state.screen != Screen::Playing || arena.alive_count() > 1 {
+        return;
+    }
+
+    let winner = arena.cycles.iter().position(|cycle| cycle.is_alive);
+    round_over_events.send(RoundOverEvent { winner });
+}
+
+pub fn round_over_countdown(
+    time: Res<Time>,
+    mut game_state: ResMut<GameState>,
+    mut start_round_events: EventWriter<StartRoundEvent>,
+) {
+    if game_state.screen != Screen::RoundOver {
+        return;
+    }
+
+    game_state.round_over_timer.tick(time.delta());
+    if game_state.round_over_timer.just_finished() {
+        start_round_events.send_default();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn hud_update(
+    game_state: Res<GameState>,
+    settings: Res<MatchSettings>,
+    arena: Res<Arena>,
+    mut head_query: Query<(&CycleHead, &mut Sprite, &mut Renderable)>,
+    mut score_query: Query<&mut Text, (With<ScoreText>, Without<RoundBannerText>)>,
+    mut banner_query: Query<&mut Visibility, With<RoundBannerText>>,
+) {
+    for (head, mut sprite, mut renderable) in head_query.iter_mut() {
+        let cycle = &arena.cycles[head.index];
+        renderable.pos = cell_pos(cycle.pos);
+        sprite.color = if cycle.is_alive {
+            Color::WHITE
+        } else {
+            CRASHED_COLOR
+        };
+    }
+    for mut visibility in banner_query.iter_mut() {
+        *visibility = if game_state.round_start_timer.finished() {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+
+    if !game_state.is_changed() {
+        return;
+    }
+    for mut text in score_query.iter_mut() {
+        let style = text.sections[0].style.clone();
+        text.sections = game_state
+            .scores
+            .iter()
+            .enumerate()
+            .map(|(index, score)| TextSection {
+                value: format!("{}: {}   ", settings.cycle_name(index), score),
+                style: TextStyle {
+                    color: CYCLE_COLORS[index],
+                    ..style.clone()
+                },
+            })
+            .collect();
+        if text.sections.is_empty() {
+            text.sections.push(TextSection::new("", style));
+        }
+    }
+}
+
+pub fn match_setup_update(
+    settings: Res<MatchSettings>,
+    action_bindings: Res<ActionBindings>,
+    mut text_query: Query<&mut Text, With<MatchSetupText>>,
+) {
+    let rows: Vec<String> = SetupOption::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let marker = if index == settings.selected { ">" } else { " " };
+            let (label, value) = match option {
+                SetupOption::HumanPlayers => ("Players", settings.human_players),
+                SetupOption::CpuOpponents => ("CPU opponents", settings.cpu_opponents),
+                SetupOption::RoundsToWin => ("Rounds to win", settings.rounds_to_win() as usize),
+            };
+            format!("{} {:<14} < {} >", marker, label, value)
+        })
+        .collect();
+    let player_one_keys = player_one_bindings(&action_bindings, settings.human_players)
+        .iter()
+        .map(Binding::keys_label)
+        .collect::<Vec<_>>()
+        .join("/");
+    let controls: Vec<String> = [format!("[{}]", player_one_keys), "[Arrows]".to_string()]
+        .into_iter()
+        .take(settings.human_players)
+        .enumerate()
+        .map(|(index, keys)| format!("P{}: {}", index + 1, keys))
+        .collect();
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("{}\n\n{}", rows.join("\n"), controls.join("   "));
+    }
+}
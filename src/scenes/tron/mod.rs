@@ -0,0 +1,10 @@
+pub use tron_scene_plugin::TronScenePlugin;
+
+mod components;
+mod constants;
+mod event_handlers;
+mod events;
+mod input;
+mod logic;
+mod resources;
+mod tron_scene_plugin;
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+
+use bevy::app::{App, Plugin};
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{Resource, Timer, TimerMode};
+use bevy::utils::HashSet;
+
+use crate::scenes::tron::constants::*;
+
+pub struct ResourcesPlugin;
+
+impl Plugin for ResourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameState::default())
+            .insert_resource(MatchSettings::default())
+            .insert_resource(Arena::default());
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Screen {
+    #[default]
+    MatchSetup,
+    Playing,
+    RoundOver,
+    MatchOver,
+}
+
+#[derive(Resource)]
+pub struct GameState {
+    pub screen: Screen,
+    pub round: u32,
+    pub scores: Vec<u32>,
+    pub step_timer: Timer,
+    pub round_start_timer: Timer,
+    pub round_over_timer: Timer,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        GameState {
+            screen: Screen::MatchSetup,
+            round: 0,
+            scores: Vec::new(),
+            step_timer: Timer::from_seconds(STEP_INTERVAL, TimerMode::Repeating),
+            round_start_timer: Timer::from_seconds(ROUND_START_DELAY, TimerMode::Once),
+            round_over_timer: Timer::from_seconds(ROUND_OVER_DELAY, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SetupOption {
+    HumanPlayers,
+    CpuOpponents,
+    RoundsToWin,
+}
+
+impl SetupOption {
+    pub const ALL: [SetupOption; 3] = [
+        SetupOption::HumanPlayers,
+        SetupOption::CpuOpponents,
+        SetupOption::RoundsToWin,
+    ];
+}
+
+/// Who plays the match and how long it lasts, kept between matches
+#[derive(Resource)]
+pub struct MatchSettings {
+    pub human_players: usize,
+    pub cpu_opponents: usize,
+    pub rounds_to_win_index: usize,
+    pub selected: usize,
+}
+
+impl Default for MatchSettings {
+    fn default() -> Self {
+        MatchSettings {
+            human_players: 2,
+            cpu_opponents: 0,
+            rounds_to_win_index: 1,
+            selected: 0,
+        }
+    }
+}
+
+impl MatchSettings {
+    pub fn cycle_count(&self) -> usize {
+        self.human_players + self.cpu_opponents
+    }
+
+    pub fn cycle_name(&self, index: usize) -> String {
+        if index < self.human_players {
+            format!("Player {}", index + 1)
+        } else {
+            format!("CPU {}", index + 1 - self.human_players)
+        }
+    }
+
+    pub fn rounds_to_win(&self) -> u32 {
+        ROUNDS_TO_WIN_OPTIONS[self.rounds_to_win_index]
+    }
+
+    pub fn selected_option(&self) -> SetupOption {
+        SetupOption::ALL[self.selected]
+    }
+
+    /// Changes the selected option by one step, keeping between two and four cycles
+    pub fn adjust(&mut self, delta: i32) {
+        let step = |value: usize, max: usize| (value as i32 + delta).clamp(0, max as i32) as usize;
+        match self.selected_option() {
+            SetupOption::HumanPlayers => {
+                self.human_players = step(self.human_players, MAX_HUMAN_PLAYERS).max(1);
+            }
+            SetupOption::CpuOpponents => {
+                self.cpu_opponents = step(self.cpu_opponents, MAX_CYCLES);
+            }
+            SetupOption::RoundsToWin => {
+                self.rounds_to_win_index =
+                    step(self.rounds_to_win_index, ROUNDS_TO_WIN_OPTIONS.len() - 1);
+            }
+        }
+        let min_cpus = MIN_CYCLES.saturating_sub(self.human_players);
+        let max_cpus = MAX_CYCLES - self.human_players;
+        self.cpu_opponents = self.cpu_opponents.clamp(min_cpus, max_cpus);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Cycle {
+    pub pos: IVec2,
+    pub dir: IVec2,
+    pub is_cpu: bool,
+    pub is_alive: bool,
+    turns: VecDeque<IVec2>,
+}
+
+impl Cycle {
+    pub fn new(pos: IVec2, dir: IVec2, is_cpu: bool) -> Self {
+        Cycle {
+            pos,
+            dir,
+            is_cpu,
+            is_alive: true,
+            turns: VecDeque::new(),
+        }
+    }
+
+    /// Buffers a 90 degree turn relative to the last queued heading
+    pub fn queue_turn(&mut self, dir: IVec2) {
+        let last = self.turns.back().copied().unwrap_or(self.dir);
+        if dir == last || dir == -last || self.turns.len() >= MAX_QUEUED_TURNS {
+            return;
+        }
+        self.turns.push_back(dir);
+    }
+
+    pub fn apply_next_turn(&mut self) {
+        if let Some(dir) = self.turns.pop_front() {
+            self.dir = dir;
+        }
+    }
+}
+
+/// The cycles of the current round and every cell their trails cover
+#[derive(Resource, Default)]
+pub struct Arena {
+    pub cycles: Vec<Cycle>,
+    pub occupied: HashSet<IVec2>,
+}
+
+impl Arena {
+    pub fn is_free(&self, pos: IVec2) -> bool {
+        (0..ARENA_WIDTH).contains(&pos.x)
+            && (0..ARENA_HEIGHT).contains(&pos.y)
+            && !self.occupied.contains(&pos)
+    }
+
+    /// Counts the free cells reachable from `start`, up to `limit`
+    pub fn free_space(&self, start: IVec2, limit: usize) -> usize {
+        if !self.is_free(start) {
+            return 0;
+        }
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            if visited.len() >= limit {
+                break;
+            }
+            if !self.is_free(pos) || !visited.insert(pos) {
+                continue;
+            }
+            stack.extend([IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y].map(|dir| pos + dir));
+        }
+        visited.len()
+    }
+
+    pub fn alive_count(&self) -> usize {
+        self.cycles.iter().filter(|cycle| cycle.is_alive).count()
+    }
+}
+
+/// Center of an arena cell in play area units, with row 0 at the bottom
+pub fn cell_pos(pos: IVec2) -> Vec2 {
+    Vec2::new(
+        (pos.x as f32 - (ARENA_WIDTH - 1) as f32 / 2.0) * CELL_SIZE,
+        (pos.y as f32 - (ARENA_HEIGHT - 1) as f32 / 2.0) * CELL_SIZE + ARENA_OFFSET_Y,
+    )
+}
@@ -0,0 +1,42 @@
+use bevy::app::App;
+use bevy::prelude::*;
+
+use crate::common::{AppState, ViewportSize};
+use crate::scenes::tron::components::TronEntity;
+use crate::scenes::tron::constants::PLAY_AREA_HEIGHT;
+use crate::scenes::tron::event_handlers::{spawn_arena, EventHandlerPlugin};
+use crate::scenes::tron::events::{EventsPlugin, ShowMatchSetupEvent};
+use crate::scenes::tron::input::InputPlugin;
+use crate::scenes::tron::logic::LogicPlugin;
+use crate::scenes::tron::resources::ResourcesPlugin;
+
+pub struct TronScenePlugin;
+
+impl Plugin for TronScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResourcesPlugin)
+            .add_plugin(EventsPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(EventHandlerPlugin)
+            .add_plugin(LogicPlugin)
+            .add_system(setup_scene.in_schedule(OnEnter(AppState::Tron)))
+            .add_system(despawn_game.in_schedule(OnExit(AppState::Tron)));
+    }
+}
+
+fn setup_scene(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    viewport_size: Res<ViewportSize>,
+    mut show_match_setup_events: EventWriter<ShowMatchSetupEvent>,
+) {
+    let scale = viewport_size.height / PLAY_AREA_HEIGHT;
+    spawn_arena(&mut commands, &asset_server, scale);
+    show_match_setup_events.send_default();
+}
+
+fn despawn_game(mut commands: Commands, entities: Query<Entity, With<TronEntity>>) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use rand::{thread_rng, Rng};
+
+use crate::common::ViewportSize;
+use crate::constants::WINDOW_HEIGHT;
+use crate::settings::Settings;
+
+/// Largest distance the camera is moved by, at full trauma
+const MAX_SHAKE_OFFSET: f32 = 12.0;
+/// Trauma lost per second
+const TRAUMA_DECAY: f32 = 1.5;
+
+pub struct ScreenShakePlugin;
+
+impl Plugin for ScreenShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ShakeScreenEvent>()
+            .init_resource::<ScreenShake>()
+            .add_system(remove_shake_offset.in_base_set(CoreSet::PreUpdate))
+            .add_system(shake_screen_event_handler)
+            .add_system(
+                apply_shake_offset
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+/// Shakes the camera, harder and longer the stronger it is, up to 1.0
+pub struct ShakeScreenEvent {
+    pub strength: f32,
+}
+
+#[derive(Resource, Default)]
+struct ScreenShake {
+    trauma: f32,
+    /// Added to the camera for the current frame only, so that scenes moving it see where it is
+    offset: Vec2,
+}
+
+fn remove_shake_offset(
+    mut shake: ResMut<ScreenShake>,
+    mut camera_q: Query<&mut Transform, With<Camera>>,
+) {
+    if shake.offset == Vec2::ZERO {
+        return;
+    }
+    for mut transform in camera_q.iter_mut() {
+        transform.translation -= shake.offset.extend(0.0);
+    }
+    shake.offset = Vec2::ZERO;
+}
+
+fn shake_screen_event_handler(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut events: EventReader<ShakeScreenEvent>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    let strength: f32 = events.iter().map(|event| event.strength).sum();
+    if !settings.is_screen_shake_enabled {
+        shake.trauma = 0.0;
+        return;
+    }
+    shake.trauma = (shake.trauma + strength - TRAUMA_DECAY * time.delta_seconds()).clamp(0.0, 1.0);
+}
+
+fn apply_shake_offset(
+    viewport_size: Res<ViewportSize>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera_q: Query<&mut Transform, With<Camera>>,
+) {
+    if shake.trauma == 0.0 {
+        return;
+    }
+
+    // squared so that small shakes stay subtle
+    let amount =
+        MAX_SHAKE_OFFSET * shake.trauma * shake.trauma * viewport_size.height / WINDOW_HEIGHT;
+    let mut rng = thread_rng();
+    shake.offset = Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)) * amount;
+    for mut transform in camera_q.iter_mut() {
+        transform.translation += shake.offset.extend(0.0);
+    }
+}
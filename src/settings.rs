@@ -0,0 +1,134 @@
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow, WindowMode};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{config_file_path, file_version, write_file};
+
+/// Bumped whenever the layout of the settings file changes
+const SETTINGS_FILE_VERSION: u32 = 1;
+const SETTINGS_FILE_NAME: &str = "settings.ron";
+
+/// Volumes are changed by this much at a time
+pub const VOLUME_STEP: f32 = 0.1;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(apply_window_settings.run_if(resource_changed::<Settings>()));
+    }
+}
+
+/// Options of the player, inserted by `main` as the window is created from them
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub is_fullscreen: bool,
+    pub is_vsync_enabled: bool,
+    pub show_fps: bool,
+    pub is_screen_shake_enabled: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SettingsFile {
+    version: u32,
+    settings: Settings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            master_volume: 1.0,
+            music_volume: 0.7,
+            sfx_volume: 1.0,
+            is_fullscreen: false,
+            is_vsync_enabled: true,
+            show_fps: cfg!(debug_assertions),
+            is_screen_shake_enabled: true,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        let Some(path) = config_file_path(SETTINGS_FILE_NAME) else {
+            return Settings::default();
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Settings::default();
+        };
+
+        let version = match file_version(&content) {
+            Ok(version) => version,
+            Err(err) => {
+                warn!("Ignoring unreadable settings file {:?}: {}", path, err);
+                return Settings::default();
+            }
+        };
+        match version {
+            SETTINGS_FILE_VERSION => match ron::from_str::<SettingsFile>(&content) {
+                Ok(file) => file.settings,
+                Err(err) => {
+                    warn!("Ignoring unreadable settings file {:?}: {}", path, err);
+                    Settings::default()
+                }
+            },
+            _ => {
+                warn!(
+                    "Ignoring settings file {:?} with unsupported version {}",
+                    path, version
+                );
+                Settings::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = config_file_path(SETTINGS_FILE_NAME) else { return; };
+        let file = SettingsFile {
+            version: SETTINGS_FILE_VERSION,
+            settings: self.clone(),
+        };
+        let content = ron::ser::to_string_pretty(&file, PrettyConfig::default()).unwrap();
+        if let Err(err) = write_file(&path, content) {
+            warn!("Could not save settings to {:?}: {}", path, err);
+        }
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        if self.is_fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.is_vsync_enabled {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+}
+
+fn apply_window_settings(
+    settings: Res<Settings>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = window_q.get_single_mut() else { return; };
+    let mode = settings.window_mode();
+    let present_mode = settings.present_mode();
+    // only touched when different, so that the window is not recreated for nothing
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+}
@@ -0,0 +1,35 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+const FOLDER_NAME: &str = "retro-arcade-collection";
+
+/// Read first to pick how to parse the rest of a versioned file
+#[derive(Deserialize)]
+struct FileVersion {
+    version: u32,
+}
+
+/// Path of a file holding the player preferences
+pub fn config_file_path(file_name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|folder| folder.join(FOLDER_NAME).join(file_name))
+}
+
+/// Path of a file holding what the player achieved
+pub fn data_file_path(file_name: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|folder| folder.join(FOLDER_NAME).join(file_name))
+}
+
+pub fn file_version(content: &str) -> Result<u32, ron::error::SpannedError> {
+    ron::from_str::<FileVersion>(content).map(|file| file.version)
+}
+
+/// Writes the file, creating its folder if needed
+pub fn write_file(path: &Path, content: String) -> io::Result<()> {
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)?;
+    }
+    fs::write(path, content)
+}
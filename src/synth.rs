@@ -0,0 +1,319 @@
+use std::f32::consts::TAU;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::audio::{Decodable, Source};
+use bevy::reflect::TypeUuid;
+
+/// Low, as the waves are simple enough not to need more
+pub const SAMPLE_RATE: u32 = 22050;
+
+/// Samples generated at runtime, played like any sound loaded from a file
+#[derive(TypeUuid, Clone)]
+#[uuid = "5b8f4c2e-6a1d-4e3b-9c7f-2d4a8e1b3f60"]
+pub struct SynthSound {
+    samples: Arc<[f32]>,
+}
+
+impl SynthSound {
+    pub fn new(samples: Vec<f32>) -> Self {
+        SynthSound {
+            samples: samples.into(),
+        }
+    }
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SynthDecoder {
+            samples: self.samples.clone(),
+            index: 0,
+        }
+    }
+}
+
+pub struct SynthDecoder {
+    samples: Arc<[f32]>,
+    index: usize,
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.samples.get(self.index).copied();
+        self.index += 1;
+        sample
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some(self.samples.len().saturating_sub(self.index))
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(
+            self.samples.len() as f32 / SAMPLE_RATE as f32,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Waveform {
+    /// Pulse wave, high for this fraction of each period
+    Square(f32),
+    Triangle,
+    Sine,
+    /// Random level every half period, so that the frequency still sets how rough it sounds
+    Noise,
+}
+
+impl Waveform {
+    /// Value between -1.0 and 1.0 at `phase`, in periods
+    pub fn sample(self, phase: f32) -> f32 {
+        match self {
+            Waveform::Square(duty) => {
+                if phase.fract() < duty {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Triangle => 1.0 - 4.0 * (phase.fract() - 0.5).abs(),
+            Waveform::Sine => (phase * TAU).sin(),
+            Waveform::Noise => noise_level((phase * 2.0) as u32),
+        }
+    }
+}
+
+/// Same level for the same step, so that a sound renders the same every time
+fn noise_level(step: u32) -> f32 {
+    let mut hash = step.wrapping_mul(0x9e37_79b9);
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+/// Volume of a sound over time, in seconds apart from the sustain level
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Envelope {
+    pub attack: f32,
+    pub decay: f32,
+    /// Volume held after the decay, up to 1.0
+    pub sustain_level: f32,
+    pub sustain: f32,
+    pub release: f32,
+}
+
+impl Envelope {
+    pub fn length(&self) -> f32 {
+        self.attack + self.decay + self.sustain + self.release
+    }
+
+    /// Volume `t` seconds into the sound
+    pub fn level(&self, t: f32) -> f32 {
+        if t < self.attack {
+            return t / self.attack;
+        }
+        let t = t - self.attack;
+        if t < self.decay {
+            return 1.0 - (1.0 - self.sustain_level) * t / self.decay;
+        }
+        let t = t - self.decay;
+        if t < self.sustain {
+            return self.sustain_level;
+        }
+        let t = t - self.sustain;
+        (self.sustain_level * (1.0 - t / self.release)).max(0.0)
+    }
+}
+
+/// Parameters of a sound effect, in the spirit of sfxr
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SfxPreset {
+    pub waveform: Waveform,
+    /// Starting frequency, in Hz
+    pub frequency: f32,
+    /// Octaves the pitch moves by each second, falling when negative
+    pub sweep: f32,
+    pub envelope: Envelope,
+    pub volume: f32,
+}
+
+impl SfxPreset {
+    pub const LASER: SfxPreset = SfxPreset {
+        waveform: Waveform::Square(0.25),
+        frequency: 1400.0,
+        sweep: -8.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.04,
+            sustain_level: 0.5,
+            sustain: 0.05,
+            release: 0.08,
+        },
+        volume: 0.3,
+    };
+
+    pub const EXPLOSION: SfxPreset = SfxPreset {
+        waveform: Waveform::Noise,
+        frequency: 1800.0,
+        sweep: -2.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.08,
+            sustain_level: 0.6,
+            sustain: 0.08,
+            release: 0.3,
+        },
+        volume: 0.5,
+    };
+
+    pub const BIG_EXPLOSION: SfxPreset = SfxPreset {
+        waveform: Waveform::Noise,
+        frequency: 900.0,
+        sweep: -1.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.15,
+            sustain_level: 0.7,
+            sustain: 0.25,
+            release: 0.8,
+        },
+        volume: 0.6,
+    };
+
+    pub const THRUST: SfxPreset = SfxPreset {
+        waveform: Waveform::Noise,
+        frequency: 300.0,
+        sweep: 0.0,
+        envelope: Envelope {
+            attack: 0.02,
+            decay: 0.0,
+            sustain_level: 1.0,
+            sustain: 0.1,
+            release: 0.03,
+        },
+        volume: 0.2,
+    };
+
+    pub const BLIP: SfxPreset = SfxPreset {
+        waveform: Waveform::Triangle,
+        frequency: 600.0,
+        sweep: 4.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.02,
+            sustain_level: 0.6,
+            sustain: 0.02,
+            release: 0.02,
+        },
+        volume: 0.25,
+    };
+
+    pub const CHIME: SfxPreset = SfxPreset {
+        waveform: Waveform::Square(0.5),
+        frequency: 520.0,
+        sweep: 3.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.05,
+            sustain_level: 0.7,
+            sustain: 0.15,
+            release: 0.2,
+        },
+        volume: 0.25,
+    };
+
+    pub const FALL: SfxPreset = SfxPreset {
+        waveform: Waveform::Square(0.5),
+        frequency: 900.0,
+        sweep: -2.5,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.1,
+            sustain_level: 0.8,
+            sustain: 0.8,
+            release: 0.3,
+        },
+        volume: 0.3,
+    };
+
+    pub const POWER_UP: SfxPreset = SfxPreset {
+        waveform: Waveform::Square(0.5),
+        frequency: 440.0,
+        sweep: 4.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.05,
+            sustain_level: 0.8,
+            sustain: 0.15,
+            release: 0.1,
+        },
+        volume: 0.3,
+    };
+
+    pub fn render(&self) -> SynthSound {
+        let sample_count = (self.envelope.length() * SAMPLE_RATE as f32) as usize;
+        let mut phase = 0.0;
+        let samples = (0..sample_count)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                phase += self.frequency * 2f32.powf(self.sweep * t) / SAMPLE_RATE as f32;
+                self.waveform.sample(phase) * self.envelope.level(t) * self.volume
+            })
+            .collect();
+        SynthSound::new(samples)
+    }
+}
+
+/// Frequency of the note, in semitones from A4 at 440 Hz
+pub fn note_frequency(semitones_from_a4: i32) -> f32 {
+    440.0 * 2f32.powf(semitones_from_a4 as f32 / 12.0)
+}
+
+/// Seconds over which each note fades in and out, so that it doesn't click
+const NOTE_FADE: f32 = 0.005;
+
+/// Plays each note for its length in seconds, a `None` frequency being a rest
+pub fn render_notes(waveform: Waveform, notes: &[(Option<f32>, f32)], volume: f32) -> Vec<f32> {
+    let mut samples = Vec::new();
+    for &(frequency, length) in notes {
+        let sample_count = (length * SAMPLE_RATE as f32) as usize;
+        let Some(frequency) = frequency else {
+            samples.resize(samples.len() + sample_count, 0.0);
+            continue;
+        };
+        for i in 0..sample_count {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let fade = (t / NOTE_FADE).min((length - t) / NOTE_FADE).min(1.0);
+            samples.push(waveform.sample(t * frequency) * volume * fade);
+        }
+    }
+    samples
+}
+
+/// Adds the voices together, as long as the longest of them
+pub fn mix(voices: &[Vec<f32>]) -> Vec<f32> {
+    let length = voices.iter().map(Vec::len).max().unwrap_or(0);
+    let mut samples = vec![0.0; length];
+    for voice in voices {
+        for (sample, value) in samples.iter_mut().zip(voice) {
+            *sample += value;
+        }
+    }
+    samples
+}